@@ -0,0 +1,103 @@
+extern crate mainspring;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mainspring::address_map::{
+    memory::{Memory, ReadOnly, ReadWrite},
+    AddressMap, Addressable,
+};
+use mainspring::cpu::mos6502::{register::ProgramCounter, MOS6502};
+use mainspring::prelude::v1::*;
+
+const CYCLES: usize = 100_000;
+
+/// A cpu executing a rom of nops spanning 0x6000 to 0x7000, ending in a jump
+/// back to the start of the sled.
+fn nop_sled() -> MOS6502 {
+    let mut sled = [0xea; 0x1001].to_vec();
+    sled[0x0ffe..].copy_from_slice(&[0x4c, 0x00, 0x60]);
+    let rom = Memory::<ReadOnly>::new(0x6000, 0x7000).load(sled);
+
+    MOS6502::default()
+        .register_address_space(0x6000..=0x7000, rom)
+        .unwrap()
+        .with_pc_register(ProgramCounter::with_value(0x6000))
+}
+
+/// A cpu executing the LDA/STA loop of `examples/basic.rs`, storing to ram
+/// between 0x8000 and 0xbfff.
+fn lda_sta_loop() -> MOS6502 {
+    let rom = Memory::<ReadOnly>::new(0xffea, 0xffff).load(vec![
+        0xa9, 0x01, 0x8d, 0x00, 0x80, 0xa9, 0x02, 0x8d, 0x01, 0x80, 0xa9, 0x03, 0x8d, 0x02, 0x80,
+        0x4c, 0xea, 0xff, 0xea, 0xff, 0x00, 0x00,
+    ]);
+    let ram = Memory::<ReadWrite>::new(0x8000, 0xbfff);
+
+    MOS6502::default()
+        .register_address_space(0xffea..=0xffff, rom)
+        .unwrap()
+        .register_address_space(0x8000..=0xbfff, ram)
+        .unwrap()
+        .reset()
+        .unwrap()
+}
+
+/// An address map resembling a populated 6502 system, with ram, a handful of
+/// single page devices and a rom.
+fn populated_address_map() -> AddressMap<u16> {
+    (0x40..0x50u16).fold(
+        AddressMap::new()
+            .register(
+                0x0000..=0x3fff,
+                Box::new(Memory::<ReadWrite>::new(0x0000, 0x3fff)),
+            )
+            .unwrap()
+            .register(
+                0x8000..=0xffff,
+                Box::new(Memory::<ReadOnly>::new(0x8000, 0xffff)),
+            )
+            .unwrap(),
+        |am, page| {
+            let start = page << 8;
+            am.register(
+                start..=start + 0xff,
+                Box::new(Memory::<ReadWrite>::new(start, start + 0xff)),
+            )
+            .unwrap()
+        },
+    )
+}
+
+/// Panics if the cpu halts before running the full cycle budget, which would
+/// cut a benchmark short.
+fn assert_runs_to_budget(cpu: &MOS6502) {
+    let state = cpu.clone().run(CYCLES).unwrap();
+    assert!(state.halted().is_none(), "halted on {:?}", state.halted());
+    assert_eq!(CYCLES, state.cycles());
+}
+
+fn cpu_benchmarks(c: &mut Criterion) {
+    let cpu = nop_sled();
+    assert_runs_to_budget(&cpu);
+    c.bench_function("nop sled", |b| {
+        b.iter(|| black_box(cpu.clone().run(CYCLES)))
+    });
+
+    let cpu = lda_sta_loop();
+    assert_runs_to_budget(&cpu);
+    c.bench_function("lda/sta loop", |b| {
+        b.iter(|| black_box(cpu.clone().run(CYCLES)))
+    });
+}
+
+fn address_map_benchmarks(c: &mut Criterion) {
+    let am = populated_address_map();
+    c.bench_function("address map read", |b| {
+        b.iter(|| {
+            (0..=u16::MAX)
+                .step_by(7)
+                .fold(0u8, |acc, addr| acc ^ am.read(black_box(addr)))
+        })
+    });
+}
+
+criterion_group!(benches, cpu_benchmarks, address_map_benchmarks);
+criterion_main!(benches);
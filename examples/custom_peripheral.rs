@@ -2,6 +2,7 @@
 
 extern crate mainspring;
 use mainspring::address_map::memory::{Memory, ReadOnly};
+use mainspring::address_map::WriteError;
 use mainspring::cpu::mos6502::MOS6502;
 
 #[allow(unused)]
@@ -9,29 +10,19 @@ use mainspring::prelude::v1::*;
 
 /// VIA functions as an analogy to the 6522 VIA chip. However, only a subset of
 /// functionality has be implemented for this demo. For the case of this example,
-/// all ports are configured in write mode with predefined addresses.
-#[derive(Clone)]
+/// all ports are configured in write mode. The VIA is registered with relative
+/// addressing, receiving the offset of its registers rather than their address
+/// on the bus, so it can be mapped anywhere without knowing its base address.
+#[derive(Clone, Default)]
 pub struct VIA {
-    port_a_addr: u16, // address connected to enable port a CS
-    port_b_addr: u16, // address connected to enable port a CS
-    port_a_mask: u16,
-    port_b_mask: u16,
     port_a: u8,
     port_b: u8,
 }
 
-impl VIA {
-    pub fn new(base_addr: u16) -> Self {
-        VIA {
-            port_a_addr: base_addr,
-            port_b_addr: base_addr + 1,
-            port_a_mask: base_addr + 2,
-            port_b_mask: base_addr + 3,
-            port_a: 0,
-            port_b: 0,
-        }
-    }
-}
+const PORT_A: u16 = 0x00;
+const PORT_B: u16 = 0x01;
+const PORT_A_MASK: u16 = 0x02;
+const PORT_B_MASK: u16 = 0x03;
 
 impl Addressable<u16> for VIA {
     // Read the value at an address. Since this device is never read a
@@ -40,20 +31,25 @@ impl Addressable<u16> for VIA {
         0
     }
 
-    /// Write a value to a a specified address. If the address matches port a,
+    /// Write a value to a a specified register. If the register is port a,
     /// it will write the value to the console after storing it.
-    fn write(&mut self, addr: u16, value: u8) -> Result<u8, String> {
-        if addr == self.port_a_addr {
-            self.port_a = value;
-            println!("{:08b}", value);
-            Ok(value)
-        } else if addr == self.port_b_addr {
-            self.port_b = value;
-            Ok(value)
-        } else if (self.port_a_mask..=self.port_b_mask).contains(&addr) {
-            Ok(value)
-        } else {
-            Err(format!("unknown VIA write addrress: {}", addr))
+    fn write(&mut self, offset: u16, value: u8) -> Result<u8, WriteError<u16>> {
+        match offset {
+            PORT_A => {
+                self.port_a = value;
+                println!("{:08b}", value);
+                Ok(value)
+            }
+            PORT_B => {
+                self.port_b = value;
+                Ok(value)
+            }
+            PORT_A_MASK | PORT_B_MASK => Ok(value),
+            _ => Err(WriteError::Rejected {
+                address: offset,
+                value,
+                reason: "unknown VIA register".to_string(),
+            }),
         }
     }
 }
@@ -61,19 +57,21 @@ impl Addressable<u16> for VIA {
 fn main() {
     // A small rom that loops over write 01010101 and 10101010 to port a where
     // it is output. this will loop endlessly until stopped.
-    let rom = Memory::<ReadOnly>::new(0x7fea, 0x7fff).load(vec![
+    let rom = Memory::<ReadOnly>::new(0xffea, 0xffff).load(vec![
         0xa9, 0xff, 0x8d, 0x02, 0x80, 0xa9, 0x55, 0x8d, 0x00, 0x80, 0xa9, 0xaa, 0x8d, 0x00, 0x80,
-        0x4c, 0xef, 0x7f, 0xea, 0x7f, 0x00, 0x00,
+        0x4c, 0xef, 0xff, 0xea, 0xff, 0x00, 0x00,
     ]);
 
-    let via = VIA::new(0x8000);
+    let via = VIA::default();
     let cpu = MOS6502::default()
         // Registers the address space and the rom as addressable memory with
         // the cpu. This accepts any implementation of the Addressable trait.
-        .register_address_space(0x7fea..=0x7fff, rom)
+        .register_address_space(0xffea..=0xffff, rom)
         // Registration can fail, this unwraps the result.
         .unwrap()
-        .register_address_space(0x8000..=0x8003, via)
+        // Registers the VIA at 0x8000, passing it offsets from the start of
+        // its range.
+        .register_relative_address_space(0x8000..=0x8003, via)
         .unwrap()
         // Resets the cpu and loads the reset vector into the PC.
         .reset()
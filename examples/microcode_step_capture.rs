@@ -8,22 +8,23 @@ use mainspring::prelude::v1::*;
 fn main() {
     // A ReadOnly memory segment containing a small rom consisting of a
     // LDA/STA loop. This will run until stopped. This exists in the address
-    // space inclusively beteen 0x7fea and 0x7fff.
-    let rom = Memory::<ReadOnly>::new(0x7fea, 0x7fff).load(vec![
+    // space inclusively beteen 0xffea and 0xffff, ending with the reset
+    // vector at 0xfffc.
+    let rom = Memory::<ReadOnly>::new(0xffea, 0xffff).load(vec![
         0xa9, 0x01, 0x8d, 0x00, 0x80, 0xa9, 0x02, 0x8d, 0x01, 0x80, 0xa9, 0x03, 0x8d, 0x02, 0x80,
-        0x4c, 0xea, 0x7f, 0xea, 0x7f, 0x00, 0x00,
+        0x4c, 0xea, 0xff, 0xea, 0xff, 0x00, 0x00,
     ]);
 
     // A segment of ReadWrite memory existing inclusively in the the space
-    // between 0x8000 and 0xffff.
-    let ram = Memory::<ReadWrite>::new(0x8000, 0xffff);
+    // between 0x8000 and 0xbfff.
+    let ram = Memory::<ReadWrite>::new(0x8000, 0xbfff);
     let cpu = MOS6502::default()
         // Registers the address space and the rom as addressable memory with
         // the cpu. This accepts any implementation of the Addressable trait.
-        .register_address_space(0x7fea..=0x7fff, rom)
+        .register_address_space(0xffea..=0xffff, rom)
         // Registration can fail, this unwraps the result.
         .unwrap()
-        .register_address_space(0x8000..=0xffff, ram)
+        .register_address_space(0x8000..=0xbfff, ram)
         .unwrap()
         // Resets the cpu and loads the reset vector into the PC.
         .reset()
@@ -0,0 +1,154 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::HexRange;
+
+/// Formats an address in hex, padded to the width of the address type.
+struct HexAddress<O>(O);
+
+impl<O> fmt::Display for HexAddress<O>
+where
+    O: Copy + Into<u32>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = std::mem::size_of::<O>() * 2 + 2;
+        write!(f, "{:#0w$x}", self.0.into(), w = width)
+    }
+}
+
+/// RegistrationError represents the conditions under which an address space
+/// can't be registered with, or found in, an AddressMap.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistrationError<O> {
+    /// The range ends before it starts.
+    Empty { range: RangeInclusive<O> },
+    /// The range overlaps with an address space that is already registered.
+    Overlap {
+        range: RangeInclusive<O>,
+        existing: RangeInclusive<O>,
+        name: String,
+    },
+    /// No address space, or overlay, is registered to the range.
+    NotRegistered { range: RangeInclusive<O> },
+}
+
+impl<O> fmt::Display for RegistrationError<O>
+where
+    O: Copy + Into<u32>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty { range } => write!(f, "address space {:?} is empty", HexRange(range)),
+            Self::Overlap {
+                range,
+                existing,
+                name,
+            } => write!(
+                f,
+                "address space {:?} overlaps with {:?} ({})",
+                HexRange(range),
+                HexRange(existing),
+                name
+            ),
+            Self::NotRegistered { range } => {
+                write!(f, "address space {:?} is not registered", HexRange(range))
+            }
+        }
+    }
+}
+
+impl<O> std::error::Error for RegistrationError<O> where O: fmt::Debug + Copy + Into<u32> {}
+
+/// WriteError represents the conditions under which a write to an
+/// addressable fails. Each variant carries the address and value written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WriteError<O> {
+    /// The address isn't backed by any address space.
+    Unmapped { address: O, value: u8 },
+    /// The address is backed by read-only memory.
+    ReadOnly { address: O, value: u8 },
+    /// The address falls outside of the range backed by the device written
+    /// to.
+    OutOfBounds {
+        address: O,
+        value: u8,
+        range: RangeInclusive<O>,
+    },
+    /// A device rejected the write for a reason of its own.
+    Rejected {
+        address: O,
+        value: u8,
+        reason: String,
+    },
+}
+
+impl<O> WriteError<O>
+where
+    O: Copy,
+{
+    /// Returns the address written.
+    pub fn address(&self) -> O {
+        match self {
+            Self::Unmapped { address, .. }
+            | Self::ReadOnly { address, .. }
+            | Self::OutOfBounds { address, .. }
+            | Self::Rejected { address, .. } => *address,
+        }
+    }
+
+    /// Returns the value written.
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::Unmapped { value, .. }
+            | Self::ReadOnly { value, .. }
+            | Self::OutOfBounds { value, .. }
+            | Self::Rejected { value, .. } => *value,
+        }
+    }
+
+    /// Replaces the address of the error with the bus address of a write
+    /// that was folded into the window of a device. Out of bounds errors keep
+    /// the address the device received, matching the range they carry.
+    pub(crate) fn at(mut self, bus_address: O) -> Self {
+        match &mut self {
+            Self::Unmapped { address, .. }
+            | Self::ReadOnly { address, .. }
+            | Self::Rejected { address, .. } => *address = bus_address,
+            Self::OutOfBounds { .. } => (),
+        }
+        self
+    }
+}
+
+impl<O> fmt::Display for WriteError<O>
+where
+    O: Copy + Into<u32>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (address, value) = (HexAddress(self.address()), self.value());
+        match self {
+            Self::Unmapped { .. } => {
+                write!(f, "write of {:#04x} to unmapped address {}", value, address)
+            }
+            Self::ReadOnly { .. } => write!(
+                f,
+                "write of {:#04x} to read-only address {}",
+                value, address
+            ),
+            Self::OutOfBounds { range, .. } => write!(
+                f,
+                "write of {:#04x} to {} outside of {:?}",
+                value,
+                address,
+                HexRange(range)
+            ),
+            Self::Rejected { reason, .. } => write!(
+                f,
+                "write of {:#04x} to {} rejected: {}",
+                value, address, reason
+            ),
+        }
+    }
+}
+
+impl<O> std::error::Error for WriteError<O> where O: fmt::Debug + Copy + Into<u32> {}
@@ -0,0 +1,458 @@
+use crate::address_map::{Addressable, WriteError};
+
+/// The address the program ROM window of a cartridge starts at.
+pub const PRG_START: u16 = 0x8000;
+
+/// The size of the program ROM window of a cartridge.
+const PRG_WINDOW: usize = 0x8000;
+
+/// The size of the character window of a cartridge.
+const CHR_WINDOW: usize = 0x2000;
+
+// Represents an error that happens in configuring a bank-switched image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MapperErr {
+    /// The size of an image isn't a non-zero multiple of its bank size.
+    ImageSize { size: usize, bank_size: usize },
+}
+
+impl std::fmt::Display for MapperErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ImageSize { size, bank_size } => write!(
+                f,
+                "image of {} bytes is not a multiple of the {} byte bank size",
+                size, bank_size
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MapperErr {}
+
+/// Banks maps a window onto selectable banks of a larger image. The window is
+/// divided into slots the size of a bank, each of which can select any bank
+/// of the image. Bank numbers wrap at the number of banks in the image, as
+/// the unused high bits of a bank register would on a board with a smaller
+/// chip.
+#[derive(Debug, Clone)]
+pub struct Banks {
+    bank_size: usize,
+    image: Vec<u8>,
+    slots: Vec<usize>,
+}
+
+impl Banks {
+    /// Divides an image into banks of a size, mapping each of the slots of a
+    /// window to the first bank. The image must be a non-zero multiple of the
+    /// bank size.
+    pub fn new(image: Vec<u8>, bank_size: usize, slots: usize) -> Result<Self, MapperErr> {
+        if bank_size == 0 || image.is_empty() || !image.len().is_multiple_of(bank_size) {
+            return Err(MapperErr::ImageSize {
+                size: image.len(),
+                bank_size,
+            });
+        }
+
+        Ok(Banks {
+            bank_size,
+            image,
+            slots: vec![0; slots.max(1)],
+        })
+    }
+
+    /// Returns the number of banks in the image.
+    pub fn bank_count(&self) -> usize {
+        self.image.len() / self.bank_size
+    }
+
+    /// Returns the number of the last bank in the image.
+    pub fn last(&self) -> usize {
+        self.bank_count() - 1
+    }
+
+    /// Maps a slot of the window to a bank of the image.
+    pub fn select(&mut self, slot: usize, bank: usize) {
+        let bank_count = self.bank_count();
+        self.slots[slot] = bank % bank_count;
+    }
+
+    /// Returns the bank mapped to a slot of the window.
+    pub fn selected(&self, slot: usize) -> usize {
+        self.slots[slot]
+    }
+
+    /// Reads the byte at an offset into the window.
+    pub fn read(&self, offset: usize) -> u8 {
+        self.image[self.index(offset)]
+    }
+
+    /// Assigns the byte at an offset into the window.
+    pub fn write(&mut self, offset: usize, value: u8) {
+        let idx = self.index(offset);
+        self.image[idx] = value;
+    }
+
+    /// Dump converts the current state of the image into a corresponding
+    /// Vec<u8>.
+    pub fn dump(&self) -> Vec<u8> {
+        self.image.clone()
+    }
+
+    fn index(&self, offset: usize) -> usize {
+        let slot = (offset / self.bank_size) % self.slots.len();
+        self.slots[slot] * self.bank_size + offset % self.bank_size
+    }
+}
+
+/// Mapper defines the bank switching logic of a cartridge board, selecting
+/// the banks of program ROM and character memory mapped into their windows
+/// in response to writes to its control registers.
+pub trait Mapper: Clone + 'static {
+    /// The size of a bank of program ROM. The program window is divided into
+    /// slots of this size.
+    const PRG_BANK_SIZE: usize;
+
+    /// The size of a bank of character memory. The character window is
+    /// divided into slots of this size.
+    const CHR_BANK_SIZE: usize;
+
+    /// Maps the banks selected at power on.
+    fn reset(&mut self, prg: &mut Banks, chr: &mut Banks);
+
+    /// Handles a cpu write to the program window, updating the selected
+    /// banks.
+    fn write(&mut self, addr: u16, value: u8, prg: &mut Banks, chr: &mut Banks);
+
+    /// Returns true if the board drives the data bus from ROM while its
+    /// registers are written, such that the value written is the logical and
+    /// of the value and the ROM byte at the address.
+    fn bus_conflicts(&self) -> bool {
+        false
+    }
+}
+
+/// Cartridge pairs a mapper with its program ROM and character memory,
+/// exposing the program window to the cpu as an addressable registered at
+/// 0x8000..=0xffff. Character memory is exposed through `read_chr` and
+/// `write_chr` for the video bus. Cartridges without character ROM are given
+/// 8KiB of character RAM.
+#[derive(Clone)]
+pub struct Cartridge<M> {
+    mapper: M,
+    prg: Banks,
+    chr: Banks,
+    chr_ram: bool,
+}
+
+impl<M> Cartridge<M>
+where
+    M: Mapper,
+{
+    /// Instantiates a cartridge from a mapper and its program and character
+    /// images, leaving the mapper in its power on state.
+    pub fn new(mut mapper: M, prg: Vec<u8>, chr: Vec<u8>) -> Result<Self, MapperErr> {
+        let chr_ram = chr.is_empty();
+        let chr = if chr_ram { vec![0; CHR_WINDOW] } else { chr };
+        let mut prg = Banks::new(prg, M::PRG_BANK_SIZE, PRG_WINDOW / M::PRG_BANK_SIZE)?;
+        let mut chr = Banks::new(chr, M::CHR_BANK_SIZE, CHR_WINDOW / M::CHR_BANK_SIZE)?;
+        mapper.reset(&mut prg, &mut chr);
+
+        Ok(Cartridge {
+            mapper,
+            prg,
+            chr,
+            chr_ram,
+        })
+    }
+
+    /// Returns the mapper of the cartridge.
+    pub fn mapper(&self) -> &M {
+        &self.mapper
+    }
+
+    /// Returns the banks of program ROM.
+    pub fn prg(&self) -> &Banks {
+        &self.prg
+    }
+
+    /// Returns the banks of character memory.
+    pub fn chr(&self) -> &Banks {
+        &self.chr
+    }
+
+    /// Reads a byte from the character window at 0x0000..=0x1fff.
+    pub fn read_chr(&self, addr: u16) -> u8 {
+        self.chr.read(addr as usize % CHR_WINDOW)
+    }
+
+    /// Writes a byte to the character window, returning an error if the
+    /// cartridge has character ROM rather than RAM.
+    pub fn write_chr(&mut self, addr: u16, value: u8) -> Result<u8, WriteError<u16>> {
+        if !self.chr_ram {
+            return Err(WriteError::ReadOnly {
+                address: addr,
+                value,
+            });
+        }
+
+        self.chr.write(addr as usize % CHR_WINDOW, value);
+        Ok(value)
+    }
+}
+
+impl<M> Addressable<u16> for Cartridge<M>
+where
+    M: Mapper,
+{
+    /// Reads a byte from the selected bank of program ROM.
+    fn read(&self, addr: u16) -> u8 {
+        self.prg
+            .read(addr.wrapping_sub(PRG_START) as usize % PRG_WINDOW)
+    }
+
+    /// Routes a write to the control registers of the mapper.
+    fn write(&mut self, addr: u16, value: u8) -> Result<u8, WriteError<u16>> {
+        let value = if self.mapper.bus_conflicts() {
+            value & self.read(addr)
+        } else {
+            value
+        };
+
+        self.mapper.write(addr, value, &mut self.prg, &mut self.chr);
+        Ok(value)
+    }
+}
+
+/// Nrom is the board without bank switching, mapping 16KiB or 32KiB of
+/// program ROM and 8KiB of character memory. A 16KiB program is mirrored
+/// into both halves of the window.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Nrom;
+
+impl Mapper for Nrom {
+    const PRG_BANK_SIZE: usize = 0x4000;
+    const CHR_BANK_SIZE: usize = 0x2000;
+
+    fn reset(&mut self, prg: &mut Banks, _: &mut Banks) {
+        prg.select(0, 0);
+        prg.select(1, 1);
+    }
+
+    fn write(&mut self, _: u16, _: u8, _: &mut Banks, _: &mut Banks) {}
+}
+
+/// UxRom switches a 16KiB bank of program ROM into 0x8000..=0xbfff, selected
+/// by any write to the program window, with the last bank fixed at
+/// 0xc000..=0xffff.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UxRom;
+
+impl Mapper for UxRom {
+    const PRG_BANK_SIZE: usize = 0x4000;
+    const CHR_BANK_SIZE: usize = 0x2000;
+
+    fn reset(&mut self, prg: &mut Banks, _: &mut Banks) {
+        prg.select(0, 0);
+        prg.select(1, prg.last());
+    }
+
+    fn write(&mut self, _: u16, value: u8, prg: &mut Banks, _: &mut Banks) {
+        prg.select(0, value as usize);
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        true
+    }
+}
+
+/// Cnrom switches an 8KiB bank of character ROM, selected by any write to
+/// the program window, with program ROM mapped as it is on Nrom.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cnrom;
+
+impl Mapper for Cnrom {
+    const PRG_BANK_SIZE: usize = 0x4000;
+    const CHR_BANK_SIZE: usize = 0x2000;
+
+    fn reset(&mut self, prg: &mut Banks, chr: &mut Banks) {
+        Nrom.reset(prg, chr);
+    }
+
+    fn write(&mut self, _: u16, value: u8, _: &mut Banks, chr: &mut Banks) {
+        chr.select(0, value as usize);
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        true
+    }
+}
+
+/// Nametable mirroring as configured by a mapper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mirroring {
+    SingleScreenLower,
+    SingleScreenUpper,
+    Vertical,
+    Horizontal,
+}
+
+/// Mmc1 loads its registers serially through a 5-bit shift register. Each
+/// write shifts bit 0 of the value in, least significant bit first, and the
+/// fifth write copies the shift register into the register selected by
+/// address bits 13 and 14. A write with bit 7 set resets the shift register
+/// and fixes the last bank of program ROM at 0xc000..=0xffff.
+#[derive(Debug, Clone, Copy)]
+pub struct Mmc1 {
+    shift: u8,
+    writes: u8,
+    control: u8,
+    chr_bank_0: u8,
+    chr_bank_1: u8,
+    prg_bank: u8,
+}
+
+impl Default for Mmc1 {
+    fn default() -> Self {
+        Mmc1 {
+            shift: 0,
+            writes: 0,
+            control: 0x0c,
+            chr_bank_0: 0,
+            chr_bank_1: 0,
+            prg_bank: 0,
+        }
+    }
+}
+
+impl Mmc1 {
+    /// Returns the nametable mirroring selected by the control register.
+    pub fn mirroring(&self) -> Mirroring {
+        match self.control & 0x03 {
+            0 => Mirroring::SingleScreenLower,
+            1 => Mirroring::SingleScreenUpper,
+            2 => Mirroring::Vertical,
+            _ => Mirroring::Horizontal,
+        }
+    }
+
+    fn update(&self, prg: &mut Banks, chr: &mut Banks) {
+        let prg_bank = (self.prg_bank & 0x0f) as usize;
+        match (self.control >> 2) & 0x03 {
+            0 | 1 => {
+                prg.select(0, prg_bank & !1);
+                prg.select(1, prg_bank | 1);
+            }
+            2 => {
+                prg.select(0, 0);
+                prg.select(1, prg_bank);
+            }
+            _ => {
+                prg.select(0, prg_bank);
+                prg.select(1, prg.last());
+            }
+        }
+
+        if self.control & 0x10 == 0 {
+            chr.select(0, self.chr_bank_0 as usize & !1);
+            chr.select(1, self.chr_bank_0 as usize | 1);
+        } else {
+            chr.select(0, self.chr_bank_0 as usize);
+            chr.select(1, self.chr_bank_1 as usize);
+        }
+    }
+}
+
+impl Mapper for Mmc1 {
+    const PRG_BANK_SIZE: usize = 0x4000;
+    const CHR_BANK_SIZE: usize = 0x1000;
+
+    fn reset(&mut self, prg: &mut Banks, chr: &mut Banks) {
+        *self = Mmc1::default();
+        self.update(prg, chr);
+    }
+
+    fn write(&mut self, addr: u16, value: u8, prg: &mut Banks, chr: &mut Banks) {
+        if value & 0x80 != 0 {
+            self.shift = 0;
+            self.writes = 0;
+            self.control |= 0x0c;
+            self.update(prg, chr);
+            return;
+        }
+
+        self.shift |= (value & 0x01) << self.writes;
+        self.writes += 1;
+        if self.writes < 5 {
+            return;
+        }
+
+        let register = std::mem::take(&mut self.shift);
+        self.writes = 0;
+        match (addr >> 13) & 0x03 {
+            0 => self.control = register,
+            1 => self.chr_bank_0 = register,
+            2 => self.chr_bank_1 = register,
+            _ => self.prg_bank = register,
+        }
+        self.update(prg, chr);
+    }
+}
+
+/// Latch is a discrete latch holding the last value written to the program
+/// window, with a mask selecting the bits of the latch that choose the 32KiB
+/// bank of program ROM and another choosing the 8KiB bank of character
+/// memory. This covers the simple boards common to homebrew, such as GxROM
+/// with `Latch::new(0x30, 0x03)` or Color Dreams with `Latch::new(0x03, 0xf0)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Latch {
+    prg_mask: u8,
+    chr_mask: u8,
+    bus_conflicts: bool,
+}
+
+impl Latch {
+    /// Instantiates a latch from the masks of its program and character bank
+    /// bits.
+    pub fn new(prg_mask: u8, chr_mask: u8) -> Self {
+        Latch {
+            prg_mask,
+            chr_mask,
+            bus_conflicts: false,
+        }
+    }
+
+    /// Sets whether writes to the latch conflict with the ROM byte at the
+    /// address written.
+    pub fn with_bus_conflicts(mut self, bus_conflicts: bool) -> Self {
+        self.bus_conflicts = bus_conflicts;
+        self
+    }
+
+    fn bank(value: u8, mask: u8) -> usize {
+        if mask == 0 {
+            0
+        } else {
+            ((value & mask) >> mask.trailing_zeros()) as usize
+        }
+    }
+}
+
+impl Mapper for Latch {
+    const PRG_BANK_SIZE: usize = 0x8000;
+    const CHR_BANK_SIZE: usize = 0x2000;
+
+    fn reset(&mut self, prg: &mut Banks, chr: &mut Banks) {
+        prg.select(0, 0);
+        chr.select(0, 0);
+    }
+
+    fn write(&mut self, _: u16, value: u8, prg: &mut Banks, chr: &mut Banks) {
+        prg.select(0, Self::bank(value, self.prg_mask));
+        chr.select(0, Self::bank(value, self.chr_mask));
+    }
+
+    fn bus_conflicts(&self) -> bool {
+        self.bus_conflicts
+    }
+}
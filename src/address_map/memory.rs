@@ -1,20 +1,28 @@
-use crate::address_map::Addressable;
+use crate::address_map::{Addressable, WriteError};
 use std::marker::PhantomData;
 
 // Represents an error that happens in interactions with memory.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MemoryErr {
-    Load,
+    /// The size of an image doesn't match the size of the memory it was
+    /// loaded into.
+    ImageSize { expected: usize, actual: usize },
 }
 
 impl std::fmt::Display for MemoryErr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Load => write!(f, "failed to load rom"),
+            Self::ImageSize { expected, actual } => write!(
+                f,
+                "failed to load rom of {} bytes into {} bytes of memory",
+                actual, expected
+            ),
         }
     }
 }
 
+impl std::error::Error for MemoryErr {}
+
 /// Represents a ReadOnly type of memory. This is entirely used for
 /// typechecking and has no other practical uses.
 #[derive(Clone, Copy)]
@@ -25,22 +33,27 @@ pub struct ReadOnly;
 #[derive(Clone, Copy)]
 pub struct ReadWrite;
 
-/// Represents an addressable segment of memory, be it RAM or ROM.
+/// Represents an addressable segment of memory, be it RAM or ROM. Memory is
+/// addressed by the offset type of the bus it is registered on, defaulting to
+/// the 16-bit bus of the 6502.
 #[allow(dead_code)]
 #[derive(Clone)]
-pub struct Memory<T> {
+pub struct Memory<T, O = u16> {
     mem_type: PhantomData<T>,
-    start_address: u16,
-    stop_address: u16,
+    start_address: O,
+    stop_address: O,
     inner: Vec<u8>,
 }
 
-impl<T> Memory<T> {
+impl<T, O> Memory<T, O>
+where
+    O: Into<u32> + Copy,
+{
     /// Allocates a new addressable memory module taking both a start and stop
     /// address.
-    pub fn new(start_address: u16, stop_address: u16) -> Self {
+    pub fn new(start_address: O, stop_address: O) -> Self {
         let mut data = Vec::new();
-        data.resize((stop_address - start_address) as usize + 1, 0);
+        data.resize((stop_address.into() - start_address.into()) as usize + 1, 0);
         Memory {
             mem_type: PhantomData,
             start_address,
@@ -54,6 +67,44 @@ impl<T> Memory<T> {
         self.inner.clone()
     }
 
+    /// Returns the first address of the memory.
+    pub(crate) fn start_address(&self) -> O {
+        self.start_address
+    }
+
+    /// Returns the byte at an address, relative to the start address.
+    /// Addresses outside of the memory, or beyond the end of a short image
+    /// loaded into it, read as 0x00 in the same manner as the unmapped
+    /// addresses of an AddressMap.
+    pub(crate) fn peek(&self, addr: O) -> u8 {
+        addr.into()
+            .checked_sub(self.start_address.into())
+            .and_then(|offset| self.inner.get(offset as usize))
+            .copied()
+            .unwrap_or(0x00)
+    }
+
+    /// Assigns the byte at an address, relative to the start address,
+    /// returning an error if the address falls outside of the memory.
+    pub(crate) fn poke(&mut self, addr: O, value: u8) -> Result<u8, WriteError<O>> {
+        let byte = addr
+            .into()
+            .checked_sub(self.start_address.into())
+            .and_then(|offset| self.inner.get_mut(offset as usize));
+
+        match byte {
+            Some(byte) => {
+                *byte = value;
+                Ok(value)
+            }
+            None => Err(WriteError::OutOfBounds {
+                address: addr,
+                value,
+                range: self.start_address..=self.stop_address,
+            }),
+        }
+    }
+
     /// Load data into memory takes a rom and returns an instance of Memory
     /// with the newly loaded dataset.
     pub fn load(self, data: Vec<u8>) -> Self {
@@ -64,35 +115,82 @@ impl<T> Memory<T> {
             inner: data,
         }
     }
+
+    /// Loads data into memory in the same manner as `load`, returning an
+    /// error if the size of the data doesn't match the size of the memory.
+    pub fn try_load(self, data: Vec<u8>) -> Result<Self, MemoryErr> {
+        let expected = self.inner.len();
+        if data.len() != expected {
+            return Err(MemoryErr::ImageSize {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        Ok(self.load(data))
+    }
 }
 
 impl Addressable<u16> for Memory<ReadWrite> {
     /// Reads a single byte at the specified address returning the u8
-    /// representation of the value.
+    /// representation of the value. Addresses outside of the memory read as
+    /// 0x00.
     fn read(&self, addr: u16) -> u8 {
-        let addr_offset = addr - self.start_address;
-        self.inner[usize::from(addr_offset)]
+        self.peek(addr)
     }
 
-    /// Assigns a single value to an address in memory returning a result if the
-    /// write was in range.
-    fn write(&mut self, addr: u16, value: u8) -> Result<u8, String> {
-        let addr_offset = addr - self.start_address;
-        self.inner[usize::from(addr_offset)] = value;
-        Ok(value)
+    /// Assigns a single value to an address in memory returning an error if
+    /// the address is out of range.
+    fn write(&mut self, addr: u16, value: u8) -> Result<u8, WriteError<u16>> {
+        self.poke(addr, value)
     }
 }
 
 impl Addressable<u16> for Memory<ReadOnly> {
-    /// Reads a single byte at the specified address
+    /// Reads a single byte at the specified address. Addresses outside of the
+    /// memory read as 0x00.
     fn read(&self, addr: u16) -> u8 {
-        let addr_offset = addr - self.start_address;
-        self.inner[usize::from(addr_offset)]
+        self.peek(addr)
+    }
+
+    /// write returns an error signifying that the memory is
+    /// read-only.
+    fn write(&mut self, addr: u16, value: u8) -> Result<u8, WriteError<u16>> {
+        Err(WriteError::ReadOnly {
+            address: addr,
+            value,
+        })
+    }
+}
+
+impl Addressable<u32> for Memory<ReadWrite, u32> {
+    /// Reads a single byte at the specified address returning the u8
+    /// representation of the value. Addresses outside of the memory read as
+    /// 0x00.
+    fn read(&self, addr: u32) -> u8 {
+        self.peek(addr)
+    }
+
+    /// Assigns a single value to an address in memory returning an error if
+    /// the address is out of range.
+    fn write(&mut self, addr: u32, value: u8) -> Result<u8, WriteError<u32>> {
+        self.poke(addr, value)
+    }
+}
+
+impl Addressable<u32> for Memory<ReadOnly, u32> {
+    /// Reads a single byte at the specified address. Addresses outside of the
+    /// memory read as 0x00.
+    fn read(&self, addr: u32) -> u8 {
+        self.peek(addr)
     }
 
     /// write returns an error signifying that the memory is
     /// read-only.
-    fn write(&mut self, _: u16, _: u8) -> Result<u8, String> {
-        Err("memory is read-only".to_string())
+    fn write(&mut self, addr: u32, value: u8) -> Result<u8, WriteError<u32>> {
+        Err(WriteError::ReadOnly {
+            address: addr,
+            value,
+        })
     }
 }
@@ -1,28 +1,77 @@
-use std::collections::HashMap;
+use std::any::Any;
+use std::convert::TryFrom;
 use std::fmt;
 use std::{cmp::Eq, fmt::Debug, hash::Hash, ops::RangeInclusive};
 
+pub mod error;
+pub mod mapper;
 pub mod memory;
+pub use error::{RegistrationError, WriteError};
+use memory::{Memory, ReadOnly, ReadWrite};
 
 #[cfg(test)]
 mod tests;
 
-type WriteError = String;
-type RegistrationError = String;
-
 /// Addressable implements the trait for addressable memory in an address map.
 /// this can represent IO, RAM, ROM, etc...
 pub trait Addressable<O>: AddressableClone<O>
 where
-    O: Into<usize> + Debug + Clone + Copy,
+    O: Debug + Clone + Copy,
 {
+    /// Returns the value at an offset without side effects, allowing tooling
+    /// such as debuggers and disassemblers to peek at a device.
     fn read(&self, offset: O) -> u8;
-    fn write(&mut self, offset: O, data: u8) -> Result<u8, WriteError>;
+    fn write(&mut self, offset: O, data: u8) -> Result<u8, WriteError<O>>;
+
+    /// Reads the value at an offset as a cpu bus read, allowing a device to
+    /// act on the access, such as clearing a status register once it has
+    /// been read. Devices without read side effects can rely on the default,
+    /// which is equivalent to `read`.
+    fn read_mut(&mut self, offset: O) -> u8 {
+        self.read(offset)
+    }
+
+    /// Returns true if the addressable is asserting the maskable interrupt
+    /// request line. Devices that can't raise interrupts never assert it.
+    fn irq(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the addressable is asserting the non-maskable
+    /// interrupt line. Devices that can't raise interrupts never assert it.
+    fn nmi(&self) -> bool {
+        false
+    }
+
+    /// Returns the name the addressable is listed under in an address map,
+    /// defaulting to its type name without module paths.
+    fn name(&self) -> String {
+        short_type_name(std::any::type_name::<Self>())
+    }
+}
+
+/// Strips the module paths from each type in a type name, such that
+/// `mainspring::address_map::memory::Memory<mainspring::address_map::memory::ReadOnly>`
+/// becomes `Memory<ReadOnly>`.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut segment = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+    short
 }
 
 impl<O> Clone for Box<dyn Addressable<O>>
 where
-    O: Into<usize> + Debug + Clone + Copy,
+    O: Debug + Clone + Copy,
 {
     fn clone(&self) -> Box<dyn Addressable<O>> {
         self.clone_box()
@@ -31,105 +80,1003 @@ where
 
 pub trait AddressableClone<O> {
     fn clone_box(&self) -> Box<dyn Addressable<O>>;
+
+    /// Returns the addressable as `Any`, allowing an address map to recognize
+    /// plain memory when it is registered.
+    fn as_any(&self) -> &dyn Any;
 }
 
 impl<T, O> AddressableClone<O> for T
 where
     T: 'static + Addressable<O> + Clone,
-    O: Into<usize> + Debug + Clone + Copy,
+    O: Debug + Clone + Copy,
 {
     fn clone_box(&self) -> Box<dyn Addressable<O>> {
         Box::new(self.clone())
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Device is an address space registered with an AddressMap. Plain memory is
+/// held directly, allowing it to be read and written without dispatching
+/// through the Addressable trait.
+#[derive(Clone)]
+enum Device<O>
+where
+    O: Debug + Clone + Copy,
+{
+    ReadWrite(Memory<ReadWrite, O>),
+    ReadOnly(Memory<ReadOnly, O>),
+    Addressable(Box<dyn Addressable<O>>),
+}
+
+impl<O> From<Box<dyn Addressable<O>>> for Device<O>
+where
+    O: 'static + Debug + Clone + Copy,
+{
+    fn from(addr_space: Box<dyn Addressable<O>>) -> Self {
+        let any = addr_space.as_any();
+        if let Some(memory) = any.downcast_ref::<Memory<ReadWrite, O>>() {
+            Device::ReadWrite(memory.clone())
+        } else if let Some(memory) = any.downcast_ref::<Memory<ReadOnly, O>>() {
+            Device::ReadOnly(memory.clone())
+        } else {
+            Device::Addressable(addr_space)
+        }
+    }
+}
+
+impl<O> Device<O>
+where
+    O: Debug + Clone + Copy + Into<u32>,
+{
+    fn read(&self, addr: O) -> u8 {
+        match self {
+            Device::ReadWrite(memory) => memory.peek(addr),
+            Device::ReadOnly(memory) => memory.peek(addr),
+            Device::Addressable(addr_space) => addr_space.read(addr),
+        }
+    }
+
+    fn read_mut(&mut self, addr: O) -> u8 {
+        match self {
+            Device::ReadWrite(memory) => memory.peek(addr),
+            Device::ReadOnly(memory) => memory.peek(addr),
+            Device::Addressable(addr_space) => addr_space.read_mut(addr),
+        }
+    }
+
+    fn write(&mut self, addr: O, value: u8) -> Result<u8, WriteError<O>> {
+        match self {
+            Device::ReadWrite(memory) => memory.poke(addr, value),
+            Device::ReadOnly(_) => Err(WriteError::ReadOnly {
+                address: addr,
+                value,
+            }),
+            Device::Addressable(addr_space) => addr_space.write(addr, value),
+        }
+    }
+
+    /// Returns the address the device expects for the first offset of a
+    /// relatively addressed range. Plain memory is indexed from its own start
+    /// address, while any other addressable receives the offset itself.
+    fn base(&self) -> u32 {
+        match self {
+            Device::ReadWrite(memory) => memory.start_address().into(),
+            Device::ReadOnly(memory) => memory.start_address().into(),
+            Device::Addressable(_) => 0,
+        }
+    }
+
+    /// Returns true if the device may assert an interrupt line, which plain
+    /// memory never does.
+    fn interrupts(&self) -> bool {
+        matches!(self, Device::Addressable(_))
+    }
+
+    fn irq(&self) -> bool {
+        matches!(self, Device::Addressable(addr_space) if addr_space.irq())
+    }
+
+    fn nmi(&self) -> bool {
+        matches!(self, Device::Addressable(addr_space) if addr_space.nmi())
+    }
+
+    /// Returns the device as the addressable it was registered as.
+    fn into_addressable(self) -> Box<dyn Addressable<O>>
+    where
+        O: 'static,
+        Memory<ReadWrite, O>: Addressable<O>,
+        Memory<ReadOnly, O>: Addressable<O>,
+    {
+        match self {
+            Device::ReadWrite(memory) => Box::new(memory),
+            Device::ReadOnly(memory) => Box::new(memory),
+            Device::Addressable(addr_space) => addr_space,
+        }
+    }
+}
+
+/// The number of address bits covered by a page of the page table.
+const PAGE_BITS: u32 = 8;
+const PAGE_SIZE: usize = 1 << PAGE_BITS;
+
+/// Page maps the addresses of a page to the index of the device that owns
+/// them. Pages owned entirely by one device resolve without consulting
+/// individual addresses.
+#[derive(Clone)]
+enum Page {
+    Unmapped,
+    Device(usize),
+    Split(Box<[Option<usize>; PAGE_SIZE]>),
+}
+
+impl Page {
+    fn get(&self, addr: u32) -> Option<usize> {
+        match self {
+            Page::Unmapped => None,
+            Page::Device(idx) => Some(*idx),
+            Page::Split(slots) => slots[addr as usize % PAGE_SIZE],
+        }
+    }
+
+    /// Assigns the addresses from start to end, which must fall within the
+    /// page, to the device at idx.
+    fn assign(self, start: u32, end: u32, idx: usize) -> Self {
+        if end - start + 1 == PAGE_SIZE as u32 {
+            return Page::Device(idx);
+        }
+
+        let mut slots = match self {
+            Page::Unmapped => Box::new([None; PAGE_SIZE]),
+            Page::Device(owner) => Box::new([Some(owner); PAGE_SIZE]),
+            Page::Split(slots) => slots,
+        };
+        for addr in start..=end {
+            slots[addr as usize % PAGE_SIZE] = Some(idx);
+        }
+        Page::Split(slots)
+    }
+}
+
+/// Region describes an address space registered with an AddressMap by its
+/// range and the name of the addressable registered to it. Mirrors of an
+/// address space are listed as regions of their own, along with the range
+/// of the address space they mirror.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region<O> {
+    pub range: RangeInclusive<O>,
+    pub name: String,
+    pub mirror_of: Option<RangeInclusive<O>>,
+}
+
+/// Decode defines how the addresses an entry is registered to are folded
+/// into the window of its device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Decode {
+    /// Every address line in the range is decoded.
+    Full,
+    /// Only the address lines in the mask are decoded, repeating the window
+    /// of the device from the start of its range.
+    Masked(u32),
+}
+
+/// Addressing selects the addresses an addressable receives from an
+/// AddressMap it is registered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Addressing {
+    /// The addressable receives the absolute address on the bus.
+    Absolute,
+    /// The addressable receives the offset from the start of the range it is
+    /// registered to, allowing the same device to be relocated or mirrored
+    /// without knowing where it is mapped.
+    Relative,
+}
+
+/// Entry pairs a registered device with its range and name, along with any
+/// mirrors of the range and how addresses are decoded.
+#[derive(Clone)]
+struct Entry<O>
+where
+    O: Debug + Clone + Copy,
+{
+    range: RangeInclusive<O>,
+    mirrors: Vec<RangeInclusive<O>>,
+    decode: Decode,
+    addressing: Addressing,
+    name: String,
+    device: Device<O>,
+}
+
+impl<O> Entry<O>
+where
+    O: Debug + Clone + Copy + PartialOrd + Into<u32>,
+{
+    fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<O>> {
+        std::iter::once(&self.range).chain(self.mirrors.iter())
+    }
+
+    fn regions(&self) -> impl Iterator<Item = Region<O>> + '_ {
+        let primary = Region {
+            range: self.range.clone(),
+            name: self.name.clone(),
+            mirror_of: None,
+        };
+        let mirrors = self.mirrors.iter().map(move |mirror| Region {
+            range: mirror.clone(),
+            name: self.name.clone(),
+            mirror_of: Some(self.range.clone()),
+        });
+        std::iter::once(primary).chain(mirrors)
+    }
+
+    /// Folds an address into the window of the device, relative to the start
+    /// of the range for relative addressing. Plain memory is rebased onto its
+    /// own start address, such that memory of any start address may be
+    /// registered relatively.
+    fn fold(&self, addr: O) -> O
+    where
+        O: TryFrom<u32>,
+    {
+        if self.decode == Decode::Full
+            && self.mirrors.is_empty()
+            && self.addressing == Addressing::Absolute
+        {
+            return addr;
+        }
+
+        let start: u32 = (*self.range.start()).into();
+        let end: u32 = (*self.range.end()).into();
+        let offset = match self.mirrors.iter().find(|mirror| mirror.contains(&addr)) {
+            Some(mirror) => (addr.into() - (*mirror.start()).into()) % (end - start + 1),
+            None => addr.into() - start,
+        };
+        let offset = match self.decode {
+            Decode::Full => offset,
+            Decode::Masked(mask) => offset & mask,
+        };
+
+        let folded = match self.addressing {
+            Addressing::Absolute => start + offset,
+            Addressing::Relative => self.device.base() + offset,
+        };
+        O::try_from(folded).unwrap_or(addr)
+    }
+}
+
+/// Routing selects the accesses an overlay intercepts. Accesses an overlay
+/// doesn't intercept fall through to the layers beneath it, such that a ROM
+/// overlay routing only reads leaves writes to the RAM below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Routing {
+    pub reads: bool,
+    pub writes: bool,
+}
+
+impl Routing {
+    /// Intercepts neither reads nor writes, disabling the overlay.
+    pub const NONE: Routing = Routing {
+        reads: false,
+        writes: false,
+    };
+    /// Intercepts reads, letting writes fall through.
+    pub const READS: Routing = Routing {
+        reads: true,
+        writes: false,
+    };
+    /// Intercepts writes, letting reads fall through.
+    pub const WRITES: Routing = Routing {
+        reads: false,
+        writes: true,
+    };
+    /// Intercepts both reads and writes.
+    pub const ALL: Routing = Routing {
+        reads: true,
+        writes: true,
+    };
+}
+
+/// OverlayRegion describes an overlay registered with an AddressMap, along
+/// with its priority and the accesses it intercepts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayRegion<O> {
+    pub region: Region<O>,
+    pub priority: u8,
+    pub routing: Routing,
+}
+
+/// Layer is an overlay registered above the address spaces of an
+/// AddressMap.
+#[derive(Clone)]
+struct Layer<O>
+where
+    O: Debug + Clone + Copy,
+{
+    entry: Entry<O>,
+    priority: u8,
+    routing: Routing,
+}
+
+/// Source identifies a registered device that may assert an interrupt line,
+/// by the index of its address space or overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Space(usize),
+    Overlay(usize),
 }
 
 /// AddressMap contains a mapping of address spaces to corresponding addressable
 /// IO with the purpose of acting as an address map. This time is, additionally,
 /// an implementation Addressable allowing all other components to interact with
-/// it as if it were a bus.
-#[derive(Default, Clone)]
-pub struct AddressMap<O: Into<usize>>
+/// it as if it were a bus. Accesses are dispatched through a page table from
+/// address to device, taking constant time regardless of the number of
+/// address spaces registered. Overlays may be layered above the address
+/// spaces, each intercepting the reads or writes its routing selects. The
+/// interrupt lines are polled from only those devices that may assert them.
+#[derive(Clone)]
+pub struct AddressMap<O>
+where
+    O: Debug + Clone + Copy,
+{
+    inner: Vec<Entry<O>>,
+    pages: Vec<Page>,
+    layers: Vec<Layer<O>>,
+    sources: Vec<Source>,
+}
+
+impl<O> Default for AddressMap<O>
+where
+    O: Debug + Clone + Copy,
+{
+    fn default() -> Self {
+        AddressMap {
+            inner: Vec::new(),
+            pages: Vec::new(),
+            layers: Vec::new(),
+            sources: Vec::new(),
+        }
+    }
+}
+
+/// Formats a range of addresses in hex, padded to the width of the address
+/// type.
+struct HexRange<'a, O>(&'a RangeInclusive<O>);
+
+impl<O> fmt::Debug for HexRange<'_, O>
 where
-    O: Into<usize> + Debug + Clone + Copy,
+    O: Copy + Into<u32>,
 {
-    inner: HashMap<RangeInclusive<O>, Box<dyn Addressable<O>>>,
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = std::mem::size_of::<O>() * 2 + 2;
+        let (start, end): (u32, u32) = ((*self.0.start()).into(), (*self.0.end()).into());
+        write!(f, "{:#0w$x}..={:#0w$x}", start, end, w = width)
+    }
 }
 
+/// Formats the name of a region without quotes, noting the range it mirrors
+/// if it is a mirror.
+struct Name<'a, O>(&'a Region<O>);
+
+impl<O> fmt::Debug for Name<'_, O>
+where
+    O: Copy + Into<u32>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0.mirror_of {
+            Some(range) => write!(f, "{} (mirror of {:?})", self.0.name, HexRange(range)),
+            None => f.write_str(&self.0.name),
+        }
+    }
+}
+
+/// Formats the name of an overlay along with its priority and routing.
+struct OverlayName<'a, O>(&'a OverlayRegion<O>);
+
+impl<O> fmt::Debug for OverlayName<'_, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let routing = match self.0.routing {
+            Routing {
+                reads: true,
+                writes: true,
+            } => "reads and writes",
+            Routing { reads: true, .. } => "reads",
+            Routing { writes: true, .. } => "writes",
+            _ => "disabled",
+        };
+        write!(
+            f,
+            "{} (overlay {}, {})",
+            self.0.region.name, self.0.priority, routing
+        )
+    }
+}
+
+/// Formats the memory map as the registered address spaces in address
+/// order, each with the name of its addressable, followed by any overlays in
+/// priority order. The alternate form prints each address space on its own
+/// line.
 impl<O> fmt::Debug for AddressMap<O>
 where
-    O: Into<usize> + Debug + Clone + Copy,
+    O: Debug + Clone + Copy + PartialOrd + Into<u32>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let keys: Vec<&RangeInclusive<O>> = self.inner.keys().collect();
-        write!(f, "AddressMap {:?}", keys)
+        let regions = self.regions();
+        let overlays = self.overlays();
+
+        f.write_str("AddressMap ")?;
+        f.debug_map()
+            .entries(
+                regions
+                    .iter()
+                    .map(|region| (HexRange(&region.range), Name(region))),
+            )
+            .entries(
+                overlays
+                    .iter()
+                    .map(|overlay| (HexRange(&overlay.region.range), OverlayName(overlay))),
+            )
+            .finish()
     }
 }
 
 impl<O> AddressMap<O>
 where
-    O: Into<usize> + Hash + PartialOrd + Eq + Debug + Clone + Copy,
+    O: 'static + Hash + PartialOrd + Eq + Debug + Clone + Copy + Into<u32>,
 {
     pub fn new() -> Self {
-        AddressMap {
-            inner: HashMap::default(),
-        }
+        Self::default()
     }
 
     /// register attempts takes a range, representing a range of addresses and
     /// an addressable type for receiving read/write requests.
     pub fn register(
+        self,
+        range: RangeInclusive<O>,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        self.insert(
+            range,
+            vec![],
+            Decode::Full,
+            Addressing::Absolute,
+            addr_space,
+        )
+    }
+
+    /// Registers an addressable to a range, passing it offsets relative to
+    /// the start of the range rather than absolute addresses. Plain memory
+    /// instead receives the offset from its own start address, allowing it
+    /// to be relocated regardless of the addresses it was allocated with.
+    pub fn register_relative(
+        self,
+        range: RangeInclusive<O>,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        self.insert(
+            range,
+            vec![],
+            Decode::Full,
+            Addressing::Relative,
+            addr_space,
+        )
+    }
+
+    /// Registers an addressable to a range of which only the address lines in
+    /// the mask are decoded. Addresses in the range are folded into the
+    /// window starting at the start of the range, such that registering
+    /// 0x0000..=0x1fff with a mask of 0x07ff repeats a 2 KiB device four
+    /// times.
+    pub fn register_masked(
+        self,
+        range: RangeInclusive<O>,
+        mask: O,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        self.insert(
+            range,
+            vec![],
+            Decode::Masked(mask.into()),
+            Addressing::Absolute,
+            addr_space,
+        )
+    }
+
+    /// Registers a partially decoded addressable in the same manner as
+    /// `register_masked`, passing it the offset of the folded address from
+    /// the start of the range.
+    pub fn register_masked_relative(
+        self,
+        range: RangeInclusive<O>,
+        mask: O,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        self.insert(
+            range,
+            vec![],
+            Decode::Masked(mask.into()),
+            Addressing::Relative,
+            addr_space,
+        )
+    }
+
+    /// Registers an addressable to a range along with a list of mirrors of
+    /// that range. An address within a mirror is folded into the range by
+    /// its offset from the start of the mirror, wrapping at the length of the
+    /// range, so that a mirror may repeat the range multiple times.
+    pub fn register_mirrored(
+        self,
+        range: RangeInclusive<O>,
+        mirrors: Vec<RangeInclusive<O>>,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        self.insert(
+            range,
+            mirrors,
+            Decode::Full,
+            Addressing::Absolute,
+            addr_space,
+        )
+    }
+
+    /// Registers a mirrored addressable in the same manner as
+    /// `register_mirrored`, passing it the offset of the folded address from
+    /// the start of the range.
+    pub fn register_mirrored_relative(
+        self,
+        range: RangeInclusive<O>,
+        mirrors: Vec<RangeInclusive<O>>,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        self.insert(
+            range,
+            mirrors,
+            Decode::Full,
+            Addressing::Relative,
+            addr_space,
+        )
+    }
+
+    /// Inserts an entry for the addressable after validating that none of
+    /// its ranges are empty or overlap with any registered range, including
+    /// each other.
+    fn insert(
         mut self,
         range: RangeInclusive<O>,
+        mirrors: Vec<RangeInclusive<O>>,
+        decode: Decode,
+        addressing: Addressing,
         addr_space: Box<dyn Addressable<O>>,
-    ) -> Result<AddressMap<O>, RegistrationError> {
-        self.inner
-            .keys()
-            .map(|key| {
-                if key.contains(range.start()) || key.contains(range.end()) {
-                    Err(format!(
-                        "address space {:?} overlaps with {:?}",
-                        &range, &key
-                    ))
-                } else {
-                    Ok(())
-                }
-            })
-            .collect::<Result<Vec<()>, RegistrationError>>()
-            .map_err(|e| e)
-            .map(|_| {
-                self.inner.insert(range, addr_space);
-                self
+    ) -> Result<AddressMap<O>, RegistrationError<O>> {
+        let ranges: Vec<&RangeInclusive<O>> = std::iter::once(&range).chain(&mirrors).collect();
+        if let Some(empty) = ranges.iter().find(|r| r.start() > r.end()) {
+            return Err(RegistrationError::Empty {
+                range: (*empty).clone(),
+            });
+        }
+
+        for (idx, new) in ranges.iter().enumerate() {
+            let registered = self
+                .inner
+                .iter()
+                .flat_map(|entry| entry.ranges().map(move |key| (key, entry.name.as_str())));
+            let pending = ranges[..idx].iter().map(|key| (*key, "itself"));
+            if let Some((key, owner)) = registered
+                .chain(pending)
+                .find(|(key, _)| new.start() <= key.end() && key.start() <= new.end())
+            {
+                return Err(RegistrationError::Overlap {
+                    range: (*new).clone(),
+                    existing: key.clone(),
+                    name: owner.to_string(),
+                });
+            }
+        }
+
+        let idx = self.inner.len();
+        for r in ranges {
+            self.map_pages(r, idx);
+        }
+        self.inner.push(Entry {
+            range,
+            mirrors,
+            decode,
+            addressing,
+            name: addr_space.name(),
+            device: Device::from(addr_space),
+        });
+        self.index_sources();
+        Ok(self)
+    }
+
+    /// Layers an addressable over a range at a priority, intercepting the
+    /// accesses selected by its routing. Overlays are consulted from the
+    /// highest priority down before the address spaces beneath them, and may
+    /// overlap address spaces and overlays of other priorities, but not
+    /// another overlay of the same priority.
+    pub fn overlay(
+        &mut self,
+        range: RangeInclusive<O>,
+        priority: u8,
+        routing: Routing,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<(), RegistrationError<O>> {
+        self.insert_layer(range, priority, routing, Addressing::Absolute, addr_space)
+    }
+
+    /// Layers an addressable over a range in the same manner as `overlay`,
+    /// passing it offsets relative to the start of the range.
+    pub fn overlay_relative(
+        &mut self,
+        range: RangeInclusive<O>,
+        priority: u8,
+        routing: Routing,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<(), RegistrationError<O>> {
+        self.insert_layer(range, priority, routing, Addressing::Relative, addr_space)
+    }
+
+    /// Inserts a layer for the addressable, in priority order, after
+    /// validating that its range isn't empty and doesn't overlap another
+    /// overlay of the same priority.
+    fn insert_layer(
+        &mut self,
+        range: RangeInclusive<O>,
+        priority: u8,
+        routing: Routing,
+        addressing: Addressing,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<(), RegistrationError<O>> {
+        if range.start() > range.end() {
+            return Err(RegistrationError::Empty { range });
+        }
+
+        if let Some(layer) = self.layers.iter().find(|layer| {
+            layer.priority == priority
+                && range.start() <= layer.entry.range.end()
+                && layer.entry.range.start() <= range.end()
+        }) {
+            return Err(RegistrationError::Overlap {
+                range,
+                existing: layer.entry.range.clone(),
+                name: layer.entry.name.clone(),
+            });
+        }
+
+        let idx = self
+            .layers
+            .iter()
+            .position(|layer| layer.priority < priority)
+            .unwrap_or(self.layers.len());
+        self.layers.insert(
+            idx,
+            Layer {
+                entry: Entry {
+                    range,
+                    mirrors: vec![],
+                    decode: Decode::Full,
+                    addressing,
+                    name: addr_space.name(),
+                    device: Device::from(addr_space),
+                },
+                priority,
+                routing,
+            },
+        );
+        self.index_sources();
+        Ok(())
+    }
+
+    /// Changes the accesses intercepted by the overlay registered to a range,
+    /// such as to bank a ROM in or out of the address space beneath it.
+    pub fn set_routing(
+        &mut self,
+        range: RangeInclusive<O>,
+        routing: Routing,
+    ) -> Result<(), RegistrationError<O>> {
+        let layer = self
+            .layers
+            .iter_mut()
+            .find(|layer| layer.entry.range == range)
+            .ok_or(RegistrationError::NotRegistered { range })?;
+        layer.routing = routing;
+        Ok(())
+    }
+
+    /// Removes the address space or overlay registered to a range, returning
+    /// its addressable. The mirrors of an address space are removed with it.
+    /// When both an address space and an overlay are registered to the range,
+    /// the address space is removed.
+    pub fn unregister(
+        &mut self,
+        range: RangeInclusive<O>,
+    ) -> Result<Box<dyn Addressable<O>>, RegistrationError<O>>
+    where
+        Memory<ReadWrite, O>: Addressable<O>,
+        Memory<ReadOnly, O>: Addressable<O>,
+    {
+        if let Some(idx) = self.inner.iter().position(|entry| entry.range == range) {
+            let entry = self.inner.remove(idx);
+            self.remap_pages();
+            self.index_sources();
+            return Ok(entry.device.into_addressable());
+        }
+
+        let idx = self
+            .layers
+            .iter()
+            .position(|layer| layer.entry.range == range)
+            .ok_or(RegistrationError::NotRegistered { range })?;
+        let layer = self.layers.remove(idx);
+        self.index_sources();
+        Ok(layer.entry.device.into_addressable())
+    }
+
+    /// Swaps the addressable of the address space or overlay registered to a
+    /// range, returning the previous addressable. The mirrors, decoding,
+    /// addressing and routing of the range are left unchanged.
+    pub fn replace(
+        &mut self,
+        range: RangeInclusive<O>,
+        addr_space: Box<dyn Addressable<O>>,
+    ) -> Result<Box<dyn Addressable<O>>, RegistrationError<O>>
+    where
+        Memory<ReadWrite, O>: Addressable<O>,
+        Memory<ReadOnly, O>: Addressable<O>,
+    {
+        let entry = match self.inner.iter_mut().find(|entry| entry.range == range) {
+            Some(entry) => entry,
+            None => self
+                .layers
+                .iter_mut()
+                .map(|layer| &mut layer.entry)
+                .find(|entry| entry.range == range)
+                .ok_or(RegistrationError::NotRegistered { range })?,
+        };
+
+        entry.name = addr_space.name();
+        let previous = std::mem::replace(&mut entry.device, Device::from(addr_space));
+        self.index_sources();
+        Ok(previous.into_addressable())
+    }
+
+    /// Returns the ranges of unmapped addresses within the provided range, in
+    /// address order.
+    pub fn gaps(&self, within: RangeInclusive<O>) -> Vec<RangeInclusive<O>>
+    where
+        O: TryFrom<u32>,
+    {
+        let (start, end): (u32, u32) = ((*within.start()).into(), (*within.end()).into());
+        let to_range = |start: u64, end: u64| -> Option<RangeInclusive<O>> {
+            let start = O::try_from(start as u32).ok()?;
+            let end = O::try_from(end as u32).ok()?;
+            Some(start..=end)
+        };
+
+        let mut gaps = Vec::new();
+        let mut cursor = u64::from(start);
+        for region in self.regions() {
+            let region_start = u64::from((*region.range.start()).into());
+            let region_end = u64::from((*region.range.end()).into());
+            if region_end < cursor || region_start > u64::from(end) {
+                continue;
+            }
+
+            if region_start > cursor {
+                gaps.extend(to_range(cursor, region_start - 1));
+            }
+            cursor = region_end + 1;
+        }
+
+        if cursor <= u64::from(end) {
+            gaps.extend(to_range(cursor, u64::from(end)));
+        }
+        gaps
+    }
+
+    /// Returns true if the address falls within a registered address space
+    /// or overlay.
+    pub fn is_mapped(&self, addr: O) -> bool {
+        self.device_index(addr).is_some()
+            || self
+                .layers
+                .iter()
+                .any(|layer| layer.entry.range.contains(&addr))
+    }
+
+    /// Rebuilds the page table from the registered address spaces.
+    fn remap_pages(&mut self) {
+        self.pages.clear();
+        let ranges: Vec<(usize, RangeInclusive<O>)> = self
+            .inner
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, entry)| entry.ranges().map(move |range| (idx, range.clone())))
+            .collect();
+        for (idx, range) in ranges {
+            self.map_pages(&range, idx);
+        }
+    }
+
+    /// Points the page table entries covering a range at the device at idx,
+    /// growing the table as needed.
+    fn map_pages(&mut self, range: &RangeInclusive<O>, idx: usize) {
+        let (start, end): (u32, u32) = ((*range.start()).into(), (*range.end()).into());
+        let last_page = (end >> PAGE_BITS) as usize;
+        if self.pages.len() <= last_page {
+            self.pages.resize(last_page + 1, Page::Unmapped);
+        }
+
+        for page in (start >> PAGE_BITS)..=(end >> PAGE_BITS) {
+            let page_start = (page << PAGE_BITS).max(start);
+            let page_end = (page << PAGE_BITS | (PAGE_SIZE as u32 - 1)).min(end);
+            let entry = std::mem::replace(&mut self.pages[page as usize], Page::Unmapped);
+            self.pages[page as usize] = entry.assign(page_start, page_end, idx);
+        }
+    }
+}
+
+impl<O> AddressMap<O>
+where
+    O: Debug + Clone + Copy + PartialOrd + Into<u32>,
+{
+    /// Returns the registered address spaces, and their mirrors, in address
+    /// order.
+    pub fn regions(&self) -> Vec<Region<O>> {
+        let mut regions: Vec<Region<O>> = self.inner.iter().flat_map(Entry::regions).collect();
+        regions.sort_by(|a, b| {
+            a.range
+                .start()
+                .partial_cmp(b.range.start())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        regions
+    }
+
+    /// Returns the address space that owns an address, if it is mapped. An
+    /// address within a mirror returns the mirror.
+    pub fn owner(&self, addr: O) -> Option<Region<O>> {
+        self.device_index(addr).and_then(|idx| {
+            self.inner[idx]
+                .regions()
+                .find(|region| region.range.contains(&addr))
+        })
+    }
+
+    /// Returns the overlays in priority order, highest first.
+    pub fn overlays(&self) -> Vec<OverlayRegion<O>> {
+        self.layers
+            .iter()
+            .map(|layer| OverlayRegion {
+                region: Region {
+                    range: layer.entry.range.clone(),
+                    name: layer.entry.name.clone(),
+                    mirror_of: None,
+                },
+                priority: layer.priority,
+                routing: layer.routing,
             })
+            .collect()
+    }
+
+    /// Returns the index of the highest priority overlay over an address that
+    /// intercepts the access.
+    fn layer_index(&self, addr: O, intercepts: impl Fn(Routing) -> bool) -> Option<usize> {
+        if self.layers.is_empty() {
+            return None;
+        }
+
+        self.layers
+            .iter()
+            .position(|layer| intercepts(layer.routing) && layer.entry.range.contains(&addr))
+    }
+
+    /// Rebuilds the list of devices that may assert an interrupt line,
+    /// allowing the lines to be polled without visiting plain memory.
+    fn index_sources(&mut self) {
+        let spaces = self
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.device.interrupts())
+            .map(|(idx, _)| Source::Space(idx));
+        let overlays = self
+            .layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| layer.entry.device.interrupts())
+            .map(|(idx, _)| Source::Overlay(idx));
+        self.sources = spaces.chain(overlays).collect();
+    }
+
+    /// Returns the devices that may assert an interrupt line.
+    fn sources(&self) -> impl Iterator<Item = &Device<O>> {
+        self.sources.iter().map(move |source| match *source {
+            Source::Space(idx) => &self.inner[idx].device,
+            Source::Overlay(idx) => &self.layers[idx].entry.device,
+        })
+    }
+
+    /// Returns the index of the device that owns an address.
+    fn device_index(&self, addr: O) -> Option<usize> {
+        let addr: u32 = addr.into();
+        self.pages
+            .get((addr >> PAGE_BITS) as usize)
+            .and_then(|page| page.get(addr))
     }
 }
 
 impl<T> Addressable<T> for AddressMap<T>
 where
-    T: 'static + Into<usize> + Hash + PartialOrd + Eq + Debug + Clone + Copy,
+    T: 'static + Hash + PartialOrd + Eq + Debug + Clone + Copy + Into<u32> + TryFrom<u32>,
 {
     /// Reads a single byte at the specified address
     fn read(&self, addr: T) -> u8 {
-        self.inner
-            .keys()
-            .filter(|key| key.contains(&addr))
-            .map(|r| self.inner.get(r))
-            .flatten()
-            .next()
-            .map_or(0x00, |a| a.read(addr))
-    }
-
-    /// Write assigns a single value to an address in memory
-    fn write(&mut self, addr: T, value: u8) -> Result<u8, String> {
-        let range = self
-            .inner
-            .keys()
-            .cloned()
-            .find(|key| key.contains(&addr))
-            .ok_or(format!("address space {:?} unallocated", addr))?;
-        let am = self
-            .inner
-            .get_mut(&range)
-            .ok_or(format!("address space {:?} unallocated", addr))?;
-        am.write(addr, value)
+        if let Some(idx) = self.layer_index(addr, |routing| routing.reads) {
+            let entry = &self.layers[idx].entry;
+            return entry.device.read(entry.fold(addr));
+        }
+
+        self.device_index(addr).map_or(0x00, |idx| {
+            let entry = &self.inner[idx];
+            entry.device.read(entry.fold(addr))
+        })
+    }
+
+    /// Reads a single byte at the specified address, routing the read to the
+    /// mutable read of the owning address space. Unmapped addresses read as
+    /// 0x00.
+    fn read_mut(&mut self, addr: T) -> u8 {
+        if let Some(idx) = self.layer_index(addr, |routing| routing.reads) {
+            let entry = &mut self.layers[idx].entry;
+            let addr = entry.fold(addr);
+            return entry.device.read_mut(addr);
+        }
+
+        match self.device_index(addr) {
+            Some(idx) => {
+                let entry = &mut self.inner[idx];
+                let addr = entry.fold(addr);
+                entry.device.read_mut(addr)
+            }
+            None => 0x00,
+        }
+    }
+
+    /// Write assigns a single value to an address in memory. Errors raised by
+    /// the owning device carry the address on the bus, rather than the
+    /// address the device received.
+    fn write(&mut self, addr: T, value: u8) -> Result<u8, WriteError<T>> {
+        if let Some(idx) = self.layer_index(addr, |routing| routing.writes) {
+            let entry = &mut self.layers[idx].entry;
+            return entry
+                .device
+                .write(entry.fold(addr), value)
+                .map_err(|err| err.at(addr));
+        }
+
+        let idx = self.device_index(addr).ok_or(WriteError::Unmapped {
+            address: addr,
+            value,
+        })?;
+        let entry = &mut self.inner[idx];
+        entry
+            .device
+            .write(entry.fold(addr), value)
+            .map_err(|err| err.at(addr))
+    }
+
+    /// Returns true if any registered address space is asserting the
+    /// maskable interrupt request line.
+    fn irq(&self) -> bool {
+        self.sources().any(Device::irq)
+    }
+
+    /// Returns true if any registered address space is asserting the
+    /// non-maskable interrupt line.
+    fn nmi(&self) -> bool {
+        self.sources().any(Device::nmi)
     }
 }
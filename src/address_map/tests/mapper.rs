@@ -0,0 +1,231 @@
+use crate::address_map::{
+    mapper::{Banks, Cartridge, Cnrom, Latch, MapperErr, Mirroring, Mmc1, Nrom, UxRom},
+    Addressable,
+};
+
+/// Generates an image of banks of a size, each filled with its bank number.
+fn generate_banked_image(bank_size: usize, banks: usize) -> Vec<u8> {
+    (0..banks)
+        .flat_map(|bank| vec![bank as u8; bank_size])
+        .collect()
+}
+
+/// Shifts a value into the register of an MMC1 at an address, least
+/// significant bit first.
+fn write_mmc1_register(cart: &mut Cartridge<Mmc1>, addr: u16, value: u8) {
+    for bit in 0..5 {
+        cart.write(addr, (value >> bit) & 0x01).unwrap();
+    }
+}
+
+#[test]
+fn should_reject_images_that_are_not_a_multiple_of_the_bank_size() {
+    assert_eq!(
+        Some(MapperErr::ImageSize {
+            size: 0x1001,
+            bank_size: 0x1000
+        }),
+        Banks::new(vec![0; 0x1001], 0x1000, 2).err()
+    );
+    assert!(Banks::new(vec![], 0x1000, 2).is_err());
+    assert!(Cartridge::new(Nrom, vec![0; 0x2000], vec![]).is_err());
+}
+
+#[test]
+fn should_wrap_bank_numbers_at_the_number_of_banks() {
+    let mut banks = Banks::new(generate_banked_image(0x1000, 4), 0x1000, 2).unwrap();
+    banks.select(1, 6);
+
+    assert_eq!(2, banks.selected(1));
+    assert_eq!(0x00, banks.read(0x0fff));
+    assert_eq!(0x02, banks.read(0x1000));
+}
+
+#[test]
+fn should_mirror_16k_nrom_program_rom() {
+    let mut prg = generate_banked_image(0x4000, 1);
+    prg[0x0010] = 0xea;
+    let cart = Cartridge::new(Nrom, prg, generate_banked_image(0x2000, 1)).unwrap();
+
+    assert_eq!(0xea, cart.read(0x8010));
+    assert_eq!(0xea, cart.read(0xc010));
+}
+
+#[test]
+fn should_map_32k_nrom_program_rom_without_switching() {
+    let mut cart = Cartridge::new(Nrom, generate_banked_image(0x4000, 2), vec![]).unwrap();
+
+    assert!(cart.write(0x8000, 0x01).is_ok());
+    assert_eq!(0x00, cart.read(0x8000));
+    assert_eq!(0x01, cart.read(0xc000));
+}
+
+#[test]
+fn should_switch_uxrom_lower_bank_and_fix_the_last() {
+    let mut prg = generate_banked_image(0x4000, 8);
+    // bank select table in the fixed bank, avoiding bus conflicts.
+    for bank in 0..8 {
+        prg[0x1c000 + bank] = bank as u8;
+    }
+    let mut cart = Cartridge::new(UxRom, prg, vec![]).unwrap();
+    assert_eq!(0x00, cart.read(0x8000));
+    assert_eq!(0x07, cart.read(0xc008));
+
+    assert!(cart.write(0xc003, 0x03).is_ok());
+    assert_eq!(0x03, cart.read(0x8000));
+    assert_eq!(0x07, cart.read(0xffff));
+}
+
+#[test]
+fn should_and_uxrom_writes_with_rom_on_bus_conflicts() {
+    let mut prg = generate_banked_image(0x4000, 8);
+    prg[0x1c000] = 0x05;
+    let mut cart = Cartridge::new(UxRom, prg, vec![]).unwrap();
+
+    assert_eq!(Ok(0x04), cart.write(0xc000, 0x06));
+    assert_eq!(0x04, cart.read(0x8000));
+}
+
+#[test]
+fn should_switch_cnrom_character_banks() {
+    let mut prg = generate_banked_image(0x4000, 2);
+    prg[0x0000] = 0xff;
+    let mut cart = Cartridge::new(Cnrom, prg, generate_banked_image(0x2000, 4)).unwrap();
+    assert_eq!(0x00, cart.read_chr(0x1fff));
+
+    assert!(cart.write(0x8000, 0x02).is_ok());
+    assert_eq!(0x02, cart.read_chr(0x0000));
+    assert_eq!(0x02, cart.read_chr(0x1fff));
+    assert_eq!(0xff, cart.read(0x8000));
+    assert!(cart.write_chr(0x0000, 0x00).is_err());
+}
+
+#[test]
+fn should_load_mmc1_registers_through_the_shift_register() {
+    let mut cart = Cartridge::new(
+        Mmc1::default(),
+        generate_banked_image(0x4000, 8),
+        generate_banked_image(0x1000, 8),
+    )
+    .unwrap();
+    assert_eq!(0x00, cart.read(0x8000));
+    assert_eq!(0x07, cart.read(0xc000));
+
+    // a partial load leaves the banks unchanged.
+    for _ in 0..4 {
+        cart.write(0xe000, 0x01).unwrap();
+    }
+    assert_eq!(0x00, cart.read(0x8000));
+    cart.write(0xe000, 0x00).unwrap();
+    // bank 0x0f wraps to the last of the 8 banks.
+    assert_eq!(0x07, cart.read(0x8000));
+
+    write_mmc1_register(&mut cart, 0xe000, 0x02);
+    assert_eq!(0x02, cart.read(0x8000));
+    assert_eq!(0x07, cart.read(0xc000));
+}
+
+#[test]
+fn should_switch_mmc1_program_rom_modes() {
+    let mut cart =
+        Cartridge::new(Mmc1::default(), generate_banked_image(0x4000, 8), vec![]).unwrap();
+    write_mmc1_register(&mut cart, 0xe000, 0x05);
+
+    // fix the first bank at 0x8000 and switch 0xc000.
+    write_mmc1_register(&mut cart, 0x8000, 0x08);
+    assert_eq!((0x00, 0x05), (cart.read(0x8000), cart.read(0xc000)));
+
+    // switch 32KiB, ignoring the low bit of the bank.
+    write_mmc1_register(&mut cart, 0x8000, 0x00);
+    assert_eq!((0x04, 0x05), (cart.read(0x8000), cart.read(0xc000)));
+}
+
+#[test]
+fn should_switch_mmc1_character_banks_and_mirroring() {
+    let mut cart = Cartridge::new(
+        Mmc1::default(),
+        generate_banked_image(0x4000, 2),
+        generate_banked_image(0x1000, 8),
+    )
+    .unwrap();
+
+    write_mmc1_register(&mut cart, 0x8000, 0x13);
+    write_mmc1_register(&mut cart, 0xa000, 0x03);
+    write_mmc1_register(&mut cart, 0xc000, 0x06);
+    assert_eq!(Mirroring::Horizontal, cart.mapper().mirroring());
+    assert_eq!((0x03, 0x06), (cart.read_chr(0x0000), cart.read_chr(0x1000)));
+
+    // 8KiB mode ignores the second register and the low bit of the first.
+    write_mmc1_register(&mut cart, 0x8000, 0x02);
+    assert_eq!(Mirroring::Vertical, cart.mapper().mirroring());
+    assert_eq!((0x02, 0x03), (cart.read_chr(0x0000), cart.read_chr(0x1000)));
+}
+
+#[test]
+fn should_reset_mmc1_shift_register_on_bit_7() {
+    let mut cart =
+        Cartridge::new(Mmc1::default(), generate_banked_image(0x4000, 4), vec![]).unwrap();
+    write_mmc1_register(&mut cart, 0x8000, 0x08);
+    write_mmc1_register(&mut cart, 0xe000, 0x01);
+    assert_eq!(0x00, cart.read(0x8000));
+
+    cart.write(0xe000, 0x01).unwrap();
+    cart.write(0x8000, 0x80).unwrap();
+    assert_eq!((0x01, 0x03), (cart.read(0x8000), cart.read(0xc000)));
+
+    write_mmc1_register(&mut cart, 0xe000, 0x02);
+    assert_eq!(0x02, cart.read(0x8000));
+}
+
+#[test]
+fn should_select_latch_banks_through_masks() {
+    let mut cart = Cartridge::new(
+        Latch::new(0x30, 0x03),
+        generate_banked_image(0x8000, 4),
+        generate_banked_image(0x2000, 4),
+    )
+    .unwrap();
+
+    assert!(cart.write(0x8000, 0x21).is_ok());
+    assert_eq!(0x02, cart.read(0x8000));
+    assert_eq!(0x02, cart.read(0xffff));
+    assert_eq!(0x01, cart.read_chr(0x0000));
+}
+
+#[test]
+fn should_give_cartridges_without_character_rom_character_ram() {
+    let mut cart = Cartridge::new(
+        Latch::new(0x03, 0x00),
+        generate_banked_image(0x8000, 2),
+        vec![],
+    )
+    .unwrap();
+
+    assert_eq!(Ok(0x42), cart.write_chr(0x1000, 0x42));
+    assert_eq!(0x42, cart.read_chr(0x1000));
+    assert_eq!(0x2000, cart.chr().dump().len());
+}
+
+#[test]
+fn should_switch_banks_from_a_cpu_through_an_address_map() {
+    use crate::cpu::{
+        mos6502::{register::ProgramCounter, MOS6502},
+        register::Register,
+        CPU,
+    };
+
+    let mut prg = generate_banked_image(0x4000, 4);
+    // LDA #$02; STA $C001; LDA $8000 in the fixed bank, storing to the operand
+    // of the load to avoid a bus conflict.
+    let program = [0xa9, 0x02, 0x8d, 0x01, 0xc0, 0xad, 0x00, 0x80];
+    prg[0xc000..0xc000 + program.len()].copy_from_slice(&program);
+    let cart = Cartridge::new(UxRom, prg, vec![]).unwrap();
+
+    let cpu = MOS6502::default()
+        .with_pc_register(ProgramCounter::with_value(0xc000))
+        .register_address_space(0x8000..=0xffff, cart)
+        .unwrap();
+
+    let state = cpu.run(10).unwrap();
+    assert_eq!(0x02, state.acc.read());
+}
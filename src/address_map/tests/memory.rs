@@ -1,6 +1,6 @@
 use crate::address_map::{
-    memory::{Memory, ReadOnly, ReadWrite},
-    Addressable,
+    memory::{Memory, MemoryErr, ReadOnly, ReadWrite},
+    Addressable, WriteError,
 };
 
 #[test]
@@ -18,11 +18,25 @@ fn should_persist_values_in_memory_when_written() {
     assert_eq!(0xff, mem.read(0x8000))
 }
 
+#[test]
+fn should_read_zero_outside_of_memory() {
+    let mem: Memory<ReadWrite> = Memory::new(0x2000, 0x20ff);
+    let rom: Memory<ReadOnly> = Memory::new(0x8000, 0x80ff).load(vec![0xea; 0x10]);
+
+    assert_eq!(0x00, mem.read(0x1fff));
+    assert_eq!(0x00, mem.read(0x2100));
+    assert_eq!(0xea, rom.read(0x800f));
+    assert_eq!(0x00, rom.read(0x8010));
+}
+
 #[test]
 fn should_throw_error_when_write_is_attempted_on_readonly_memory() {
     let mut mem: Memory<ReadOnly> = Memory::new(0, std::u16::MAX);
     assert_eq!(
-        Err("memory is read-only".to_string()),
+        Err(WriteError::ReadOnly {
+            address: 0x8000,
+            value: 0xff
+        }),
         mem.write(0x8000, 0xff)
     );
 }
@@ -64,3 +78,41 @@ fn should_correctly_calculate_offsets() {
     assert_eq!(0xff, first_value);
     assert_eq!(0x8000, data.len());
 }
+
+#[test]
+fn should_address_memory_on_a_24_bit_bus() {
+    let mut mem: Memory<ReadWrite, u32> = Memory::new(0x010000, 0x01ffff);
+    mem.write(0x018000, 0xff).unwrap();
+
+    assert_eq!(0xff, mem.read(0x018000));
+    assert_eq!(0x10000, mem.dump().len());
+}
+
+#[test]
+fn should_reject_images_that_do_not_match_the_size_of_memory() {
+    let mem: Memory<ReadOnly> = Memory::new(0x8000, 0x80ff);
+
+    assert_eq!(
+        Some(MemoryErr::ImageSize {
+            expected: 0x100,
+            actual: 0x80
+        }),
+        mem.clone().try_load(vec![0xea; 0x80]).err()
+    );
+    assert_eq!(0xea, mem.try_load(vec![0xea; 0x100]).unwrap().read(0x80ff));
+}
+
+#[test]
+fn should_return_out_of_bounds_on_write_outside_of_memory() {
+    let mut mem: Memory<ReadWrite> = Memory::new(0x8000, 0x80ff);
+
+    assert_eq!(
+        Err(WriteError::OutOfBounds {
+            address: 0x8100,
+            value: 0xff,
+            range: 0x8000..=0x80ff
+        }),
+        mem.write(0x8100, 0xff)
+    );
+    assert!(mem.write(0x7fff, 0xff).is_err());
+}
@@ -1,8 +1,9 @@
 use crate::address_map::{
-    memory::{Memory, ReadOnly},
-    Addressable,
+    memory::{Memory, ReadOnly, ReadWrite},
+    Addressable, Routing, WriteError,
 };
 
+mod mapper;
 mod memory;
 
 macro_rules! u16_address_map {
@@ -48,3 +49,650 @@ fn should_write_valid_memory() {
     assert!(am.write(0xaaaa, 0xff).is_ok());
     assert_eq!(0xff, am.read(0xaaaa));
 }
+
+/// A status register that clears once it has been read by the cpu.
+#[derive(Clone, Default)]
+struct StatusRegister {
+    status: u8,
+}
+
+impl Addressable<u16> for StatusRegister {
+    fn read(&self, _: u16) -> u8 {
+        self.status
+    }
+
+    fn write(&mut self, _: u16, data: u8) -> Result<u8, WriteError<u16>> {
+        self.status = data;
+        Ok(data)
+    }
+
+    fn read_mut(&mut self, _: u16) -> u8 {
+        std::mem::take(&mut self.status)
+    }
+}
+
+#[test]
+fn should_route_mutable_reads_to_the_owning_address_space() {
+    let mut am = u16_address_map!(0x4000..=0x4000, StatusRegister::default()).unwrap();
+    am.write(0x4000, 0x80).unwrap();
+
+    assert_eq!(0x80, am.read_mut(0x4000));
+    assert_eq!(0x00, am.read(0x4000));
+}
+
+#[test]
+fn should_not_modify_an_address_space_on_peek() {
+    let mut am = u16_address_map!(0x4000..=0x4000, StatusRegister::default()).unwrap();
+    am.write(0x4000, 0x80).unwrap();
+
+    assert_eq!(0x80, am.read(0x4000));
+    assert_eq!(0x80, am.read(0x4000));
+}
+
+#[test]
+fn should_read_unmapped_address_as_zero_on_mutable_read() {
+    let mut am = u16_address_map!(0x4000..=0x4000, StatusRegister::default()).unwrap();
+    assert_eq!(0x00, am.read_mut(0x8000));
+}
+
+#[test]
+fn should_route_addresses_sharing_a_page_to_their_own_address_space() {
+    let mut am = u16_address_map!(0x4000..=0x4000, StatusRegister::default())
+        .unwrap()
+        .register(
+            0x4001..=0x40ff,
+            Box::new(Memory::<ReadWrite>::new(0x4001, 0x40ff)),
+        )
+        .unwrap();
+    am.write(0x4000, 0x80).unwrap();
+    am.write(0x4001, 0x01).unwrap();
+
+    assert_eq!(0x80, am.read_mut(0x4000));
+    assert_eq!(0x00, am.read(0x4000));
+    assert_eq!(0x01, am.read(0x4001));
+}
+
+#[test]
+fn should_route_unaligned_address_spaces_spanning_pages() {
+    let mut am =
+        u16_address_map!(0x10f0..=0x1210, Memory::<ReadWrite>::new(0x10f0, 0x1210)).unwrap();
+
+    assert!(!am.is_mapped(0x10ef));
+    assert!(am.is_mapped(0x10f0));
+    assert!(am.is_mapped(0x1210));
+    assert!(!am.is_mapped(0x1211));
+    assert!(am.write(0x1211, 0xff).is_err());
+
+    am.write(0x1100, 0xff).unwrap();
+    assert_eq!(0xff, am.read(0x1100));
+}
+
+#[test]
+fn should_treat_addresses_past_the_highest_address_space_as_unmapped() {
+    let mut am =
+        u16_address_map!(0x0000..=0x00ff, Memory::<ReadWrite>::new(0x0000, 0x00ff)).unwrap();
+
+    assert!(!am.is_mapped(0xffff));
+    assert_eq!(0x00, am.read(0xffff));
+    assert!(am.write(0xffff, 0xff).is_err());
+}
+
+#[test]
+fn should_reject_writes_to_read_only_memory() {
+    let mut am = u16_address_map!(
+        0x8000..=0x80ff,
+        Memory::<ReadOnly>::new(0x8000, 0x80ff).load(vec![0xea; 0x100])
+    )
+    .unwrap();
+
+    assert!(am.write(0x8000, 0x00).is_err());
+    assert_eq!(0xea, am.read(0x8000));
+}
+
+#[test]
+fn should_dispatch_addresses_on_a_24_bit_bus() {
+    let mut am = crate::address_map::AddressMap::<u32>::new()
+        .register(
+            0x01_0000..=0x01_ffff,
+            Box::new(Memory::<ReadWrite, u32>::new(0x01_0000, 0x01_ffff)),
+        )
+        .unwrap();
+
+    assert!(!am.is_mapped(0x00_ffff));
+    am.write(0x01_8000, 0x42).unwrap();
+    assert_eq!(0x42, am.read(0x01_8000));
+}
+
+#[test]
+fn should_fail_when_registering_an_address_space_enclosing_another() {
+    let am = u16_address_map!(0x4000..=0x40ff, Memory::<ReadOnly>::new(0x4000, 0x40ff)).unwrap();
+    assert!(am
+        .register(
+            0x3000..=0x5000,
+            Box::new(Memory::<ReadOnly>::new(0x3000, 0x5000))
+        )
+        .is_err());
+}
+
+#[test]
+fn should_fail_when_registering_an_address_space_enclosed_by_another() {
+    let am = u16_address_map!(0x3000..=0x5000, Memory::<ReadOnly>::new(0x3000, 0x5000)).unwrap();
+    assert!(am
+        .register(
+            0x4000..=0x40ff,
+            Box::new(Memory::<ReadOnly>::new(0x4000, 0x40ff))
+        )
+        .is_err());
+}
+
+#[test]
+fn should_register_adjacent_address_spaces() {
+    let am = u16_address_map!(0x3000..=0x3fff, Memory::<ReadOnly>::new(0x3000, 0x3fff)).unwrap();
+    assert!(am
+        .register(
+            0x4000..=0x40ff,
+            Box::new(Memory::<ReadOnly>::new(0x4000, 0x40ff))
+        )
+        .is_ok());
+}
+
+fn generate_populated_address_map() -> crate::address_map::AddressMap<u16> {
+    u16_address_map!(0x8000..=0xffff, Memory::<ReadOnly>::new(0x8000, 0xffff))
+        .unwrap()
+        .register(0x4000..=0x4000, Box::new(StatusRegister::default()))
+        .unwrap()
+        .register(
+            0x0000..=0x1fff,
+            Box::new(Memory::<ReadWrite>::new(0x0000, 0x1fff)),
+        )
+        .unwrap()
+}
+
+#[test]
+fn should_list_regions_in_address_order_with_names() {
+    let am = generate_populated_address_map();
+
+    assert_eq!(
+        vec![
+            (0x0000..=0x1fff, "Memory<ReadWrite>".to_string()),
+            (0x4000..=0x4000, "StatusRegister".to_string()),
+            (0x8000..=0xffff, "Memory<ReadOnly>".to_string()),
+        ],
+        am.regions()
+            .into_iter()
+            .map(|region| (region.range, region.name))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn should_look_up_the_owner_of_an_address() {
+    let am = generate_populated_address_map();
+
+    let owner = am.owner(0x4000).unwrap();
+    assert_eq!(0x4000..=0x4000, owner.range);
+    assert_eq!("StatusRegister", owner.name);
+    assert!(am.owner(0x4001).is_none());
+}
+
+#[test]
+fn should_find_unmapped_gaps() {
+    let am = generate_populated_address_map();
+
+    assert_eq!(
+        vec![0x2000..=0x3fff, 0x4001..=0x7fff],
+        am.gaps(0x0000..=0xffff)
+    );
+    assert_eq!(vec![0x2000..=0x3fff], am.gaps(0x1000..=0x3fff));
+    assert!(am.gaps(0x8000..=0xffff).is_empty());
+}
+
+#[test]
+fn should_format_memory_map_in_address_order() {
+    let am = generate_populated_address_map();
+
+    assert_eq!(
+        "AddressMap {0x0000..=0x1fff: Memory<ReadWrite>, 0x4000..=0x4000: StatusRegister, 0x8000..=0xffff: Memory<ReadOnly>}",
+        format!("{:?}", am)
+    );
+}
+
+#[test]
+fn should_fold_masked_addresses_into_the_device_window() {
+    let mut am = crate::address_map::AddressMap::<u16>::new()
+        .register_masked(
+            0x0000..=0x1fff,
+            0x07ff,
+            Box::new(Memory::<ReadWrite>::new(0x0000, 0x07ff)),
+        )
+        .unwrap()
+        .register_masked(
+            0x2000..=0x3fff,
+            0x0007,
+            Box::new(Memory::<ReadWrite>::new(0x2000, 0x2007)),
+        )
+        .unwrap();
+
+    assert!(am.write(0x0800, 0xea).is_ok());
+    assert_eq!(0xea, am.read(0x0000));
+    assert_eq!(0xea, am.read(0x1000));
+    assert_eq!(0xea, am.read(0x1800));
+    assert_eq!(0x00, am.read(0x0801));
+
+    assert!(am.write(0x3ffe, 0x1f).is_ok());
+    assert_eq!(0x1f, am.read(0x2006));
+    assert_eq!(0x1f, am.read_mut(0x200e));
+}
+
+#[test]
+fn should_fold_mirrored_addresses_into_the_device_window() {
+    let mut am = crate::address_map::AddressMap::<u16>::new()
+        .register_mirrored(
+            0x6000..=0x600f,
+            vec![0x7000..=0x703f, 0x8010..=0x801f],
+            Box::new(Memory::<ReadWrite>::new(0x6000, 0x600f)),
+        )
+        .unwrap();
+
+    assert!(am.write(0x6003, 0xff).is_ok());
+    assert_eq!(0xff, am.read(0x7003));
+    assert_eq!(0xff, am.read(0x7013));
+    assert_eq!(0xff, am.read(0x8013));
+
+    assert!(am.write(0x703f, 0x42).is_ok());
+    assert_eq!(0x42, am.read(0x600f));
+    assert!(!am.is_mapped(0x7040));
+}
+
+#[test]
+fn should_fail_when_registering_an_overlapping_mirror() {
+    let am = generate_populated_address_map();
+
+    assert!(am
+        .clone()
+        .register_mirrored(
+            0x2000..=0x20ff,
+            vec![0x4000..=0x40ff],
+            Box::new(Memory::<ReadWrite>::new(0x2000, 0x20ff)),
+        )
+        .is_err());
+    assert!(am
+        .register_mirrored(
+            0x2000..=0x20ff,
+            vec![0x2100..=0x21ff, 0x21f0..=0x22ff],
+            Box::new(Memory::<ReadWrite>::new(0x2000, 0x20ff)),
+        )
+        .is_err());
+}
+
+#[test]
+fn should_list_mirrors_as_regions() {
+    let am = crate::address_map::AddressMap::<u16>::new()
+        .register_mirrored(
+            0x6000..=0x600f,
+            vec![0x7000..=0x700f],
+            Box::new(Memory::<ReadWrite>::new(0x6000, 0x600f)),
+        )
+        .unwrap();
+
+    let regions = am.regions();
+    assert_eq!(2, regions.len());
+    assert_eq!(None, regions[0].mirror_of);
+    assert_eq!(Some(0x6000..=0x600f), regions[1].mirror_of);
+    assert_eq!(0x7000..=0x700f, am.owner(0x7008).unwrap().range);
+    assert_eq!(
+        "AddressMap {0x6000..=0x600f: Memory<ReadWrite>, 0x7000..=0x700f: Memory<ReadWrite> (mirror of 0x6000..=0x600f)}",
+        format!("{:?}", am)
+    );
+}
+
+#[test]
+fn should_unregister_an_address_space_and_its_mirrors() {
+    let mut am = generate_populated_address_map()
+        .register_mirrored(
+            0x2000..=0x20ff,
+            vec![0x2100..=0x21ff],
+            Box::new(Memory::<ReadWrite>::new(0x2000, 0x20ff)),
+        )
+        .unwrap();
+    am.write(0x2005, 0xff).unwrap();
+
+    let removed = am.unregister(0x2000..=0x20ff).unwrap();
+    assert_eq!(0xff, removed.read(0x2005));
+    assert!(!am.is_mapped(0x2005));
+    assert!(!am.is_mapped(0x2105));
+    assert_eq!(0x00, am.read(0x2005));
+
+    // remaining address spaces are still routed after the page table is rebuilt.
+    assert!(am.write(0x0000, 0x01).is_ok());
+    assert_eq!(0x01, am.read(0x0000));
+    assert!(am.is_mapped(0x4000));
+    assert!(am.unregister(0x2000..=0x20ff).is_err());
+}
+
+#[test]
+fn should_replace_an_address_space_in_place() {
+    let mut am = generate_populated_address_map();
+
+    let cartridge = Memory::<ReadOnly>::new(0x8000, 0xffff).load(vec![0xea; 0x8000]);
+    let previous = am.replace(0x8000..=0xffff, Box::new(cartridge)).unwrap();
+    assert_eq!(0x00, previous.read(0x8000));
+    assert_eq!(0xea, am.read(0x8000));
+    assert_eq!("Memory<ReadOnly>", am.owner(0x8000).unwrap().name);
+    assert!(am
+        .replace(0x8000..=0x8fff, Box::new(StatusRegister::default()))
+        .is_err());
+}
+
+#[test]
+fn should_route_reads_to_an_overlay_and_writes_to_the_address_space_beneath() {
+    let mut am =
+        u16_address_map!(0xa000..=0xbfff, Memory::<ReadWrite>::new(0xa000, 0xbfff)).unwrap();
+    am.overlay(
+        0xa000..=0xbfff,
+        1,
+        Routing::READS,
+        Box::new(Memory::<ReadOnly>::new(0xa000, 0xbfff).load(vec![0x60; 0x2000])),
+    )
+    .unwrap();
+
+    assert!(am.write(0xa000, 0x42).is_ok());
+    assert_eq!(0x60, am.read(0xa000));
+    assert_eq!(0x60, am.read_mut(0xa000));
+
+    am.set_routing(0xa000..=0xbfff, Routing::NONE).unwrap();
+    assert_eq!(0x42, am.read(0xa000));
+    assert!(am.set_routing(0xa000..=0xafff, Routing::ALL).is_err());
+}
+
+#[test]
+fn should_consult_overlays_from_the_highest_priority() {
+    let mut am = u16_address_map!().unwrap();
+    am.overlay(
+        0xd000..=0xdfff,
+        1,
+        Routing::ALL,
+        Box::new(StatusRegister::default()),
+    )
+    .unwrap();
+    am.overlay(
+        0xd000..=0xd0ff,
+        2,
+        Routing::READS,
+        Box::new(Memory::<ReadOnly>::new(0xd000, 0xd0ff).load(vec![0x01; 0x100])),
+    )
+    .unwrap();
+
+    assert!(am.write(0xd000, 0x07).is_ok());
+    assert_eq!(0x01, am.read(0xd000));
+    assert_eq!(0x07, am.read(0xd100));
+    assert!(am
+        .overlay(
+            0xd0ff..=0xd1ff,
+            1,
+            Routing::ALL,
+            Box::new(StatusRegister::default())
+        )
+        .is_err());
+    assert_eq!(
+        vec![2, 1],
+        am.overlays()
+            .into_iter()
+            .map(|overlay| overlay.priority)
+            .collect::<Vec<_>>()
+    );
+
+    am.unregister(0xd000..=0xd0ff).unwrap();
+    assert_eq!(0x07, am.read(0xd000));
+}
+
+#[test]
+fn should_format_overlays_after_address_spaces() {
+    let mut am = u16_address_map!(0x4000..=0x4000, StatusRegister::default()).unwrap();
+    am.overlay(
+        0x4000..=0x4000,
+        1,
+        Routing::READS,
+        Box::new(StatusRegister::default()),
+    )
+    .unwrap();
+
+    assert_eq!(
+        "AddressMap {0x4000..=0x4000: StatusRegister, 0x4000..=0x4000: StatusRegister (overlay 1, reads)}",
+        format!("{:?}", am)
+    );
+}
+
+/// A device that reads back the low byte of the address it receives.
+#[derive(Clone, Default)]
+struct AddressEcho;
+
+impl Addressable<u16> for AddressEcho {
+    fn read(&self, addr: u16) -> u8 {
+        addr as u8
+    }
+
+    fn write(&mut self, addr: u16, _: u8) -> Result<u8, WriteError<u16>> {
+        Ok(addr as u8)
+    }
+}
+
+#[test]
+fn should_pass_absolute_addresses_by_default() {
+    let am = u16_address_map!(0x4010..=0x401f, AddressEcho).unwrap();
+
+    assert_eq!(0x13, am.read(0x4013));
+}
+
+#[test]
+fn should_pass_offsets_to_relatively_addressed_devices() {
+    let mut am = crate::address_map::AddressMap::<u16>::new()
+        .register_relative(0x4010..=0x401f, Box::new(AddressEcho))
+        .unwrap()
+        .register_relative(0x6010..=0x601f, Box::new(AddressEcho))
+        .unwrap();
+
+    assert_eq!(0x03, am.read(0x4013));
+    assert_eq!(0x03, am.read_mut(0x6013));
+    assert_eq!(Ok(0x0f), am.write(0x401f, 0xff));
+}
+
+#[test]
+fn should_relocate_relatively_addressed_memory() {
+    let mut am = crate::address_map::AddressMap::<u16>::new()
+        .register_relative(
+            0x2000..=0x20ff,
+            Box::new(Memory::<ReadWrite>::new(0x0000, 0x00ff)),
+        )
+        .unwrap();
+
+    assert!(am.write(0x2010, 0xea).is_ok());
+    assert_eq!(0xea, am.read(0x2010));
+}
+
+#[test]
+fn should_relocate_relatively_addressed_memory_with_a_non_zero_start() {
+    let mut am = crate::address_map::AddressMap::<u16>::new()
+        .register_relative(
+            0x2000..=0x27ff,
+            Box::new(Memory::<ReadWrite>::new(0x2000, 0x27ff)),
+        )
+        .unwrap()
+        .register_mirrored_relative(
+            0x8000..=0x80ff,
+            vec![0x9000..=0x91ff],
+            Box::new(Memory::<ReadOnly>::new(0xc000, 0xc0ff).load(vec![0x60; 0x100])),
+        )
+        .unwrap();
+
+    assert!(am.write(0x2010, 0xea).is_ok());
+    assert_eq!(0xea, am.read(0x2010));
+    assert_eq!(0xea, am.read_mut(0x2010));
+    assert_eq!(0x60, am.read(0x80ff));
+    assert_eq!(0x60, am.read(0x9180));
+}
+
+#[test]
+fn should_pass_offsets_to_relatively_addressed_overlays() {
+    let mut am = u16_address_map!().unwrap();
+    am.overlay_relative(0x4010..=0x401f, 1, Routing::ALL, Box::new(AddressEcho))
+        .unwrap();
+    am.overlay_relative(
+        0x6000..=0x60ff,
+        1,
+        Routing::READS,
+        Box::new(Memory::<ReadOnly>::new(0xe000, 0xe0ff).load(vec![0xea; 0x100])),
+    )
+    .unwrap();
+
+    assert_eq!(0x03, am.read(0x4013));
+    assert_eq!(Ok(0x0f), am.write(0x401f, 0xff));
+    assert_eq!(0xea, am.read(0x6080));
+}
+
+#[test]
+fn should_pass_folded_offsets_to_relatively_addressed_mirrors() {
+    let am = crate::address_map::AddressMap::<u16>::new()
+        .register_masked_relative(0x2000..=0x3fff, 0x0007, Box::new(AddressEcho))
+        .unwrap()
+        .register_mirrored_relative(
+            0x6000..=0x600f,
+            vec![0x7000..=0x701f],
+            Box::new(AddressEcho),
+        )
+        .unwrap();
+
+    assert_eq!(0x05, am.read(0x3ffd));
+    assert_eq!(0x03, am.read(0x7013));
+    assert_eq!(0x03, am.read(0x6003));
+}
+
+#[test]
+fn should_return_typed_registration_errors() {
+    use crate::address_map::RegistrationError;
+
+    let mut am = generate_populated_address_map();
+
+    assert_eq!(
+        Some(RegistrationError::Overlap {
+            range: 0x1000..=0x2fff,
+            existing: 0x0000..=0x1fff,
+            name: "Memory<ReadWrite>".to_string()
+        }),
+        am.clone()
+            .register(
+                0x1000..=0x2fff,
+                Box::new(Memory::<ReadWrite>::new(0x1000, 0x2fff))
+            )
+            .err()
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 0x3000..=0x2fff;
+    assert!(matches!(
+        am.clone()
+            .register(empty, Box::new(StatusRegister::default())),
+        Err(RegistrationError::Empty { .. })
+    ));
+    assert_eq!(
+        Some(RegistrationError::NotRegistered {
+            range: 0x2000..=0x2fff
+        }),
+        am.unregister(0x2000..=0x2fff).err()
+    );
+}
+
+#[test]
+fn should_return_typed_write_errors_at_the_bus_address() {
+    let mut am = generate_populated_address_map()
+        .register_masked(
+            0x6000..=0x7fff,
+            0x00ff,
+            Box::new(Memory::<ReadOnly>::new(0x6000, 0x60ff)),
+        )
+        .unwrap();
+
+    assert_eq!(
+        Err(WriteError::Unmapped {
+            address: 0x3000,
+            value: 0x01
+        }),
+        am.write(0x3000, 0x01)
+    );
+    assert_eq!(
+        Err(WriteError::ReadOnly {
+            address: 0x7f10,
+            value: 0x01
+        }),
+        am.write(0x7f10, 0x01)
+    );
+
+    let err: Box<dyn std::error::Error> = Box::new(am.write(0x8000, 0x02).unwrap_err());
+    assert_eq!("write of 0x02 to read-only address 0x8000", err.to_string());
+}
+
+/// An interrupt controller asserting the line selected by its register,
+/// with bit 0 asserting irq and bit 1 asserting nmi.
+#[derive(Clone, Default)]
+struct InterruptController {
+    lines: u8,
+}
+
+impl Addressable<u16> for InterruptController {
+    fn read(&self, _: u16) -> u8 {
+        self.lines
+    }
+
+    fn write(&mut self, _: u16, data: u8) -> Result<u8, WriteError<u16>> {
+        self.lines = data;
+        Ok(data)
+    }
+
+    fn irq(&self) -> bool {
+        self.lines & 0x01 != 0
+    }
+
+    fn nmi(&self) -> bool {
+        self.lines & 0x02 != 0
+    }
+}
+
+#[test]
+fn should_assert_interrupts_from_registered_devices() {
+    let mut am = u16_address_map!(0x0000..=0x1fff, Memory::<ReadWrite>::new(0x0000, 0x1fff))
+        .unwrap()
+        .register(0x4000..=0x4000, Box::new(InterruptController::default()))
+        .unwrap();
+    assert!(!am.irq() && !am.nmi());
+
+    am.write(0x4000, 0x03).unwrap();
+    assert!(am.irq() && am.nmi());
+
+    // removing the ram shifts the controller within the map.
+    am.unregister(0x0000..=0x1fff).unwrap();
+    assert!(am.irq() && am.nmi());
+
+    am.replace(
+        0x4000..=0x4000,
+        Box::new(Memory::<ReadWrite>::new(0x4000, 0x4000)),
+    )
+    .unwrap();
+    assert!(!am.irq() && !am.nmi());
+}
+
+#[test]
+fn should_assert_interrupts_from_overlays() {
+    let mut am = u16_address_map!().unwrap();
+    am.overlay(
+        0x4000..=0x4000,
+        1,
+        Routing::ALL,
+        Box::new(InterruptController::default()),
+    )
+    .unwrap();
+
+    am.write(0x4000, 0x02).unwrap();
+    assert!(!am.irq() && am.nmi());
+
+    am.unregister(0x4000..=0x4000).unwrap();
+    assert!(!am.nmi());
+}
@@ -13,13 +13,57 @@ pub trait Offset {
     }
 }
 
+/// Halt identifies the instruction that halted a cpu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Halt {
+    /// The cpu locked up on an undocumented JAM.
+    Jam,
+    /// The cpu stopped its clock on a STP.
+    Stop,
+    /// The cpu is idling on a WAI until an interrupt is asserted.
+    Wait,
+}
+
+/// StopReason describes why a cpu stopped executing, where F is the fault
+/// type of the cpu.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason<F> {
+    /// The cycle budget was exhausted or the requested step completed.
+    Budget,
+    /// The predicate was satisfied.
+    Predicate,
+    /// The cpu halted on a fault.
+    Fault(F),
+    /// The cpu halted on a JAM or STP, or is waiting on a WAI.
+    Halted(Halt),
+}
+
 pub trait CPU<T> {
+    /// The fault reported when a fault stops the cpu.
+    type Fault;
+
     fn run(self, cycles: usize) -> StepState<T>;
+
+    /// Executes a single cycle, carrying any instruction left in flight into
+    /// the next invocation.
+    fn step_cycle(self) -> (StepState<T>, StopReason<Self::Fault>);
+
+    /// Executes the remainder of the instruction in flight or, between
+    /// instructions, the next instruction or interrupt.
+    fn step_instruction(self) -> (StepState<T>, StopReason<Self::Fault>);
+
+    /// Runs the cpu for up to the specified number of cycles, evaluating the
+    /// predicate after each cycle and stopping once it returns true or the
+    /// cpu halts. Predicates may inspect the program counter, registers or
+    /// memory of the cpu, including the write made on the last cycle.
+    fn run_until<P>(self, cycles: usize, predicate: P) -> (StepState<T>, StopReason<Self::Fault>)
+    where
+        P: FnMut(&T) -> bool;
 }
 
-/// Stores state between run invocations. The remaining field signifies noop cycles
-/// between instructions. This is to function as a placeholder when a run
-/// invocation returns while inbetween multi-cycle instructions.
+/// Stores state between run invocations. The remaining field signifies the
+/// cycles of an operation that straddled the end of a run invocation, such as
+/// when it returned partway through a multi-cycle instruction.
 #[derive(Clone)]
 pub struct StepState<T> {
     remaining: usize, // Remaining cycles in operation
@@ -50,6 +94,18 @@ impl<T> StepState<T> {
     pub fn unwrap(self) -> T {
         self.cpu
     }
+
+    /// Maps the enclosed cpu to a new value while retaining the remaining
+    /// cycles, such as when converting between cpu variants.
+    pub fn map<U, F>(self, f: F) -> StepState<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        StepState {
+            remaining: self.remaining,
+            cpu: f(self.cpu),
+        }
+    }
 }
 
 impl<T> From<T> for StepState<T> {
@@ -64,6 +120,10 @@ impl<T> From<StepState<T>> for (usize, T) {
     }
 }
 
+pub mod mops;
 #[macro_use]
 pub mod mos6502;
 pub mod register;
+pub mod ricoh2a03;
+pub mod w65c02;
+pub mod w65c816;
@@ -0,0 +1,146 @@
+//! Provides the cycle by cycle schedule of microcode that an instruction is
+//! generated into, shared by each cpu with its own microcode.
+
+use crate::cpu::{Cyclable, Offset};
+
+/// BusCycle describes the microcode of a cpu that accesses memory, allowing
+/// an operation to schedule its memory accesses on the cycles they occur.
+pub trait BusCycle: Sized {
+    /// The address type of the cpu's address map.
+    type Address: Copy;
+
+    /// Returns the microcode to read the provided address.
+    fn read(address: Self::Address) -> Self;
+
+    /// Returns the microcode to fetch the byte at an offset from the address
+    /// of an instruction.
+    fn fetch(address: Self::Address, offset: usize) -> Self;
+
+    /// Returns the microcode to advance the program counter past an
+    /// instruction of the provided size.
+    fn advance(offset: usize) -> Self;
+
+    /// Returns true if the microcode reads from or writes to memory.
+    fn accesses_memory(&self) -> bool;
+}
+
+/// MOps functions as a concrete wrapper around a microcode operation with
+/// metadata around sizing and cycles. This trait does NOT represent a cycle
+/// but rather the microcode equivalent of a CPU instruction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MOps<M> {
+    offset: usize,
+    cycles: usize,
+    steps: Vec<Vec<M>>,
+    sample: Option<usize>,
+}
+
+impl<M: Clone> MOps<M> {
+    /// Instantiates a new operation, scheduling all provided microcode on the
+    /// final cycle.
+    pub fn new(offset: usize, cycles: usize, microcode: Vec<M>) -> Self {
+        let mut steps = vec![Vec::new(); cycles.saturating_sub(1)];
+        steps.push(microcode);
+
+        Self {
+            offset,
+            cycles,
+            steps,
+            sample: None,
+        }
+    }
+
+    /// Appends additional microcode to the end of the operation, executing it
+    /// on the final cycle.
+    pub fn with_microcode(mut self, microcode: Vec<M>) -> Self {
+        if let Some(last) = self.steps.last_mut() {
+            last.extend(microcode);
+        }
+        self
+    }
+
+    /// Appends additional microcode to the operation, executing it on the
+    /// provided cycle, counting from 1 at the opcode fetch. Cycles beyond the
+    /// end of the operation are clamped to the final cycle.
+    pub fn with_microcode_on_cycle(mut self, cycle: usize, microcode: Vec<M>) -> Self {
+        let idx = cycle.max(1).min(self.steps.len()) - 1;
+        self.steps[idx].extend(microcode);
+        self
+    }
+
+    /// Appends each provided microcode to the operation, in order and one
+    /// per cycle, beginning on the provided cycle.
+    pub fn with_microcode_from_cycle(self, cycle: usize, microcode: Vec<M>) -> Self {
+        microcode
+            .into_iter()
+            .enumerate()
+            .fold(self, |mops, (idx, mc)| {
+                mops.with_microcode_on_cycle(cycle + idx, vec![mc])
+            })
+    }
+
+    /// Returns the cycle, counting from 1 at the opcode fetch, on which the
+    /// operand is read following the other reads of the operation, if any.
+    pub(crate) fn sample_cycle(&self) -> Option<usize> {
+        self.sample
+    }
+}
+
+impl<M: BusCycle + Clone> MOps<M> {
+    /// Schedules the fetch of the opcode and operand bytes of the
+    /// instruction at the provided address, one per cycle beginning with the
+    /// first.
+    pub fn with_fetch(self, pc: M::Address) -> Self {
+        (0..self.offset).fold(self, |mops, idx| {
+            mops.with_microcode_on_cycle(idx + 1, vec![M::fetch(pc, idx)])
+        })
+    }
+
+    /// Schedules reads of the provided addresses, in order and one per cycle,
+    /// on the latest cycles of the operation that don't otherwise access
+    /// memory. This places the read of an operand on the final cycle and the
+    /// reads of a read-modify-write prior to its writes. Every read must fit
+    /// within the cycles of the operation. When other reads precede the
+    /// final read, its cycle is recorded as the cycle on which the operand
+    /// is sampled.
+    pub fn with_reads(mut self, addresses: Vec<M::Address>) -> Self {
+        let free: Vec<usize> = (0..self.steps.len())
+            .rev()
+            .filter(|&idx| !self.steps[idx].iter().any(M::accesses_memory))
+            .collect();
+        debug_assert!(
+            addresses.len() <= free.len(),
+            "{} reads scheduled on {} free cycles",
+            addresses.len(),
+            free.len()
+        );
+
+        if addresses.len() > 1 {
+            self.sample = free.first().map(|idx| idx + 1);
+        }
+        for (addr, idx) in addresses.into_iter().rev().zip(free) {
+            self.steps[idx].insert(0, M::read(addr));
+        }
+        self
+    }
+}
+
+impl<M> Cyclable for MOps<M> {
+    fn cycles(&self) -> usize {
+        self.cycles
+    }
+}
+
+impl<M> Offset for MOps<M> {
+    fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<M: BusCycle + Clone> From<MOps<M>> for Vec<Vec<M>> {
+    fn from(src: MOps<M>) -> Self {
+        let offset = src.offset();
+
+        src.with_microcode(vec![M::advance(offset)]).steps
+    }
+}
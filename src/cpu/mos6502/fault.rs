@@ -0,0 +1,106 @@
+use crate::address_map::WriteError;
+use crate::cpu::mos6502::{operations::DecodeError, MOS6502};
+use std::fmt;
+
+/// CpuFault represents the conditions under which the cpu is unable to
+/// continue executing a program as written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CpuFault {
+    /// The byte at the program counter does not decode to a known opcode,
+    /// carrying the error raised while decoding it.
+    IllegalOpcode(DecodeError),
+    /// A device rejected a write, such as a write to read-only memory.
+    BusWrite {
+        address: u16,
+        value: u8,
+        reason: WriteError<u16>,
+    },
+    /// An access targeted an address that isn't backed by any device.
+    UnmappedAccess(u16),
+    /// The cpu executed a JAM and locked up. Only a reset will recover it,
+    /// regardless of the fault policy.
+    Jam,
+    /// The cpu executed a 65C02 STP, stopping its clock until it is reset.
+    Stop,
+}
+
+impl std::error::Error for CpuFault {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IllegalOpcode(err) => Some(err),
+            Self::BusWrite { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CpuFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IllegalOpcode(err) => write!(f, "illegal opcode {:#04x}", err.opcode()),
+            Self::BusWrite {
+                address,
+                value,
+                reason,
+            } => write!(
+                f,
+                "failed to write {:#04x} to {:#06x}: {}",
+                value, address, reason
+            ),
+            Self::UnmappedAccess(address) => {
+                write!(f, "access to unmapped address {:#06x}", address)
+            }
+            Self::Jam => write!(f, "cpu jammed"),
+            Self::Stop => write!(f, "cpu stopped"),
+        }
+    }
+}
+
+/// Fault captures a CpuFault along with the program counter of the
+/// instruction that raised it and the cycle it was raised on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fault {
+    pub kind: CpuFault,
+    pub pc: u16,
+    pub cycle: usize,
+}
+
+impl Fault {
+    pub fn new(kind: CpuFault, pc: u16, cycle: usize) -> Self {
+        Self { kind, pc, cycle }
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at pc {:#06x} on cycle {}",
+            self.kind, self.pc, self.cycle
+        )
+    }
+}
+
+impl std::error::Error for Fault {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// FaultPolicy defines how the cpu recovers from a fault.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum FaultPolicy {
+    /// Stops the cpu at the faulting instruction. A halted cpu will not
+    /// execute any further cycles until it is reset.
+    #[default]
+    Halt,
+    /// Continues execution as though the fault had not occurred. Failed
+    /// writes are dropped and illegal opcodes are skipped as a single byte,
+    /// two cycle, no-op.
+    Ignore,
+    /// Hands the cpu and the fault to a handler, continuing execution from
+    /// the cpu it returns. The fetch of an illegal opcode takes a single
+    /// cycle and leaves the program counter in place for the handler to
+    /// update.
+    Trap(fn(MOS6502, Fault) -> MOS6502),
+}
@@ -8,6 +8,7 @@ use crate::cpu::mos6502::register::{ByteRegisters, ProgramStatusFlags, WordRegis
 /// 6502 emulator.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Microcode {
+    ReadMemory(ReadMemory),
     WriteMemory(WriteMemory),
     SetProgramStatusFlagState(SetProgramStatusFlagState),
     Write8bitRegister(Write8bitRegister),
@@ -16,6 +17,25 @@ pub enum Microcode {
     Write16bitRegister(Write16bitRegister),
     Inc16bitRegister(Inc16bitRegister),
     Dec16bitRegister(Dec16bitRegister),
+    SetNmiLatch(SetNmiLatch),
+    Halt(Halt),
+    SetWaiting(SetWaiting),
+    SetStall(SetStall),
+    SetSync(SetSync),
+}
+
+/// Represents a read of the memory location specified by the address field.
+/// The value read is discarded, as operands are resolved when an instruction
+/// is decoded, but the read allows devices to act on the access.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ReadMemory {
+    pub address: u16,
+}
+
+impl ReadMemory {
+    pub fn new(address: u16) -> Self {
+        Self { address }
+    }
 }
 
 /// Represents a write of the value to the memory location specified by the
@@ -134,6 +154,90 @@ impl Dec16bitRegister {
     }
 }
 
+// Interrupts
+
+/// Represents a store of the last sampled level of the non-maskable
+/// interrupt line. This is used to detect the edge on which an NMI triggers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetNmiLatch {
+    pub value: bool,
+}
+
+impl SetNmiLatch {
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+/// Represents the cause of the cpu halting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HaltReason {
+    /// The cpu locked up on an undocumented JAM opcode.
+    Jam,
+    /// The clock was stopped by a 65C02 `stp`.
+    Stop,
+}
+
+/// Represents the cpu halting, such as on a JAM, until it is reset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Halt {
+    pub reason: HaltReason,
+}
+
+impl Halt {
+    pub fn new(reason: HaltReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Represents the cpu entering or leaving the low power wait state of a
+/// 65C02 `wai`, where it idles until an interrupt is asserted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetWaiting {
+    pub value: bool,
+}
+
+impl SetWaiting {
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+/// Represents setting the number of cycles remaining in a stall, such as one
+/// raised for the duration of a DMA transfer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetStall {
+    pub cycles: usize,
+}
+
+impl SetStall {
+    pub fn new(cycles: usize) -> Self {
+        Self { cycles }
+    }
+}
+
+/// Represents driving the SYNC output, which is held high for the opcode
+/// fetch cycle of each instruction and interrupt sequence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetSync {
+    pub value: bool,
+}
+
+impl SetSync {
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! gen_read_memory_microcode {
+    ($addr:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::ReadMemory(
+            $crate::cpu::mos6502::microcode::ReadMemory::new($addr),
+        )
+    };
+}
+
 #[allow(unused_macros)]
 macro_rules! gen_write_memory_microcode {
     ($addr:expr, $value:expr) => {
@@ -205,3 +309,48 @@ macro_rules! gen_dec_16bit_register_microcode {
         )
     };
 }
+
+#[allow(unused_macros)]
+macro_rules! gen_set_nmi_latch_microcode {
+    ($value:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::SetNmiLatch(
+            $crate::cpu::mos6502::microcode::SetNmiLatch::new($value),
+        )
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! gen_halt_microcode {
+    ($reason:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::Halt(
+            $crate::cpu::mos6502::microcode::Halt::new($reason),
+        )
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! gen_set_waiting_microcode {
+    ($value:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::SetWaiting(
+            $crate::cpu::mos6502::microcode::SetWaiting::new($value),
+        )
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! gen_set_stall_microcode {
+    ($cycles:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::SetStall(
+            $crate::cpu::mos6502::microcode::SetStall::new($cycles),
+        )
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! gen_set_sync_microcode {
+    ($value:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::SetSync(
+            $crate::cpu::mos6502::microcode::SetSync::new($value),
+        )
+    };
+}
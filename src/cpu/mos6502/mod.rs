@@ -1,13 +1,13 @@
 extern crate parcel;
-use std::convert::TryFrom;
+use std::collections::VecDeque;
 use std::ops::RangeInclusive;
 
 use crate::{
     address_map::{
         memory::{Memory, ReadWrite},
-        AddressMap, Addressable,
+        AddressMap, Addressable, RegistrationError, Routing, WriteError,
     },
-    cpu::{register::Register, StepState, CPU},
+    cpu::{register::Register, Halt, Offset, StepState, StopReason, CPU},
 };
 
 #[macro_use]
@@ -16,6 +16,9 @@ pub mod microcode;
 #[cfg(test)]
 mod tests;
 
+pub mod fault;
+use fault::{CpuFault, Fault, FaultPolicy};
+
 pub mod register;
 use register::{
     ByteRegisters, GPRegister, GeneralPurpose, ProcessorStatus, ProgramCounter, ProgramStatusFlags,
@@ -23,7 +26,7 @@ use register::{
 };
 
 pub mod operations;
-use operations::Operation;
+use operations::{Interrupt, MOps, Operation, Vectors};
 
 pub trait Generate<T, U> {
     fn generate(self, cpu: &T) -> U;
@@ -33,16 +36,74 @@ pub trait Execute<T> {
     fn execute(self, cpu: T) -> T;
 }
 
+/// Variant identifies the member of the 6502 family that a cpu emulates,
+/// determining the instruction set it decodes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Variant {
+    /// The original NMOS 6502.
+    #[default]
+    MOS6502,
+    /// The WDC 65C02 CMOS revision of the 6502.
+    W65C02,
+    /// The Ricoh 2A03 of the NES, an NMOS 6502 with decimal mode removed.
+    Ricoh2A03,
+}
+
+/// The cycles of an operation that remain to be executed, carried between
+/// runs when a cycle budget ends partway through the operation.
+#[derive(Debug, Clone, Default)]
+struct InFlight {
+    pc: u16,
+    steps: VecDeque<Vec<microcode::Microcode>>,
+    instruction: bool,
+    /// The number of steps remaining when the operand of the instruction is
+    /// read, at which point the instruction is generated again such that the
+    /// operand reflects the reads preceding it.
+    resample: Option<usize>,
+}
+
+impl InFlight {
+    fn new(pc: u16, mops: MOps) -> Self {
+        let instruction = mops.offset() > 0;
+        let sample = mops.sample_cycle().filter(|_| instruction);
+        let steps: VecDeque<_> = Vec::from(mops).into();
+
+        Self {
+            pc,
+            instruction,
+            resample: sample.map(|cycle| steps.len() + 1 - cycle),
+            steps,
+        }
+    }
+}
+
 /// MOS6502 represents the 6502 CPU
 #[derive(Debug, Clone)]
 pub struct MOS6502 {
-    address_map: AddressMap<u16>,
+    pub(crate) address_map: AddressMap<u16>,
     pub acc: GeneralPurpose,
     pub x: GeneralPurpose,
     pub y: GeneralPurpose,
     pub sp: StackPointer,
     pub pc: ProgramCounter,
     pub ps: ProcessorStatus,
+    irq_line: bool,
+    nmi_line: bool,
+    nmi_latch: bool,
+    rdy_line: bool,
+    so_line: bool,
+    sync: bool,
+    last_write: Option<(u16, u8)>,
+    fault_policy: FaultPolicy,
+    bus_fault: Option<CpuFault>,
+    halted: Option<Fault>,
+    waiting: bool,
+    stall: usize,
+    in_flight: InFlight,
+    cycles: usize,
+    retired: usize,
+    variant: Variant,
+    vectors: Vectors,
 }
 
 impl MOS6502 {
@@ -88,22 +149,150 @@ impl MOS6502 {
         mut self,
         space: RangeInclusive<u16>,
         addr_space: impl Addressable<u16> + 'static,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, RegistrationError<u16>> {
         let am = self.address_map;
         self.address_map = am.register(space, Box::new(addr_space))?;
 
         Ok(self)
     }
 
-    /// emulates the reset process of the CPU.
+    /// Registers an address space that receives offsets relative to the start
+    /// of the range rather than absolute addresses. See
+    /// `AddressMap::register_relative`.
+    pub fn register_relative_address_space(
+        mut self,
+        space: RangeInclusive<u16>,
+        addr_space: impl Addressable<u16> + 'static,
+    ) -> Result<Self, RegistrationError<u16>> {
+        let am = self.address_map;
+        self.address_map = am.register_relative(space, Box::new(addr_space))?;
+
+        Ok(self)
+    }
+
+    /// Registers an address space of which only the address lines in the mask
+    /// are decoded, repeating the addressable across the range. See
+    /// `AddressMap::register_masked`.
+    pub fn register_masked_address_space(
+        mut self,
+        space: RangeInclusive<u16>,
+        mask: u16,
+        addr_space: impl Addressable<u16> + 'static,
+    ) -> Result<Self, RegistrationError<u16>> {
+        let am = self.address_map;
+        self.address_map = am.register_masked(space, mask, Box::new(addr_space))?;
+
+        Ok(self)
+    }
+
+    /// Registers an address space along with a list of ranges that mirror it,
+    /// all routed to the same addressable. See
+    /// `AddressMap::register_mirrored`.
+    pub fn register_mirrored_address_space(
+        mut self,
+        space: RangeInclusive<u16>,
+        mirrors: Vec<RangeInclusive<u16>>,
+        addr_space: impl Addressable<u16> + 'static,
+    ) -> Result<Self, RegistrationError<u16>> {
+        let am = self.address_map;
+        self.address_map = am.register_mirrored(space, mirrors, Box::new(addr_space))?;
+
+        Ok(self)
+    }
+
+    /// Layers an addressable over a range of a running cpu's address map at
+    /// a priority, intercepting the accesses selected by its routing. See
+    /// `AddressMap::overlay`.
+    pub fn overlay_address_space(
+        &mut self,
+        space: RangeInclusive<u16>,
+        priority: u8,
+        routing: Routing,
+        addr_space: impl Addressable<u16> + 'static,
+    ) -> Result<(), RegistrationError<u16>> {
+        self.address_map
+            .overlay(space, priority, routing, Box::new(addr_space))
+    }
+
+    /// Layers an addressable over a range of a running cpu's address map in
+    /// the same manner as `overlay_address_space`, passing it offsets
+    /// relative to the start of the range. See `AddressMap::overlay_relative`.
+    pub fn overlay_relative_address_space(
+        &mut self,
+        space: RangeInclusive<u16>,
+        priority: u8,
+        routing: Routing,
+        addr_space: impl Addressable<u16> + 'static,
+    ) -> Result<(), RegistrationError<u16>> {
+        self.address_map
+            .overlay_relative(space, priority, routing, Box::new(addr_space))
+    }
+
+    /// Changes the accesses intercepted by the overlay registered to a range
+    /// of a running cpu's address map.
+    pub fn set_overlay_routing(
+        &mut self,
+        space: RangeInclusive<u16>,
+        routing: Routing,
+    ) -> Result<(), RegistrationError<u16>> {
+        self.address_map.set_routing(space, routing)
+    }
+
+    /// Removes the address space or overlay registered to a range of a
+    /// running cpu's address map, returning its addressable.
+    pub fn unregister_address_space(
+        &mut self,
+        space: RangeInclusive<u16>,
+    ) -> Result<Box<dyn Addressable<u16>>, RegistrationError<u16>> {
+        self.address_map.unregister(space)
+    }
+
+    /// Swaps the addressable registered to a range of a running cpu's address
+    /// map, such as to hot swap a cartridge, returning the previous
+    /// addressable.
+    pub fn replace_address_space(
+        &mut self,
+        space: RangeInclusive<u16>,
+        addr_space: impl Addressable<u16> + 'static,
+    ) -> Result<Box<dyn Addressable<u16>>, RegistrationError<u16>> {
+        self.address_map.replace(space, Box::new(addr_space))
+    }
+
+    /// emulates the reset process of the CPU. Over 7 cycles the cpu performs
+    /// three stack pushes with writes suppressed, decrementing the stack
+    /// pointer, disables interrupts and loads the program counter from the
+    /// reset vector. All other registers and flags are left unchanged, with
+    /// the exception of the 65C02 which also clears decimal mode. Resetting
+    /// recovers a cpu that has halted or is waiting on an interrupt, abandons
+    /// any instruction in flight, DMA stall or unserviced NMI edge, and counts
+    /// the first cycle of the sequence with the remaining cycles carried into
+    /// the next run.
     pub fn reset(self) -> StepState<Self> {
-        let mut cpu = MOS6502::with_addressmap(self.address_map);
-        let lsb: u8 = cpu.address_map.read(0x7ffc);
-        let msb: u8 = cpu.address_map.read(0x7ffd);
+        let mut cpu = self;
+        let vector = cpu.vectors.reset;
+        let lsb: u8 = cpu.address_map.read(vector);
+        let msb: u8 = cpu.address_map.read(vector.wrapping_add(1));
 
         cpu.pc = ProgramCounter::default().write(u16::from_le_bytes([lsb, msb]));
-        cpu.sp = StackPointer::default();
-        StepState::new(6, cpu)
+        cpu.sp = StackPointer::default().write(cpu.sp.read().wrapping_sub(3));
+        cpu.ps.interrupt_disable = true;
+        if cpu.variant == Variant::W65C02 {
+            cpu.ps.decimal = false;
+        }
+        cpu.bus_fault = None;
+        cpu.halted = None;
+        cpu.waiting = false;
+        cpu.stall = 0;
+        // an nmi line that is still asserted doesn't present a new edge.
+        cpu.nmi_latch = cpu.nmi_asserted();
+        cpu.cycles += 1;
+        cpu.in_flight = InFlight {
+            pc: cpu.pc.read(),
+            steps: vec![vec![]; 6].into(),
+            instruction: false,
+            resample: None,
+        };
+        StepState::new(7, cpu)
     }
 
     /// Provides a wrapper to update a general-purpose register in a way that
@@ -137,6 +326,444 @@ impl MOS6502 {
         self.ps = reg;
         self
     }
+
+    /// Sets the level of the host driven maskable interrupt request line,
+    /// returning the entire cpu after modification. IRQ is level-triggered
+    /// and is serviced between instructions for as long as it is asserted
+    /// and interrupts are not disabled.
+    pub fn with_irq_line(mut self, asserted: bool) -> Self {
+        self.irq_line = asserted;
+        self
+    }
+
+    /// Sets the level of the host driven non-maskable interrupt line,
+    /// returning the entire cpu after modification. NMI is edge-triggered and
+    /// is serviced once each time the line transitions to asserted.
+    pub fn with_nmi_line(mut self, asserted: bool) -> Self {
+        self.nmi_line = asserted;
+        self
+    }
+
+    /// Sets the level of the RDY input, returning the entire cpu after
+    /// modification. While RDY is low the cpu halts on its next read cycle,
+    /// with the halted cycles counted against any cycle budget. Writes are
+    /// not halted, but as every instruction and interrupt begins with a read
+    /// the cpu idles between instructions until RDY is raised.
+    pub fn with_rdy_line(mut self, ready: bool) -> Self {
+        self.rdy_line = ready;
+        self
+    }
+
+    /// Sets the level of the set overflow input, returning the entire cpu
+    /// after modification. The overflow flag is set each time the line
+    /// transitions to asserted, corresponding to the falling edge of the
+    /// active-low SO pin.
+    pub fn with_so_line(mut self, asserted: bool) -> Self {
+        if asserted && !self.so_line {
+            self.ps.overflow = true;
+        }
+        self.so_line = asserted;
+        self
+    }
+
+    /// Returns true if the RDY input is high and the cpu is free to execute.
+    pub fn ready(&self) -> bool {
+        self.rdy_line
+    }
+
+    /// Returns the level of the SYNC output, which is high if the last cycle
+    /// executed was the opcode fetch of an instruction or interrupt.
+    pub fn sync(&self) -> bool {
+        self.sync
+    }
+
+    /// Returns the address and value of the write made on the last cycle
+    /// executed, if any.
+    pub fn last_write(&self) -> Option<(u16, u8)> {
+        self.last_write
+    }
+
+    /// Returns true if either the host or any device in the address map is
+    /// asserting the maskable interrupt request line.
+    pub fn irq_asserted(&self) -> bool {
+        self.irq_line || self.address_map.irq()
+    }
+
+    /// Returns true if either the host or any device in the address map is
+    /// asserting the non-maskable interrupt line.
+    pub fn nmi_asserted(&self) -> bool {
+        self.nmi_line || self.address_map.nmi()
+    }
+
+    /// Sets the policy used to recover from faults, returning the entire cpu
+    /// after modification.
+    pub fn with_fault_policy(mut self, policy: FaultPolicy) -> Self {
+        self.fault_policy = policy;
+        self
+    }
+
+    /// Relocates the NMI, reset and IRQ vectors, returning the entire cpu
+    /// after modification.
+    pub fn with_vectors(mut self, vectors: Vectors) -> Self {
+        self.vectors = vectors;
+        self
+    }
+
+    /// Sets the variant of the 6502 family that is emulated. Variants are
+    /// exposed as their own cpu types, such as the W65C02, that wrap a
+    /// MOS6502 set to the corresponding variant.
+    pub(crate) fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Returns the variant of the 6502 family that is emulated.
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Returns true if ADC and SBC treat their operands as packed BCD. This
+    /// requires that the decimal flag is set and that the variant implements
+    /// decimal mode, with the Ricoh 2A03 ignoring the flag entirely.
+    pub fn decimal_mode(&self) -> bool {
+        self.ps.decimal && self.variant != Variant::Ricoh2A03
+    }
+
+    /// Stalls the cpu for the provided number of cycles, such as for the
+    /// duration of a DMA transfer, returning the entire cpu after
+    /// modification. Stalled cycles are counted but execute nothing and any
+    /// interrupts are deferred until the stall ends.
+    pub fn with_stall(mut self, cycles: usize) -> Self {
+        self.stall += cycles;
+        self
+    }
+
+    /// Returns the number of cycles remaining in the current stall.
+    pub fn stalled(&self) -> usize {
+        self.stall
+    }
+
+    /// Returns true if the cpu is idling in the wait state entered by a
+    /// 65C02 `wai`.
+    pub fn waiting(&self) -> bool {
+        self.waiting
+    }
+
+    /// Returns the fault the cpu halted on, if it has halted.
+    pub fn halted(&self) -> Option<&Fault> {
+        self.halted.as_ref()
+    }
+
+    /// Returns the number of cycles executed since the cpu was instantiated.
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Returns the number of instructions retired since the cpu was
+    /// instantiated. An instruction is retired once its final cycle has
+    /// executed, while interrupt sequences and idle cycles are not counted.
+    pub fn retired(&self) -> usize {
+        self.retired
+    }
+
+    /// Returns the number of cycles remaining in the operation in flight,
+    /// which is zero between operations.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.steps.len()
+    }
+
+    /// Executes the next instruction, or pending interrupt, to completion
+    /// returning the number of cycles it took. Any fault raised along the way
+    /// is returned once the fault policy has been applied, leaving the cpu in
+    /// whatever state the policy recovered it to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mainspring::address_map::memory::{Memory, ReadOnly};
+    /// use mainspring::cpu::mos6502::{fault::CpuFault, register::ProgramCounter, MOS6502};
+    /// use mainspring::cpu::register::Register;
+    ///
+    /// // a sta to the rom it is executing from.
+    /// let mut cpu = MOS6502::default()
+    ///     .register_address_space(
+    ///         0x0200..=0x02ff,
+    ///         Memory::<ReadOnly>::new(0x0200, 0x02ff).load(vec![0x8d, 0x00, 0x02]),
+    ///     )
+    ///     .unwrap()
+    ///     .with_pc_register(ProgramCounter::with_value(0x0200));
+    ///
+    /// let fault = cpu.try_step().unwrap_err();
+    /// assert_eq!(0x0200, fault.pc);
+    /// assert!(matches!(fault.kind, CpuFault::BusWrite { address: 0x0200, .. }));
+    /// assert!(cpu.halted().is_some());
+    /// ```
+    pub fn try_step(&mut self) -> Result<usize, Fault> {
+        let (cpu, result) = std::mem::replace(self, Self::unmapped()).step(usize::MAX);
+        *self = cpu;
+        result
+    }
+
+    /// Executes up to `budget` cycles of the operation in flight or, if
+    /// there is none, the next operation, returning the cpu along with the
+    /// number of cycles executed or the first fault raised. A read cycle
+    /// while RDY is low is counted without executing, returning after the
+    /// halted cycle.
+    fn step(self, budget: usize) -> (Self, Result<usize, Fault>) {
+        if let Some(fault) = self.halted.clone() {
+            return (self, Err(fault));
+        }
+
+        let start = self.cycles;
+        // every operation begins with a read, so the next operation isn't
+        // decoded until RDY is raised.
+        let (mut cpu, mut fault) = if self.in_flight.steps.is_empty() && self.rdy_line {
+            self.begin_operation()
+        } else {
+            (self, None)
+        };
+
+        while cpu.cycles - start < budget && cpu.halted.is_none() {
+            let write = cpu.in_flight.steps.front().map(|step| {
+                step.iter()
+                    .any(|mc| matches!(mc, microcode::Microcode::WriteMemory(_)))
+            });
+
+            if !cpu.rdy_line && write != Some(true) {
+                cpu.sync = false;
+                cpu.cycles += 1;
+                break;
+            } else if write.is_none() {
+                break;
+            }
+
+            let (next, bus_fault) = cpu.execute_in_flight_cycle();
+            cpu = next;
+            fault = fault.or(bus_fault);
+        }
+
+        // the cpu may also have halted itself, as on a jam.
+        let result = fault
+            .or_else(|| cpu.halted.clone())
+            .map_or(Ok(cpu.cycles - start), Err);
+        (cpu, result)
+    }
+
+    /// Decodes the next operation, placing it in flight along with any fault
+    /// raised while decoding.
+    fn begin_operation(self) -> (Self, Option<Fault>) {
+        let pc = self.pc.read();
+        let (mut cpu, mops, fault) = self.next_operation();
+        cpu.in_flight = mops.map(|mops| InFlight::new(pc, mops)).unwrap_or_default();
+        (cpu, fault)
+    }
+
+    /// Executes the next cycle of the operation in flight, retiring the
+    /// instruction on its final cycle. The operation is abandoned if the cpu
+    /// halts.
+    fn execute_in_flight_cycle(mut self) -> (Self, Option<Fault>) {
+        if self.in_flight.resample == Some(self.in_flight.steps.len()) {
+            self = self.resample();
+        }
+        let step = self.in_flight.steps.pop_front().unwrap_or_default();
+        let pc = self.in_flight.pc;
+        let (mut cpu, fault) = self.execute_cycle(step, pc);
+
+        if cpu.halted.is_some() {
+            cpu.in_flight = InFlight::default();
+        } else if cpu.in_flight.steps.is_empty() && cpu.in_flight.instruction {
+            cpu.retired += 1;
+        }
+        (cpu, fault)
+    }
+
+    /// Decodes the next operation, or pending interrupt. A fault raised while
+    /// decoding is handled according to the fault policy and returned
+    /// alongside the operation to execute in its place, which is `None` if
+    /// the cpu has halted.
+    fn next_operation(self) -> (Self, Option<MOps>, Option<Fault>) {
+        let (pc, cycle) = (self.pc.read(), self.cycles);
+        match self.decode() {
+            Ok(mops) => (self, Some(mops), None),
+            Err(kind) => {
+                let fault = Fault::new(kind, pc, cycle);
+                let cpu = self.handle_fault(fault.clone());
+                let mops = match cpu.fault_policy {
+                    _ if cpu.halted.is_some() => None,
+                    FaultPolicy::Ignore => Some(MOps::new(1, 2, vec![])),
+                    _ => Some(MOps::new(0, 1, vec![])),
+                };
+
+                (cpu, mops, Some(fault))
+            }
+        }
+    }
+
+    /// Generates the operations for the next instruction or, if one is
+    /// pending, an interrupt.
+    fn decode(&self) -> Result<MOps, CpuFault> {
+        if self.stall > 0 {
+            return Ok(MOps::new(
+                0,
+                1,
+                vec![gen_set_stall_microcode!(self.stall - 1)],
+            ));
+        }
+
+        // halt on the opcode fetch, or the first read of an interrupt, while
+        // RDY is held low.
+        if !self.rdy_line {
+            return Ok(MOps::new(0, 1, vec![]));
+        }
+
+        let nmi = self.nmi_asserted();
+        let irq = self.irq_asserted();
+
+        // Poll interrupts between instructions, with an NMI edge taking
+        // priority over a level IRQ.
+        let mops = if nmi && !self.nmi_latch {
+            Interrupt::Nmi
+                .generate(self)
+                .with_reads(Interrupt::Nmi.reads(self))
+                .with_microcode_on_cycle(1, vec![gen_set_sync_microcode!(true)])
+        } else if irq && !self.ps.interrupt_disable {
+            Interrupt::Irq
+                .generate(self)
+                .with_reads(Interrupt::Irq.reads(self))
+                .with_microcode_on_cycle(1, vec![gen_set_sync_microcode!(true)])
+        } else if self.waiting && !irq {
+            // idle in the wait state until an interrupt is asserted.
+            MOps::new(0, 1, vec![])
+        } else {
+            self.decode_instruction()?
+        };
+
+        // an asserted interrupt wakes the cpu from the wait state, even if
+        // the interrupt itself is masked.
+        let mops = if self.waiting && (irq || nmi && !self.nmi_latch) {
+            mops.with_microcode(vec![gen_set_waiting_microcode!(false)])
+        } else {
+            mops
+        };
+
+        // latch the sampled nmi level for detecting the next edge.
+        if nmi != self.nmi_latch {
+            Ok(mops.with_microcode(vec![gen_set_nmi_latch_microcode!(nmi)]))
+        } else {
+            Ok(mops)
+        }
+    }
+
+    /// Generates the operations for the instruction at the program counter.
+    fn decode_instruction(&self) -> Result<MOps, CpuFault> {
+        let pc = self.pc.read();
+        if !self.address_map.is_mapped(pc) {
+            return Err(CpuFault::UnmappedAccess(pc));
+        }
+
+        let opcodes: [u8; 3] = [
+            self.address_map.read(pc),
+            self.address_map.read(pc.wrapping_add(1)),
+            self.address_map.read(pc.wrapping_add(2)),
+        ];
+
+        // Parse correct operation
+        let oper: Operation =
+            operations::decode(self.variant, &opcodes).map_err(CpuFault::IllegalOpcode)?;
+        let reads = oper.reads(self);
+        Ok(oper
+            .generate(self)
+            .with_fetch(pc)
+            .with_reads(reads)
+            .with_microcode_on_cycle(1, vec![gen_set_sync_microcode!(true)]))
+    }
+
+    /// Generates the instruction in flight again on the cycle its operand is
+    /// read. Operand values are sampled from memory when an instruction is
+    /// generated, so this allows them to observe the side effects of the
+    /// reads preceding the operand, such as a dummy read of a status
+    /// register. The remaining steps are replaced by those of the
+    /// regenerated instruction, retaining the interrupt bookkeeping
+    /// scheduled on the final step when the instruction was decoded.
+    fn resample(mut self) -> Self {
+        self.in_flight.resample = None;
+        let mut steps: Vec<Vec<microcode::Microcode>> = match self.decode_instruction() {
+            Ok(mops) => mops.into(),
+            Err(_) => return self,
+        };
+
+        let remaining = self.in_flight.steps.len();
+        let mut tail = steps.split_off(steps.len().saturating_sub(remaining));
+        let bookkeeping: Vec<_> = self
+            .in_flight
+            .steps
+            .back()
+            .into_iter()
+            .flatten()
+            .filter(|mc| {
+                matches!(
+                    mc,
+                    microcode::Microcode::SetNmiLatch(_) | microcode::Microcode::SetWaiting(_)
+                )
+            })
+            .copied()
+            .collect();
+        if let Some(last) = tail.last_mut() {
+            last.extend(bookkeeping);
+        }
+        self.in_flight.steps = tail.into();
+        self
+    }
+
+    /// Executes a single cycle of microcode, handling any fault raised by a
+    /// write according to the fault policy. SYNC is low for the cycle unless
+    /// driven high by its microcode.
+    fn execute_cycle(mut self, step: Vec<microcode::Microcode>, pc: u16) -> (Self, Option<Fault>) {
+        self.sync = false;
+        self.last_write = None;
+        let mut cpu = step.into_iter().fold(self, |cpu, mc| mc.execute(cpu));
+        let cycle = cpu.cycles;
+        cpu.cycles += 1;
+
+        match cpu.bus_fault.take() {
+            Some(kind) => {
+                let fault = Fault::new(kind, pc, cycle);
+                (cpu.handle_fault(fault.clone()), Some(fault))
+            }
+            None => (cpu, None),
+        }
+    }
+
+    /// Returns the reason the cpu has stopped executing, if it has halted or
+    /// is idling in the wait state between instructions.
+    fn stop_reason(&self) -> Option<StopReason<Fault>> {
+        match &self.halted {
+            Some(fault) if fault.kind == CpuFault::Jam => Some(StopReason::Halted(Halt::Jam)),
+            Some(fault) if fault.kind == CpuFault::Stop => Some(StopReason::Halted(Halt::Stop)),
+            Some(fault) => Some(StopReason::Fault(fault.clone())),
+            None if self.waiting && self.in_flight.steps.is_empty() => {
+                Some(StopReason::Halted(Halt::Wait))
+            }
+            None => None,
+        }
+    }
+
+    /// Wraps the cpu in a StepState reporting the cycles left in flight.
+    fn into_step_state(self) -> StepState<Self> {
+        StepState::new(self.in_flight() + 1, self)
+    }
+
+    /// Applies the fault policy to a fault.
+    fn handle_fault(mut self, fault: Fault) -> Self {
+        match self.fault_policy {
+            FaultPolicy::Halt => {
+                self.halted = Some(fault);
+                self
+            }
+            FaultPolicy::Ignore => self,
+            FaultPolicy::Trap(handler) => handler(self, fault),
+        }
+    }
 }
 
 impl Default for MOS6502 {
@@ -153,34 +780,145 @@ impl Default for MOS6502 {
                     Box::new(Memory::<ReadWrite>::new(0x0100, 0x01FF)),
                 )
                 .unwrap(),
+            ..Self::unmapped()
+        }
+    }
+}
+
+impl MOS6502 {
+    /// Returns a cpu with an empty address map. Unlike the default this
+    /// doesn't allocate, allowing it to stand in for a cpu that is
+    /// temporarily moved out from behind a mutable reference.
+    fn unmapped() -> Self {
+        Self {
+            address_map: AddressMap::new(),
             acc: GeneralPurpose::default(),
             x: GeneralPurpose::default(),
             y: GeneralPurpose::default(),
             sp: StackPointer::default(),
             pc: ProgramCounter::default(),
             ps: ProcessorStatus::default(),
+            irq_line: false,
+            nmi_line: false,
+            nmi_latch: false,
+            rdy_line: true,
+            so_line: false,
+            sync: false,
+            last_write: None,
+            fault_policy: FaultPolicy::default(),
+            bus_fault: None,
+            halted: None,
+            waiting: false,
+            stall: 0,
+            in_flight: InFlight::default(),
+            cycles: 0,
+            retired: 0,
+            variant: Variant::default(),
+            vectors: Vectors::default(),
         }
     }
 }
 
 impl CPU<MOS6502> for MOS6502 {
+    type Fault = Fault;
+
     fn run(self, cycles: usize) -> StepState<MOS6502> {
-        let state = self
-            .clone()
-            .into_iter()
-            .map(Into::<Vec<Vec<microcode::Microcode>>>::into)
-            .flatten() // flatten instructions to cycles
-            .take(cycles)
-            .flatten()
-            .fold(self, |c, mc| mc.execute(c));
-        StepState::from(state)
+        let target = self.cycles + cycles;
+        let mut cpu = self;
+
+        // faults are recovered from by the fault policy, stopping early only
+        // if the cpu halts.
+        while cpu.cycles < target && cpu.halted.is_none() {
+            let budget = target - cpu.cycles;
+            cpu = cpu.step(budget).0;
+        }
+
+        cpu.into_step_state()
+    }
+
+    fn step_cycle(self) -> (StepState<MOS6502>, StopReason<Fault>) {
+        let cpu = self.step(1).0;
+        let reason = cpu.stop_reason().unwrap_or(StopReason::Budget);
+        (cpu.into_step_state(), reason)
+    }
+
+    /// Executes the remainder of the operation in flight or the next
+    /// operation, stopping early on a read cycle halted by RDY.
+    fn step_instruction(self) -> (StepState<MOS6502>, StopReason<Fault>) {
+        let cpu = self.step(usize::MAX).0;
+        let reason = cpu.stop_reason().unwrap_or(StopReason::Budget);
+        (cpu.into_step_state(), reason)
+    }
+
+    fn run_until<P>(self, cycles: usize, predicate: P) -> (StepState<MOS6502>, StopReason<Fault>)
+    where
+        P: FnMut(&MOS6502) -> bool,
+    {
+        MOS6502::run_until_as(self, cycles, predicate)
+    }
+}
+
+impl MOS6502 {
+    /// Runs the cpu in the same manner as `run_until`, with the predicate
+    /// observing the cpu as `C`. This allows the variants of the cpu to
+    /// evaluate a predicate on themselves, moving the cpu in and out of the
+    /// variant on each cycle rather than cloning it.
+    pub(crate) fn run_until_as<C, P>(
+        cpu: C,
+        cycles: usize,
+        mut predicate: P,
+    ) -> (StepState<C>, StopReason<Fault>)
+    where
+        C: From<MOS6502> + Into<MOS6502>,
+        P: FnMut(&C) -> bool,
+    {
+        let mut cpu: MOS6502 = cpu.into();
+        let target = cpu.cycles + cycles;
+
+        let reason = loop {
+            if cpu.cycles >= target {
+                break StopReason::Budget;
+            }
+
+            cpu = cpu.step(1).0;
+            if let Some(reason) = cpu.stop_reason() {
+                break reason;
+            }
+
+            let view = C::from(cpu);
+            let matched = predicate(&view);
+            cpu = view.into();
+            if matched {
+                break StopReason::Predicate;
+            }
+        };
+
+        (cpu.into_step_state().map(C::from), reason)
     }
 }
 
+/// The remaining cycles of a StepState are carried by the cpu as the
+/// operation in flight, which is resumed by the next run.
 impl CPU<MOS6502> for StepState<MOS6502> {
+    type Fault = Fault;
+
     fn run(self, cycles: usize) -> StepState<MOS6502> {
-        let remaining = self.remaining;
-        self.cpu.run(cycles - remaining)
+        self.cpu.run(cycles)
+    }
+
+    fn step_cycle(self) -> (StepState<MOS6502>, StopReason<Fault>) {
+        self.cpu.step_cycle()
+    }
+
+    fn step_instruction(self) -> (StepState<MOS6502>, StopReason<Fault>) {
+        self.cpu.step_instruction()
+    }
+
+    fn run_until<P>(self, cycles: usize, predicate: P) -> (StepState<MOS6502>, StopReason<Fault>)
+    where
+        P: FnMut(&MOS6502) -> bool,
+    {
+        self.cpu.run_until(cycles, predicate)
     }
 }
 
@@ -213,25 +951,29 @@ impl Iterator for MOS6502IntoIterator {
     type Item = operations::MOps;
 
     fn next(&mut self) -> Option<operations::MOps> {
-        let pc = self.state.pc.read();
-        let opcodes: [u8; 3] = [
-            self.state.address_map.read(pc),
-            self.state.address_map.read(pc + 1),
-            self.state.address_map.read(pc + 2),
-        ];
+        if self.state.halted.is_some() {
+            return None;
+        }
 
-        // Parse correct operation
-        let oper: Operation = TryFrom::try_from(&opcodes).unwrap();
-        let mops = oper.generate(&self.state);
+        // finish any operation left in flight by a run.
+        let mut state = std::mem::replace(&mut self.state, MOS6502::unmapped());
+        while !state.in_flight.steps.is_empty() {
+            state = state.execute_in_flight_cycle().0;
+        }
+
+        let pc = state.pc.read();
+        let (mut state, mops, _) = state.next_operation();
 
         // rectify state
-        let microcode_steps: Vec<Vec<microcode::Microcode>> = mops.clone().into();
-        self.state = microcode_steps
-            .into_iter()
-            .flatten()
-            .fold(self.state.clone(), |cpu, mc| mc.execute(cpu));
+        if let Some(mops) = mops.clone() {
+            state.in_flight = InFlight::new(pc, mops);
+            while !state.in_flight.steps.is_empty() {
+                state = state.execute_in_flight_cycle().0;
+            }
+        }
+        self.state = state;
 
-        Some(mops)
+        mops
     }
 }
 
@@ -240,6 +982,7 @@ impl Iterator for MOS6502IntoIterator {
 impl Execute<MOS6502> for microcode::Microcode {
     fn execute(self, cpu: MOS6502) -> MOS6502 {
         match self {
+            Self::ReadMemory(mc) => mc.execute(cpu),
             Self::WriteMemory(mc) => mc.execute(cpu),
             Self::SetProgramStatusFlagState(mc) => mc.execute(cpu),
             Self::Write8bitRegister(mc) => mc.execute(cpu),
@@ -248,14 +991,40 @@ impl Execute<MOS6502> for microcode::Microcode {
             Self::Write16bitRegister(mc) => mc.execute(cpu),
             Self::Inc16bitRegister(mc) => mc.execute(cpu),
             Self::Dec16bitRegister(mc) => mc.execute(cpu),
+            Self::SetNmiLatch(mc) => mc.execute(cpu),
+            Self::Halt(mc) => mc.execute(cpu),
+            Self::SetWaiting(mc) => mc.execute(cpu),
+            Self::SetStall(mc) => mc.execute(cpu),
+            Self::SetSync(mc) => mc.execute(cpu),
         }
     }
 }
 
+impl Execute<MOS6502> for microcode::ReadMemory {
+    fn execute(self, cpu: MOS6502) -> MOS6502 {
+        let mut cpu = cpu;
+        cpu.address_map.read_mut(self.address);
+        cpu
+    }
+}
+
 impl Execute<MOS6502> for microcode::WriteMemory {
     fn execute(self, cpu: MOS6502) -> MOS6502 {
         let mut cpu = cpu;
-        cpu.address_map.write(self.address, self.value).unwrap();
+        cpu.last_write = Some((self.address, self.value));
+        if let Err(reason) = cpu.address_map.write(self.address, self.value) {
+            let fault = match reason {
+                WriteError::Unmapped { address, .. } => CpuFault::UnmappedAccess(address),
+                reason => CpuFault::BusWrite {
+                    address: self.address,
+                    value: self.value,
+                    reason,
+                },
+            };
+
+            // only the first fault raised in a cycle is recorded.
+            cpu.bus_fault.get_or_insert(fault);
+        }
         cpu
     }
 }
@@ -398,3 +1167,381 @@ impl Execute<MOS6502> for microcode::Dec16bitRegister {
         cpu.with_pc_register(ProgramCounter::with_value(pc))
     }
 }
+
+impl Execute<MOS6502> for microcode::SetNmiLatch {
+    fn execute(self, cpu: MOS6502) -> MOS6502 {
+        let mut cpu = cpu;
+        cpu.nmi_latch = self.value;
+        cpu
+    }
+}
+
+impl Execute<MOS6502> for microcode::Halt {
+    fn execute(self, cpu: MOS6502) -> MOS6502 {
+        let mut cpu = cpu;
+        let kind = match self.reason {
+            microcode::HaltReason::Jam => CpuFault::Jam,
+            microcode::HaltReason::Stop => CpuFault::Stop,
+        };
+        cpu.halted = Some(Fault::new(kind, cpu.pc.read(), cpu.cycles));
+        cpu
+    }
+}
+
+impl Execute<MOS6502> for microcode::SetWaiting {
+    fn execute(self, cpu: MOS6502) -> MOS6502 {
+        let mut cpu = cpu;
+        cpu.waiting = self.value;
+        cpu
+    }
+}
+
+impl Execute<MOS6502> for microcode::SetStall {
+    fn execute(self, cpu: MOS6502) -> MOS6502 {
+        let mut cpu = cpu;
+        cpu.stall = self.cycles;
+        cpu
+    }
+}
+
+impl Execute<MOS6502> for microcode::SetSync {
+    fn execute(self, cpu: MOS6502) -> MOS6502 {
+        let mut cpu = cpu;
+        cpu.sync = self.value;
+        cpu
+    }
+}
+
+// variants
+
+/// Generates a cpu type for a variant of the 6502 family. The generated type
+/// wraps, and dereferences to, a MOS6502 set to the variant, forwarding the
+/// builder methods and the CPU trait while keeping the variant's own type.
+macro_rules! gen_variant_cpu {
+    ($(#[$meta:meta])* $name:ident, $variant:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            inner: $crate::cpu::mos6502::MOS6502,
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// instantiates a new cpu with a provided address_map.
+            pub fn with_addressmap(am: $crate::address_map::AddressMap<u16>) -> Self {
+                Self::from($crate::cpu::mos6502::MOS6502::with_addressmap(am))
+            }
+
+            /// Registers an address space in the same manner as the
+            /// MOS6502's `register_address_space`, forwarding any error
+            /// encountered.
+            pub fn register_address_space(
+                self,
+                space: std::ops::RangeInclusive<u16>,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<Self, $crate::address_map::RegistrationError<u16>> {
+                self.inner
+                    .register_address_space(space, addr_space)
+                    .map(Self::from)
+            }
+
+            /// Registers a relatively addressed address space in the same
+            /// manner as the MOS6502's `register_relative_address_space`.
+            pub fn register_relative_address_space(
+                self,
+                space: std::ops::RangeInclusive<u16>,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<Self, $crate::address_map::RegistrationError<u16>> {
+                self.inner
+                    .register_relative_address_space(space, addr_space)
+                    .map(Self::from)
+            }
+
+            /// Registers a partially decoded address space in the same manner
+            /// as the MOS6502's `register_masked_address_space`.
+            pub fn register_masked_address_space(
+                self,
+                space: std::ops::RangeInclusive<u16>,
+                mask: u16,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<Self, $crate::address_map::RegistrationError<u16>> {
+                self.inner
+                    .register_masked_address_space(space, mask, addr_space)
+                    .map(Self::from)
+            }
+
+            /// Registers a mirrored address space in the same manner as the
+            /// MOS6502's `register_mirrored_address_space`.
+            pub fn register_mirrored_address_space(
+                self,
+                space: std::ops::RangeInclusive<u16>,
+                mirrors: Vec<std::ops::RangeInclusive<u16>>,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<Self, $crate::address_map::RegistrationError<u16>> {
+                self.inner
+                    .register_mirrored_address_space(space, mirrors, addr_space)
+                    .map(Self::from)
+            }
+
+            /// Layers an overlay over the address map in the same manner as
+            /// the MOS6502's `overlay_address_space`.
+            pub fn overlay_address_space(
+                &mut self,
+                space: std::ops::RangeInclusive<u16>,
+                priority: u8,
+                routing: $crate::address_map::Routing,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<(), $crate::address_map::RegistrationError<u16>> {
+                self.inner
+                    .overlay_address_space(space, priority, routing, addr_space)
+            }
+
+            /// Layers a relatively addressed overlay over the address map in
+            /// the same manner as the MOS6502's
+            /// `overlay_relative_address_space`.
+            pub fn overlay_relative_address_space(
+                &mut self,
+                space: std::ops::RangeInclusive<u16>,
+                priority: u8,
+                routing: $crate::address_map::Routing,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<(), $crate::address_map::RegistrationError<u16>> {
+                self.inner
+                    .overlay_relative_address_space(space, priority, routing, addr_space)
+            }
+
+            /// Changes the routing of an overlay in the same manner as the
+            /// MOS6502's `set_overlay_routing`.
+            pub fn set_overlay_routing(
+                &mut self,
+                space: std::ops::RangeInclusive<u16>,
+                routing: $crate::address_map::Routing,
+            ) -> Result<(), $crate::address_map::RegistrationError<u16>> {
+                self.inner.set_overlay_routing(space, routing)
+            }
+
+            /// Removes an address space in the same manner as the MOS6502's
+            /// `unregister_address_space`.
+            pub fn unregister_address_space(
+                &mut self,
+                space: std::ops::RangeInclusive<u16>,
+            ) -> Result<
+                Box<dyn $crate::address_map::Addressable<u16>>,
+                $crate::address_map::RegistrationError<u16>,
+            > {
+                self.inner.unregister_address_space(space)
+            }
+
+            /// Swaps an address space in the same manner as the MOS6502's
+            /// `replace_address_space`.
+            pub fn replace_address_space(
+                &mut self,
+                space: std::ops::RangeInclusive<u16>,
+                addr_space: impl $crate::address_map::Addressable<u16> + 'static,
+            ) -> Result<
+                Box<dyn $crate::address_map::Addressable<u16>>,
+                $crate::address_map::RegistrationError<u16>,
+            > {
+                self.inner.replace_address_space(space, addr_space)
+            }
+
+            /// emulates the reset process of the CPU.
+            pub fn reset(self) -> $crate::cpu::StepState<Self> {
+                self.inner.reset().map(Self::from)
+            }
+
+            /// Provides a wrapper to update a general-purpose register in a
+            /// way that returns the entire cpu after modification.
+            pub fn with_gp_register(
+                self,
+                reg_type: $crate::cpu::mos6502::register::GPRegister,
+                reg: $crate::cpu::mos6502::register::GeneralPurpose,
+            ) -> Self {
+                Self::from(self.inner.with_gp_register(reg_type, reg))
+            }
+
+            /// Provides a wrapper to update the stack-pointer register in a
+            /// way that returns the entire cpu after modification.
+            pub fn with_sp_register(self, reg: $crate::cpu::mos6502::register::StackPointer) -> Self {
+                Self::from(self.inner.with_sp_register(reg))
+            }
+
+            /// Provides a wrapper to update the program-counter register in a
+            /// way that returns the entire cpu after modification.
+            pub fn with_pc_register(
+                self,
+                reg: $crate::cpu::mos6502::register::ProgramCounter,
+            ) -> Self {
+                Self::from(self.inner.with_pc_register(reg))
+            }
+
+            /// Provides a wrapper to update the processor-status register in
+            /// a way that returns the entire cpu after modification.
+            pub fn with_ps_register(
+                self,
+                reg: $crate::cpu::mos6502::register::ProcessorStatus,
+            ) -> Self {
+                Self::from(self.inner.with_ps_register(reg))
+            }
+
+            /// Sets the level of the host driven maskable interrupt request
+            /// line, returning the entire cpu after modification.
+            pub fn with_irq_line(self, asserted: bool) -> Self {
+                Self::from(self.inner.with_irq_line(asserted))
+            }
+
+            /// Sets the level of the host driven non-maskable interrupt line,
+            /// returning the entire cpu after modification.
+            pub fn with_nmi_line(self, asserted: bool) -> Self {
+                Self::from(self.inner.with_nmi_line(asserted))
+            }
+
+            /// Sets the level of the RDY input, returning the entire cpu
+            /// after modification.
+            pub fn with_rdy_line(self, ready: bool) -> Self {
+                Self::from(self.inner.with_rdy_line(ready))
+            }
+
+            /// Sets the level of the set overflow input, returning the entire
+            /// cpu after modification.
+            pub fn with_so_line(self, asserted: bool) -> Self {
+                Self::from(self.inner.with_so_line(asserted))
+            }
+
+            /// Sets the policy used to recover from faults, returning the
+            /// entire cpu after modification.
+            pub fn with_fault_policy(self, policy: $crate::cpu::mos6502::fault::FaultPolicy) -> Self {
+                Self::from(self.inner.with_fault_policy(policy))
+            }
+
+            /// Stalls the cpu for the provided number of cycles, returning the
+            /// entire cpu after modification.
+            pub fn with_stall(self, cycles: usize) -> Self {
+                Self::from(self.inner.with_stall(cycles))
+            }
+
+            /// Relocates the NMI, reset and IRQ vectors, returning the entire
+            /// cpu after modification.
+            pub fn with_vectors(self, vectors: $crate::cpu::mos6502::operations::Vectors) -> Self {
+                Self::from(self.inner.with_vectors(vectors))
+            }
+
+            /// Returns the underlying cpu.
+            pub fn unwrap(self) -> $crate::cpu::mos6502::MOS6502 {
+                self.inner
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::from($crate::cpu::mos6502::MOS6502::default())
+            }
+        }
+
+        /// Converts a MOS6502 into the variant, retaining its state while
+        /// switching its behavior to that of the variant.
+        impl From<$crate::cpu::mos6502::MOS6502> for $name {
+            fn from(src: $crate::cpu::mos6502::MOS6502) -> Self {
+                Self {
+                    inner: src.with_variant($variant),
+                }
+            }
+        }
+
+        impl From<$name> for $crate::cpu::mos6502::MOS6502 {
+            fn from(src: $name) -> Self {
+                src.inner
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = $crate::cpu::mos6502::MOS6502;
+
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.inner
+            }
+        }
+
+        impl $crate::cpu::CPU<$name> for $name {
+            type Fault = $crate::cpu::mos6502::fault::Fault;
+
+            fn run(self, cycles: usize) -> $crate::cpu::StepState<$name> {
+                $crate::cpu::CPU::run(self.inner, cycles).map(Self::from)
+            }
+
+            fn step_cycle(
+                self,
+            ) -> ($crate::cpu::StepState<$name>, $crate::cpu::StopReason<Self::Fault>) {
+                let (state, reason) = $crate::cpu::CPU::step_cycle(self.inner);
+                (state.map(Self::from), reason)
+            }
+
+            fn step_instruction(
+                self,
+            ) -> ($crate::cpu::StepState<$name>, $crate::cpu::StopReason<Self::Fault>) {
+                let (state, reason) = $crate::cpu::CPU::step_instruction(self.inner);
+                (state.map(Self::from), reason)
+            }
+
+            fn run_until<P>(
+                self,
+                cycles: usize,
+                predicate: P,
+            ) -> ($crate::cpu::StepState<$name>, $crate::cpu::StopReason<Self::Fault>)
+            where
+                P: FnMut(&$name) -> bool,
+            {
+                $crate::cpu::mos6502::MOS6502::run_until_as(self, cycles, predicate)
+            }
+        }
+
+        impl $crate::cpu::CPU<$name> for $crate::cpu::StepState<$name> {
+            type Fault = $crate::cpu::mos6502::fault::Fault;
+
+            fn run(self, cycles: usize) -> $crate::cpu::StepState<$name> {
+                $crate::cpu::CPU::run(self.map($name::unwrap), cycles).map($name::from)
+            }
+
+            fn step_cycle(
+                self,
+            ) -> ($crate::cpu::StepState<$name>, $crate::cpu::StopReason<Self::Fault>) {
+                $crate::cpu::CPU::step_cycle(self.unwrap())
+            }
+
+            fn step_instruction(
+                self,
+            ) -> ($crate::cpu::StepState<$name>, $crate::cpu::StopReason<Self::Fault>) {
+                $crate::cpu::CPU::step_instruction(self.unwrap())
+            }
+
+            fn run_until<P>(
+                self,
+                cycles: usize,
+                predicate: P,
+            ) -> ($crate::cpu::StepState<$name>, $crate::cpu::StopReason<Self::Fault>)
+            where
+                P: FnMut(&$name) -> bool,
+            {
+                $crate::cpu::CPU::run_until(self.unwrap(), cycles, predicate)
+            }
+        }
+
+        impl IntoIterator for $name {
+            type Item = $crate::cpu::mos6502::operations::MOps;
+            type IntoIter = $crate::cpu::mos6502::MOS6502IntoIterator;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_iter()
+            }
+        }
+    };
+}
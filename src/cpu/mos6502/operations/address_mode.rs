@@ -1,16 +1,105 @@
 extern crate parcel;
-use crate::cpu::{Cyclable, Offset};
+use super::{
+    add_index_to_address, add_index_to_zeropage_address, dereference_indexed_indirect_address,
+    dereference_indirect_indexed_address,
+};
+use crate::cpu::{
+    mos6502::{Variant, MOS6502},
+    register::Register,
+    Cyclable, Offset,
+};
 use parcel::{parsers::byte::any_byte, MatchStatus, ParseResult, Parser};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// EffectiveAddress describes the memory an address mode references when
+/// resolving the operand of an instruction. Address modes that don't
+/// reference memory rely on the defaults.
+pub trait EffectiveAddress {
+    /// Returns the address of the operand in memory.
+    fn effective_address(&self, _: &MOS6502) -> Option<u16> {
+        None
+    }
+
+    /// Returns the addresses read while resolving the effective address, such
+    /// as the bytes of an indirect pointer or the discarded read of an address
+    /// prior to indexing it.
+    fn pointer_reads(&self, _: &MOS6502) -> Vec<u16> {
+        vec![]
+    }
+
+    /// Returns the address read on the cycle an indexed address mode spends
+    /// carrying the index into the high byte of the effective address. The
+    /// cycle is only taken when the index crosses a page, unless `always` is
+    /// set, as it is for instructions that write to the effective address.
+    fn carry_read(&self, _: &MOS6502, _always: bool) -> Option<u16> {
+        None
+    }
+}
+
+/// Returns the addresses of a little-endian pointer stored in the zeropage,
+/// wrapping within the zeropage.
+fn zeropage_pointer_reads(addr: u8) -> Vec<u16> {
+    vec![addr as u16, addr.wrapping_add(1) as u16]
+}
+
+/// Returns the address read while carrying an index into the high byte of a
+/// base address, if that cycle is taken. The NMOS 6502 reads the address
+/// prior to the carry, while the 65C02 rereads the last byte of the
+/// instruction when the index crosses a page.
+fn indexed_carry_read(
+    cpu: &MOS6502,
+    base_addr: u16,
+    index: u8,
+    last_byte: u16,
+    always: bool,
+) -> Option<u16> {
+    let uncarried = (base_addr & 0xff00) | (base_addr.wrapping_add(index as u16) & 0x00ff);
+    let crossed = uncarried != add_index_to_address(base_addr, index);
+
+    match cpu.variant {
+        _ if !crossed && !always => None,
+        Variant::W65C02 if crossed => Some(last_byte),
+        _ => Some(uncarried),
+    }
+}
+
+/// Accumulator address mode. This is signified by the instruction operating
+/// directly on the accumulator and taking no address mode arguments. An
+/// example instruction with an accumulator address mode would be. `asl a`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Accumulator;
 
+impl EffectiveAddress for Accumulator {
+    /// The byte following the opcode is read and discarded.
+    fn pointer_reads(&self, cpu: &MOS6502) -> Vec<u16> {
+        vec![cpu.pc.read().wrapping_add(1)]
+    }
+}
+
+impl Offset for Accumulator {
+    fn offset(&self) -> usize {
+        0
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], Accumulator> for Accumulator {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], Accumulator> {
+        Ok(MatchStatus::Match((input, Accumulator)))
+    }
+}
+
 /// Implied address address mode. This is signified by no address mode
 /// arguments. An example instruction with an implied address mode would be.
 /// `nop`
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Implied;
 
+impl EffectiveAddress for Implied {
+    /// The byte following the opcode is read and discarded.
+    fn pointer_reads(&self, cpu: &MOS6502) -> Vec<u16> {
+        vec![cpu.pc.read().wrapping_add(1)]
+    }
+}
+
 impl Offset for Implied {
     fn offset(&self) -> usize {
         0
@@ -26,6 +115,8 @@ impl<'a> Parser<'a, &'a [u8], Implied> for Implied {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Immediate(pub u8);
 
+impl EffectiveAddress for Immediate {}
+
 impl Cyclable for Immediate {}
 impl Offset for Immediate {}
 
@@ -53,6 +144,12 @@ impl From<Immediate> for u8 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Absolute(pub u16);
 
+impl EffectiveAddress for Absolute {
+    fn effective_address(&self, _: &MOS6502) -> Option<u16> {
+        Some(self.0)
+    }
+}
+
 impl Offset for Absolute {
     fn offset(&self) -> usize {
         2
@@ -85,6 +182,12 @@ impl<'a> Parser<'a, &'a [u8], Absolute> for Absolute {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ZeroPage(pub u8);
 
+impl EffectiveAddress for ZeroPage {
+    fn effective_address(&self, _: &MOS6502) -> Option<u16> {
+        Some(self.0 as u16)
+    }
+}
+
 impl Cyclable for ZeroPage {}
 impl Offset for ZeroPage {}
 
@@ -113,6 +216,17 @@ impl From<ZeroPage> for u8 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ZeroPageIndexedWithX(pub u8);
 
+impl EffectiveAddress for ZeroPageIndexedWithX {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(add_index_to_zeropage_address(self.0, cpu.x.read()))
+    }
+
+    /// The unindexed address is read and discarded while the index is added.
+    fn pointer_reads(&self, _: &MOS6502) -> Vec<u16> {
+        vec![self.0 as u16]
+    }
+}
+
 impl Cyclable for ZeroPageIndexedWithX {}
 impl Offset for ZeroPageIndexedWithX {}
 
@@ -141,6 +255,17 @@ impl From<ZeroPageIndexedWithX> for u8 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ZeroPageIndexedWithY(pub u8);
 
+impl EffectiveAddress for ZeroPageIndexedWithY {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(add_index_to_zeropage_address(self.0, cpu.y.read()))
+    }
+
+    /// The unindexed address is read and discarded while the index is added.
+    fn pointer_reads(&self, _: &MOS6502) -> Vec<u16> {
+        vec![self.0 as u16]
+    }
+}
+
 impl Cyclable for ZeroPageIndexedWithY {}
 impl Offset for ZeroPageIndexedWithY {}
 
@@ -170,6 +295,8 @@ impl From<ZeroPageIndexedWithY> for u8 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Relative(pub i8);
 
+impl EffectiveAddress for Relative {}
+
 impl Offset for Relative {}
 
 impl<'a> Parser<'a, &'a [u8], Relative> for Relative {
@@ -200,6 +327,21 @@ impl From<Relative> for i8 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Indirect(pub u16);
 
+impl EffectiveAddress for Indirect {
+    /// The NMOS 6502 doesn't carry into the high byte when fetching the msb
+    /// of the pointer, wrapping to the start of the page.
+    fn pointer_reads(&self, cpu: &MOS6502) -> Vec<u16> {
+        let msb_addr = match cpu.variant {
+            Variant::MOS6502 | Variant::Ricoh2A03 => {
+                (self.0 & 0xff00) | (self.0.wrapping_add(1) & 0x00ff)
+            }
+            Variant::W65C02 => self.0.wrapping_add(1),
+        };
+
+        vec![self.0, msb_addr]
+    }
+}
+
 impl Offset for Indirect {
     fn offset(&self) -> usize {
         2
@@ -219,6 +361,17 @@ impl<'a> Parser<'a, &'a [u8], Indirect> for Indirect {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct AbsoluteIndexedWithX(pub u16);
 
+impl EffectiveAddress for AbsoluteIndexedWithX {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(add_index_to_address(self.0, cpu.x.read()))
+    }
+
+    fn carry_read(&self, cpu: &MOS6502, always: bool) -> Option<u16> {
+        let last_byte = cpu.pc.read().wrapping_add(2);
+        indexed_carry_read(cpu, self.0, cpu.x.read(), last_byte, always)
+    }
+}
+
 impl Offset for AbsoluteIndexedWithX {
     fn offset(&self) -> usize {
         2
@@ -252,6 +405,17 @@ impl<'a> Parser<'a, &'a [u8], AbsoluteIndexedWithX> for AbsoluteIndexedWithX {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct AbsoluteIndexedWithY(pub u16);
 
+impl EffectiveAddress for AbsoluteIndexedWithY {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(add_index_to_address(self.0, cpu.y.read()))
+    }
+
+    fn carry_read(&self, cpu: &MOS6502, always: bool) -> Option<u16> {
+        let last_byte = cpu.pc.read().wrapping_add(2);
+        indexed_carry_read(cpu, self.0, cpu.y.read(), last_byte, always)
+    }
+}
+
 impl Offset for AbsoluteIndexedWithY {
     fn offset(&self) -> usize {
         2
@@ -286,6 +450,23 @@ impl From<AbsoluteIndexedWithY> for u16 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct XIndexedIndirect(pub u8);
 
+impl EffectiveAddress for XIndexedIndirect {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(dereference_indexed_indirect_address(
+            cpu,
+            self.0,
+            cpu.x.read(),
+        ))
+    }
+
+    /// The unindexed pointer is read and discarded while the index is added.
+    fn pointer_reads(&self, cpu: &MOS6502) -> Vec<u16> {
+        let mut reads = vec![self.0 as u16];
+        reads.extend(zeropage_pointer_reads(self.0.wrapping_add(cpu.x.read())));
+        reads
+    }
+}
+
 impl Offset for XIndexedIndirect {}
 
 impl<'a> Parser<'a, &'a [u8], XIndexedIndirect> for XIndexedIndirect {
@@ -314,6 +495,26 @@ impl From<XIndexedIndirect> for u8 {
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct IndirectYIndexed(pub u8);
 
+impl EffectiveAddress for IndirectYIndexed {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(dereference_indirect_indexed_address(
+            cpu,
+            self.0,
+            cpu.y.read(),
+        ))
+    }
+
+    fn pointer_reads(&self, _: &MOS6502) -> Vec<u16> {
+        zeropage_pointer_reads(self.0)
+    }
+
+    fn carry_read(&self, cpu: &MOS6502, always: bool) -> Option<u16> {
+        let base_addr = dereference_indirect_indexed_address(cpu, self.0, 0);
+        let last_byte = cpu.pc.read().wrapping_add(1);
+        indexed_carry_read(cpu, base_addr, cpu.y.read(), last_byte, always)
+    }
+}
+
 impl Offset for IndirectYIndexed {}
 
 impl<'a> Parser<'a, &'a [u8], IndirectYIndexed> for IndirectYIndexed {
@@ -335,3 +536,122 @@ impl From<IndirectYIndexed> for u8 {
         src.0
     }
 }
+
+/// ZeroPageIndirect represents an address stored in sequential bytes of the
+/// zeropage at the operand value. Example being (LL, LL + 1). This mode is
+/// only available on the 65C02.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ZeroPageIndirect(pub u8);
+
+impl EffectiveAddress for ZeroPageIndirect {
+    fn effective_address(&self, cpu: &MOS6502) -> Option<u16> {
+        Some(dereference_indirect_indexed_address(cpu, self.0, 0))
+    }
+
+    fn pointer_reads(&self, _: &MOS6502) -> Vec<u16> {
+        zeropage_pointer_reads(self.0)
+    }
+}
+
+impl Offset for ZeroPageIndirect {}
+
+impl<'a> Parser<'a, &'a [u8], ZeroPageIndirect> for ZeroPageIndirect {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ZeroPageIndirect> {
+        any_byte().map(ZeroPageIndirect).parse(input)
+    }
+}
+
+impl ZeroPageIndirect {
+    /// Unpacks the enclosed address from a ZeroPageIndirect address mode into
+    /// a corresponding u8 address.
+    pub fn unwrap(self) -> u8 {
+        self.into()
+    }
+}
+
+impl From<ZeroPageIndirect> for u8 {
+    fn from(src: ZeroPageIndirect) -> Self {
+        src.0
+    }
+}
+
+/// AbsoluteIndexedIndirect represents an address stored in sequential bytes
+/// at an X register offset from the operand value. Example being
+/// (LLHH + X, LLHH + X + 1). This mode is only available on the 65C02.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AbsoluteIndexedIndirect(pub u16);
+
+impl EffectiveAddress for AbsoluteIndexedIndirect {
+    fn pointer_reads(&self, cpu: &MOS6502) -> Vec<u16> {
+        let addr = add_index_to_address(self.0, cpu.x.read());
+        vec![addr, addr.wrapping_add(1)]
+    }
+}
+
+impl Offset for AbsoluteIndexedIndirect {
+    fn offset(&self) -> usize {
+        2
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], AbsoluteIndexedIndirect> for AbsoluteIndexedIndirect {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], AbsoluteIndexedIndirect> {
+        parcel::take_n(any_byte(), 2)
+            .map(|b| AbsoluteIndexedIndirect(u16::from_le_bytes([b[0], b[1]])))
+            .parse(input)
+    }
+}
+
+impl AbsoluteIndexedIndirect {
+    /// Unpacks the enclosed address from a AbsoluteIndexedIndirect address
+    /// mode into a corresponding u16 address.
+    pub fn unwrap(self) -> u16 {
+        self.into()
+    }
+}
+
+impl From<AbsoluteIndexedIndirect> for u16 {
+    fn from(src: AbsoluteIndexedIndirect) -> Self {
+        src.0
+    }
+}
+
+/// ZeroPageRelative wraps a zeropage address and a relative offset, used by
+/// the 65C02 bit branch instructions to test a bit of the value in the
+/// zeropage before branching. An example instruction being `bbr0 $LL, $RR`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ZeroPageRelative(pub u8, pub i8);
+
+impl EffectiveAddress for ZeroPageRelative {
+    fn effective_address(&self, _: &MOS6502) -> Option<u16> {
+        Some(self.0 as u16)
+    }
+}
+
+impl Offset for ZeroPageRelative {
+    fn offset(&self) -> usize {
+        2
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], ZeroPageRelative> for ZeroPageRelative {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ZeroPageRelative> {
+        parcel::take_n(any_byte(), 2)
+            .map(|b| ZeroPageRelative(b[0], b[1] as i8))
+            .parse(input)
+    }
+}
+
+impl ZeroPageRelative {
+    /// Unpacks the enclosed zeropage address and relative offset from a
+    /// ZeroPageRelative address mode.
+    pub fn unwrap(self) -> (u8, i8) {
+        self.into()
+    }
+}
+
+impl From<ZeroPageRelative> for (u8, i8) {
+    fn from(src: ZeroPageRelative) -> Self {
+        (src.0, src.1)
+    }
+}
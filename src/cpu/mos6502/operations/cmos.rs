@@ -0,0 +1,894 @@
+//! The instructions added by the WDC 65C02, including the Rockwell bit
+//! manipulation instructions. These are decoded ahead of the documented NMOS
+//! opcodes, claiming both the undefined opcodes of the NMOS 6502 and the new
+//! addressing modes of existing mnemonics.
+
+use super::{
+    add_index_to_address, add_index_to_zeropage_address, add_with_carry, address_mode,
+    branch_on_case, decimal_penalty, dereference_address_to_operand,
+    dereference_indirect_indexed_address, mnemonic, stack_pointer_from_byte_value,
+    subtract_with_borrow, Instruction, MOps, Operand, Operation, Page,
+};
+use crate::address_map::Addressable;
+use crate::cpu::{
+    mos6502::{
+        microcode::{HaltReason, Microcode},
+        register::*,
+        Generate, MOS6502,
+    },
+    register::Register,
+    Cyclable, Offset,
+};
+use parcel::{parsers::byte::expect_byte, BoxedParser, ParseResult, Parser};
+
+/// Returns parsers for each of the 65C02 operations, for inclusion in the
+/// CmosOperationParser.
+pub(super) fn operation_parsers<'a>() -> Vec<BoxedParser<'a, &'a [u8], Operation>> {
+    vec![
+        inst_to_operation!(mnemonic::ADC, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(
+            mnemonic::BBR::<0>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<1>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<2>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<3>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<4>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<5>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<6>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBR::<7>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<0>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<1>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<2>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<3>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<4>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<5>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<6>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(
+            mnemonic::BBS::<7>,
+            address_mode::ZeroPageRelative::default()
+        ),
+        inst_to_operation!(mnemonic::BIT, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::BIT, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::BIT, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::BRA, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::DEC, address_mode::Accumulator),
+        inst_to_operation!(mnemonic::EOR, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::INC, address_mode::Accumulator),
+        inst_to_operation!(
+            mnemonic::JMP,
+            address_mode::AbsoluteIndexedIndirect::default()
+        ),
+        inst_to_operation!(mnemonic::LDA, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::PHX, address_mode::Implied),
+        inst_to_operation!(mnemonic::PHY, address_mode::Implied),
+        inst_to_operation!(mnemonic::PLX, address_mode::Implied),
+        inst_to_operation!(mnemonic::PLY, address_mode::Implied),
+        inst_to_operation!(mnemonic::RMB::<0>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<1>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<2>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<3>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<4>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<5>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<6>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RMB::<7>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RSV, address_mode::Implied),
+        inst_to_operation!(mnemonic::RSV, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::RSV, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RSV, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::RSV, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::RSV, address_mode::Indirect::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::SMB::<0>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<1>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<2>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<3>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<4>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<5>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<6>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SMB::<7>, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::ZeroPageIndirect::default()),
+        inst_to_operation!(mnemonic::STP, address_mode::Implied),
+        inst_to_operation!(mnemonic::STZ, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::STZ, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::STZ, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::STZ, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::TRB, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::TRB, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::TSB, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::TSB, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::WAI, address_mode::Implied),
+    ]
+}
+
+// Zeropage Indirect
+
+/// Dereferences the address stored in the zeropage at, and wrapping within
+/// the zeropage after, the operand of a `(zp)` addressed instruction.
+fn dereference_zeropage_indirect_address(cpu: &MOS6502, addr: u8) -> u16 {
+    dereference_indirect_indexed_address(cpu, addr, 0)
+}
+
+// ADC
+
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::ZeroPageIndirect, 0x72, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            add_with_carry(cpu, value),
+        )
+    }
+}
+
+// AND
+
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::ZeroPageIndirect, 0x32, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// BBR
+
+gen_instruction_cycles_and_parser!(mnemonic::BBR<0>, address_mode::ZeroPageRelative, 0x0f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<1>, address_mode::ZeroPageRelative, 0x1f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<2>, address_mode::ZeroPageRelative, 0x2f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<3>, address_mode::ZeroPageRelative, 0x3f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<4>, address_mode::ZeroPageRelative, 0x4f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<5>, address_mode::ZeroPageRelative, 0x5f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<6>, address_mode::ZeroPageRelative, 0x6f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBR<7>, address_mode::ZeroPageRelative, 0x7f, 5);
+
+impl<const BIT: u8> Generate<MOS6502, MOps>
+    for Instruction<mnemonic::BBR<BIT>, address_mode::ZeroPageRelative>
+where
+    Self: Cyclable,
+{
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let (addr, offset) = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr as u16);
+
+        branch_on_case(
+            value & (1 << BIT) == 0,
+            offset,
+            self.offset(),
+            self.cycles(),
+            cpu,
+        )
+    }
+}
+
+// BBS
+
+gen_instruction_cycles_and_parser!(mnemonic::BBS<0>, address_mode::ZeroPageRelative, 0x8f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<1>, address_mode::ZeroPageRelative, 0x9f, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<2>, address_mode::ZeroPageRelative, 0xaf, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<3>, address_mode::ZeroPageRelative, 0xbf, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<4>, address_mode::ZeroPageRelative, 0xcf, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<5>, address_mode::ZeroPageRelative, 0xdf, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<6>, address_mode::ZeroPageRelative, 0xef, 5);
+gen_instruction_cycles_and_parser!(mnemonic::BBS<7>, address_mode::ZeroPageRelative, 0xff, 5);
+
+impl<const BIT: u8> Generate<MOS6502, MOps>
+    for Instruction<mnemonic::BBS<BIT>, address_mode::ZeroPageRelative>
+where
+    Self: Cyclable,
+{
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let (addr, offset) = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr as u16);
+
+        branch_on_case(
+            value & (1 << BIT) != 0,
+            offset,
+            self.offset(),
+            self.cycles(),
+            cpu,
+        )
+    }
+}
+
+// BIT
+
+gen_instruction_cycles_and_parser!(mnemonic::BIT, address_mode::Immediate, 0x89, 2);
+
+// immediate bit tests only set the zero flag, as there is no memory operand
+// to source the negative and overflow flags from.
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BIT, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let result = Operand::new(cpu.acc.read() & self.address_mode.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_flag_set_microcode!(
+                ProgramStatusFlags::Zero,
+                result.zero
+            )],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::BIT, address_mode::ZeroPageIndexedWithX, 0x34, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BIT, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let value = value.unwrap();
+        let result = Operand::new(cpu.acc.read() & value);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value & 0x80 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, value & 0x40 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::BIT, address_mode::AbsoluteIndexedWithX, 0x3c, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BIT, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let value = value.unwrap();
+        let result = Operand::new(cpu.acc.read() & value);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value & 0x80 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, value & 0x40 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+            ],
+        )
+    }
+}
+
+// BRA
+
+gen_instruction_cycles_and_parser!(mnemonic::BRA, address_mode::Relative, 0x80, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BRA, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        // the cycles include the penalty for the branch always being taken.
+        branch_on_case(true, offset, self.offset(), self.cycles() - 1, cpu)
+    }
+}
+
+// CMP
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::ZeroPageIndirect, 0xd2, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let (lhs, rhs) = (cpu.acc.read(), cpu.address_map.read(addr));
+        let diff = Operand::new(lhs) - Operand::new(rhs);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, lhs >= rhs),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+// DEC
+
+gen_instruction_cycles_and_parser!(mnemonic::DEC, address_mode::Accumulator, 0x3a, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEC, address_mode::Accumulator> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(cpu.acc.read()) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// EOR
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::ZeroPageIndirect, 0x52, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// INC
+
+gen_instruction_cycles_and_parser!(mnemonic::INC, address_mode::Accumulator, 0x1a, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INC, address_mode::Accumulator> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(cpu.acc.read()) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// JMP
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::JMP,
+    address_mode::AbsoluteIndexedIndirect,
+    0x7c,
+    6
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::JMP, address_mode::AbsoluteIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let indirect_addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let lsb = cpu.address_map.read(indirect_addr);
+        let msb = cpu.address_map.read(indirect_addr.wrapping_add(1));
+        let addr = u16::from_le_bytes([lsb, msb]);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::PC,
+                addr.wrapping_sub(self.offset() as u16)
+            )],
+        )
+    }
+}
+
+// LDA
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::ZeroPageIndirect, 0xb2, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// ORA
+
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::ZeroPageIndirect, 0x12, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// PHX
+
+gen_instruction_cycles_and_parser!(mnemonic::PHX, address_mode::Implied, 0xda, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PHX, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.x.read();
+        let sp = cpu.sp.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(stack_pointer_from_byte_value(sp), value),
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
+            ],
+        )
+    }
+}
+
+// PHY
+
+gen_instruction_cycles_and_parser!(mnemonic::PHY, address_mode::Implied, 0x5a, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PHY, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.y.read();
+        let sp = cpu.sp.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(stack_pointer_from_byte_value(sp), value),
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
+            ],
+        )
+    }
+}
+
+// PLX
+
+gen_instruction_cycles_and_parser!(mnemonic::PLX, address_mode::Implied, 0xfa, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PLX, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let sp = cpu.sp.read().overflowing_add(1).0;
+        let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// PLY
+
+gen_instruction_cycles_and_parser!(mnemonic::PLY, address_mode::Implied, 0x7a, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PLY, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let sp = cpu.sp.read().overflowing_add(1).0;
+        let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// RMB
+
+gen_instruction_cycles_and_parser!(mnemonic::RMB<0>, address_mode::ZeroPage, 0x07, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<1>, address_mode::ZeroPage, 0x17, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<2>, address_mode::ZeroPage, 0x27, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<3>, address_mode::ZeroPage, 0x37, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<4>, address_mode::ZeroPage, 0x47, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<5>, address_mode::ZeroPage, 0x57, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<6>, address_mode::ZeroPage, 0x67, 5);
+gen_instruction_cycles_and_parser!(mnemonic::RMB<7>, address_mode::ZeroPage, 0x77, 5);
+
+impl<const BIT: u8> Generate<MOS6502, MOps>
+    for Instruction<mnemonic::RMB<BIT>, address_mode::ZeroPage>
+where
+    Self: Cyclable,
+{
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr) & !(1 << BIT);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+// RSV
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::RSV,
+    address_mode::Implied,
+    [
+        0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2b, 0x33, 0x3b, 0x43, 0x4b, 0x53, 0x5b, 0x63, 0x6b, 0x73,
+        0x7b, 0x83, 0x8b, 0x93, 0x9b, 0xa3, 0xab, 0xb3, 0xbb, 0xc3, 0xd3, 0xe3, 0xeb, 0xf3, 0xfb
+    ],
+    1
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RSV, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::RSV,
+    address_mode::Immediate,
+    [0x02, 0x22, 0x42, 0x62, 0x82, 0xc2, 0xe2],
+    2
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RSV, address_mode::Immediate> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RSV, address_mode::ZeroPage, 0x44, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RSV, address_mode::ZeroPage> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::RSV,
+    address_mode::ZeroPageIndexedWithX,
+    [0x54, 0xd4, 0xf4],
+    4
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RSV, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RSV, address_mode::Absolute, [0xdc, 0xfc], 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RSV, address_mode::Absolute> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+// 0x5c reads a full absolute operand but, unlike the other three byte
+// reserved opcodes, takes eight cycles to do so.
+gen_instruction_cycles_and_parser!(mnemonic::RSV, address_mode::Indirect, 0x5c, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RSV, address_mode::Indirect> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+// SBC
+
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::ZeroPageIndirect, 0xf2, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            subtract_with_borrow(cpu, value),
+        )
+    }
+}
+
+// SMB
+
+gen_instruction_cycles_and_parser!(mnemonic::SMB<0>, address_mode::ZeroPage, 0x87, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<1>, address_mode::ZeroPage, 0x97, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<2>, address_mode::ZeroPage, 0xa7, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<3>, address_mode::ZeroPage, 0xb7, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<4>, address_mode::ZeroPage, 0xc7, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<5>, address_mode::ZeroPage, 0xd7, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<6>, address_mode::ZeroPage, 0xe7, 5);
+gen_instruction_cycles_and_parser!(mnemonic::SMB<7>, address_mode::ZeroPage, 0xf7, 5);
+
+impl<const BIT: u8> Generate<MOS6502, MOps>
+    for Instruction<mnemonic::SMB<BIT>, address_mode::ZeroPage>
+where
+    Self: Cyclable,
+{
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr) | (1 << BIT);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+// STA
+
+gen_instruction_cycles_and_parser!(mnemonic::STA, address_mode::ZeroPageIndirect, 0x92, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STA, address_mode::ZeroPageIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = dereference_zeropage_indirect_address(cpu, self.address_mode.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, cpu.acc.read())],
+        )
+    }
+}
+
+// STP
+
+gen_instruction_cycles_and_parser!(mnemonic::STP, address_mode::Implied, 0xdb, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STP, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_halt_microcode!(HaltReason::Stop)],
+        )
+    }
+}
+
+// STZ
+
+gen_instruction_cycles_and_parser!(mnemonic::STZ, address_mode::ZeroPage, 0x64, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STZ, address_mode::ZeroPage> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, 0)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STZ, address_mode::ZeroPageIndexedWithX, 0x74, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STZ, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, 0)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STZ, address_mode::Absolute, 0x9c, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STZ, address_mode::Absolute> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, 0)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STZ, address_mode::AbsoluteIndexedWithX, 0x9e, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STZ, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, 0)],
+        )
+    }
+}
+
+// TRB
+
+/// Generates the microcode for a test and reset of the accumulator bits in
+/// the value at the provided address, with the zero flag reflecting the bits
+/// that were set in both prior to the reset.
+fn test_and_reset_bits(cpu: &MOS6502, addr: u16) -> Vec<Microcode> {
+    let (acc, value) = (cpu.acc.read(), cpu.address_map.read(addr));
+
+    vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, acc & value == 0),
+        gen_write_memory_microcode!(addr, value & !acc),
+    ]
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::TRB, address_mode::ZeroPage, 0x14, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::TRB, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+
+        MOps::new(self.offset(), self.cycles(), test_and_reset_bits(cpu, addr))
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::TRB, address_mode::Absolute, 0x1c, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::TRB, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+
+        MOps::new(self.offset(), self.cycles(), test_and_reset_bits(cpu, addr))
+    }
+}
+
+// TSB
+
+/// Generates the microcode for a test and set of the accumulator bits in the
+/// value at the provided address, with the zero flag reflecting the bits that
+/// were set in both prior to the set.
+fn test_and_set_bits(cpu: &MOS6502, addr: u16) -> Vec<Microcode> {
+    let (acc, value) = (cpu.acc.read(), cpu.address_map.read(addr));
+
+    vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, acc & value == 0),
+        gen_write_memory_microcode!(addr, value | acc),
+    ]
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::TSB, address_mode::ZeroPage, 0x04, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::TSB, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+
+        MOps::new(self.offset(), self.cycles(), test_and_set_bits(cpu, addr))
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::TSB, address_mode::Absolute, 0x0c, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::TSB, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+
+        MOps::new(self.offset(), self.cycles(), test_and_set_bits(cpu, addr))
+    }
+}
+
+// WAI
+
+gen_instruction_cycles_and_parser!(mnemonic::WAI, address_mode::Implied, 0xcb, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::WAI, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_set_waiting_microcode!(true)],
+        )
+    }
+}
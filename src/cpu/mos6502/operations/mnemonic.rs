@@ -1,13 +1,52 @@
 extern crate parcel;
-use crate::cpu::Offset;
+use crate::address_map::Addressable;
+use crate::cpu::{mos6502::MOS6502, register::Register, Offset};
 use parcel::{ParseResult, Parser};
 
+mod cmos;
+pub use cmos::*;
+
+#[cfg(feature = "illegal-opcodes")]
+mod undocumented;
+#[cfg(feature = "illegal-opcodes")]
+pub use undocumented::*;
+
+/// MemoryReads describes the memory that an instruction reads from the bus,
+/// allowing devices with read side effects to observe the access. By default
+/// a mnemonic reads its operand from the effective address of its address
+/// mode, if it has one.
+pub trait MemoryReads {
+    fn reads(&self, _: &MOS6502, effective_address: Option<u16>) -> Vec<u16> {
+        effective_address.into_iter().collect()
+    }
+
+    /// Returns true if the mnemonic writes to its effective address. Indexed
+    /// address modes always spend a cycle carrying the index into the high
+    /// byte of the address before a write, rather than only when the index
+    /// crosses a page.
+    fn writes(&self) -> bool {
+        false
+    }
+}
+
+/// Returns the addresses read when pulling the next `count` bytes from the
+/// stack, beginning with the discarded read of the current stack pointer
+/// made while it is incremented.
+fn stack_reads(cpu: &MOS6502, count: u8) -> Vec<u16> {
+    let sp = cpu.sp.read();
+    (0..=count)
+        .map(|offset| u16::from_le_bytes([sp.wrapping_add(offset), 0x01]))
+        .collect()
+}
+
 /// Load operand into Accumulator
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct LDA;
 
 impl Offset for LDA {}
 
+impl MemoryReads for LDA {}
+
 impl<'a> Parser<'a, &'a [u8], LDA> for LDA {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], LDA> {
         parcel::one_of(vec![
@@ -31,6 +70,8 @@ pub struct LDX;
 
 impl Offset for LDX {}
 
+impl MemoryReads for LDX {}
+
 impl<'a> Parser<'a, &'a [u8], LDX> for LDX {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], LDX> {
         parcel::one_of(vec![
@@ -51,6 +92,8 @@ pub struct LDY;
 
 impl Offset for LDY {}
 
+impl MemoryReads for LDY {}
+
 impl<'a> Parser<'a, &'a [u8], LDY> for LDY {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], LDY> {
         parcel::one_of(vec![
@@ -71,6 +114,16 @@ pub struct STA;
 
 impl Offset for STA {}
 
+impl MemoryReads for STA {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
 impl<'a> Parser<'a, &'a [u8], STA> for STA {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], STA> {
         parcel::one_of(vec![
@@ -87,29 +140,135 @@ impl<'a> Parser<'a, &'a [u8], STA> for STA {
     }
 }
 
+/// Store Index X in Memory
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct STX;
 
+impl Offset for STX {}
+
+impl MemoryReads for STX {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], STX> for STX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], STX> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x86),
+            parcel::parsers::byte::expect_byte(0x96),
+            parcel::parsers::byte::expect_byte(0x8e),
+        ])
+        .map(|_| STX)
+        .parse(input)
+    }
+}
+
+/// Store Index Y in Memory
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct STY;
 
+impl Offset for STY {}
+
+impl MemoryReads for STY {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], STY> for STY {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], STY> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x84),
+            parcel::parsers::byte::expect_byte(0x94),
+            parcel::parsers::byte::expect_byte(0x8c),
+        ])
+        .map(|_| STY)
+        .parse(input)
+    }
+}
+
 // Arithmetic
+
+/// Add memory to Accumulator with carry
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ADC;
 
+impl Offset for ADC {}
+
+impl MemoryReads for ADC {}
+
+impl<'a> Parser<'a, &'a [u8], ADC> for ADC {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ADC> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x69),
+            parcel::parsers::byte::expect_byte(0x65),
+            parcel::parsers::byte::expect_byte(0x75),
+            parcel::parsers::byte::expect_byte(0x6d),
+            parcel::parsers::byte::expect_byte(0x7d),
+            parcel::parsers::byte::expect_byte(0x79),
+            parcel::parsers::byte::expect_byte(0x61),
+            parcel::parsers::byte::expect_byte(0x71),
+        ])
+        .map(|_| ADC)
+        .parse(input)
+    }
+}
+
+/// Subtract memory from Accumulator with borrow
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct SBC;
 
+impl Offset for SBC {}
+
+impl MemoryReads for SBC {}
+
+impl<'a> Parser<'a, &'a [u8], SBC> for SBC {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SBC> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xe9),
+            parcel::parsers::byte::expect_byte(0xe5),
+            parcel::parsers::byte::expect_byte(0xf5),
+            parcel::parsers::byte::expect_byte(0xed),
+            parcel::parsers::byte::expect_byte(0xfd),
+            parcel::parsers::byte::expect_byte(0xf9),
+            parcel::parsers::byte::expect_byte(0xe1),
+            parcel::parsers::byte::expect_byte(0xf1),
+        ])
+        .map(|_| SBC)
+        .parse(input)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct INC;
 
 impl Offset for INC {}
 
+impl MemoryReads for INC {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
 impl<'a> Parser<'a, &'a [u8], INC> for INC {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], INC> {
-        parcel::parsers::byte::expect_byte(0xee)
-            .map(|_| INC)
-            .parse(input)
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xe6),
+            parcel::parsers::byte::expect_byte(0xf6),
+            parcel::parsers::byte::expect_byte(0xee),
+            parcel::parsers::byte::expect_byte(0xfe),
+        ])
+        .map(|_| INC)
+        .parse(input)
     }
 }
 
@@ -118,6 +277,8 @@ pub struct INX;
 
 impl Offset for INX {}
 
+impl MemoryReads for INX {}
+
 impl<'a> Parser<'a, &'a [u8], INX> for INX {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], INX> {
         parcel::parsers::byte::expect_byte(0xe8)
@@ -131,6 +292,8 @@ pub struct INY;
 
 impl Offset for INY {}
 
+impl MemoryReads for INY {}
+
 impl<'a> Parser<'a, &'a [u8], INY> for INY {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], INY> {
         parcel::parsers::byte::expect_byte(0xc8)
@@ -139,37 +302,243 @@ impl<'a> Parser<'a, &'a [u8], INY> for INY {
     }
 }
 
+/// Decrement Memory by One
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct DEC;
 
+impl Offset for DEC {}
+
+impl MemoryReads for DEC {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], DEC> for DEC {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], DEC> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xc6),
+            parcel::parsers::byte::expect_byte(0xd6),
+            parcel::parsers::byte::expect_byte(0xce),
+            parcel::parsers::byte::expect_byte(0xde),
+        ])
+        .map(|_| DEC)
+        .parse(input)
+    }
+}
+
+/// Decrement Index X by One
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct DEX;
 
+impl Offset for DEX {}
+
+impl MemoryReads for DEX {}
+
+impl<'a> Parser<'a, &'a [u8], DEX> for DEX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], DEX> {
+        parcel::one_of(vec![parcel::parsers::byte::expect_byte(0xca)])
+            .map(|_| DEX)
+            .parse(input)
+    }
+}
+
+/// Decrement Index Y by One
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct DEY;
 
+impl Offset for DEY {}
+
+impl MemoryReads for DEY {}
+
+impl<'a> Parser<'a, &'a [u8], DEY> for DEY {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], DEY> {
+        parcel::one_of(vec![parcel::parsers::byte::expect_byte(0x88)])
+            .map(|_| DEY)
+            .parse(input)
+    }
+}
+
 // Shift and Rotate
+/// Shift Left One Bit (Memory or Accumulator)
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ASL;
 
+impl Offset for ASL {}
+
+impl MemoryReads for ASL {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], ASL> for ASL {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ASL> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x0a),
+            parcel::parsers::byte::expect_byte(0x06),
+            parcel::parsers::byte::expect_byte(0x16),
+            parcel::parsers::byte::expect_byte(0x0e),
+            parcel::parsers::byte::expect_byte(0x1e),
+        ])
+        .map(|_| ASL)
+        .parse(input)
+    }
+}
+
+/// Shift One Bit Right (Memory or Accumulator)
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct LSR;
 
+impl Offset for LSR {}
+
+impl MemoryReads for LSR {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], LSR> for LSR {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], LSR> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x4a),
+            parcel::parsers::byte::expect_byte(0x46),
+            parcel::parsers::byte::expect_byte(0x56),
+            parcel::parsers::byte::expect_byte(0x4e),
+            parcel::parsers::byte::expect_byte(0x5e),
+        ])
+        .map(|_| LSR)
+        .parse(input)
+    }
+}
+
+/// Rotate One Bit Left (Memory or Accumulator)
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ROL;
 
+impl Offset for ROL {}
+
+impl MemoryReads for ROL {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], ROL> for ROL {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ROL> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x2a),
+            parcel::parsers::byte::expect_byte(0x26),
+            parcel::parsers::byte::expect_byte(0x36),
+            parcel::parsers::byte::expect_byte(0x2e),
+            parcel::parsers::byte::expect_byte(0x3e),
+        ])
+        .map(|_| ROL)
+        .parse(input)
+    }
+}
+
+/// Rotate One Bit Right (Memory or Accumulator)
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ROR;
 
+impl Offset for ROR {}
+
+impl MemoryReads for ROR {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], ROR> for ROR {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ROR> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x6a),
+            parcel::parsers::byte::expect_byte(0x66),
+            parcel::parsers::byte::expect_byte(0x76),
+            parcel::parsers::byte::expect_byte(0x6e),
+            parcel::parsers::byte::expect_byte(0x7e),
+        ])
+        .map(|_| ROR)
+        .parse(input)
+    }
+}
+
+/// AND memory with Accumulator
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct AND;
 
+impl Offset for AND {}
+
+impl MemoryReads for AND {}
+
+impl<'a> Parser<'a, &'a [u8], AND> for AND {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], AND> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x29),
+            parcel::parsers::byte::expect_byte(0x25),
+            parcel::parsers::byte::expect_byte(0x35),
+            parcel::parsers::byte::expect_byte(0x2d),
+            parcel::parsers::byte::expect_byte(0x3d),
+            parcel::parsers::byte::expect_byte(0x39),
+            parcel::parsers::byte::expect_byte(0x21),
+            parcel::parsers::byte::expect_byte(0x31),
+        ])
+        .map(|_| AND)
+        .parse(input)
+    }
+}
+
+/// OR memory with Accumulator
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct ORA;
 
+impl Offset for ORA {}
+
+impl MemoryReads for ORA {}
+
+impl<'a> Parser<'a, &'a [u8], ORA> for ORA {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ORA> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x09),
+            parcel::parsers::byte::expect_byte(0x05),
+            parcel::parsers::byte::expect_byte(0x15),
+            parcel::parsers::byte::expect_byte(0x0d),
+            parcel::parsers::byte::expect_byte(0x1d),
+            parcel::parsers::byte::expect_byte(0x19),
+            parcel::parsers::byte::expect_byte(0x01),
+            parcel::parsers::byte::expect_byte(0x11),
+        ])
+        .map(|_| ORA)
+        .parse(input)
+    }
+}
+
+/// Exclusive-OR memory with Accumulator
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct EOR;
 
+impl Offset for EOR {}
+
+impl MemoryReads for EOR {}
+
+impl<'a> Parser<'a, &'a [u8], EOR> for EOR {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], EOR> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x49),
+            parcel::parsers::byte::expect_byte(0x45),
+            parcel::parsers::byte::expect_byte(0x55),
+            parcel::parsers::byte::expect_byte(0x4d),
+            parcel::parsers::byte::expect_byte(0x5d),
+            parcel::parsers::byte::expect_byte(0x59),
+            parcel::parsers::byte::expect_byte(0x41),
+            parcel::parsers::byte::expect_byte(0x51),
+        ])
+        .map(|_| EOR)
+        .parse(input)
+    }
+}
+
 // Compare and Test Bit
 
 /// Compare memory with Accumulator
@@ -178,6 +547,8 @@ pub struct CMP;
 
 impl Offset for CMP {}
 
+impl MemoryReads for CMP {}
+
 impl<'a> Parser<'a, &'a [u8], CMP> for CMP {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CMP> {
         parcel::one_of(vec![
@@ -195,21 +566,73 @@ impl<'a> Parser<'a, &'a [u8], CMP> for CMP {
     }
 }
 
+/// Compare Memory and Index X
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct CPX;
 
+impl Offset for CPX {}
+
+impl MemoryReads for CPX {}
+
+impl<'a> Parser<'a, &'a [u8], CPX> for CPX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CPX> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xe0),
+            parcel::parsers::byte::expect_byte(0xe4),
+            parcel::parsers::byte::expect_byte(0xec),
+        ])
+        .map(|_| CPX)
+        .parse(input)
+    }
+}
+
+/// Compare Memory and Index Y
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct CPY;
 
+impl Offset for CPY {}
+
+impl MemoryReads for CPY {}
+
+impl<'a> Parser<'a, &'a [u8], CPY> for CPY {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CPY> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xc0),
+            parcel::parsers::byte::expect_byte(0xc4),
+            parcel::parsers::byte::expect_byte(0xcc),
+        ])
+        .map(|_| CPY)
+        .parse(input)
+    }
+}
+
+/// Test Bits in Memory with Accumulator
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BIT;
 
+impl Offset for BIT {}
+
+impl MemoryReads for BIT {}
+
+impl<'a> Parser<'a, &'a [u8], BIT> for BIT {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BIT> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x24),
+            parcel::parsers::byte::expect_byte(0x2c),
+        ])
+        .map(|_| BIT)
+        .parse(input)
+    }
+}
+
 // Branch
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BCC;
 
 impl Offset for BCC {}
 
+impl MemoryReads for BCC {}
+
 impl<'a> Parser<'a, &'a [u8], BCC> for BCC {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BCC> {
         parcel::one_of(vec![parcel::parsers::byte::expect_byte(0x90)])
@@ -223,6 +646,8 @@ pub struct BCS;
 
 impl Offset for BCS {}
 
+impl MemoryReads for BCS {}
+
 impl<'a> Parser<'a, &'a [u8], BCS> for BCS {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BCS> {
         parcel::one_of(vec![parcel::parsers::byte::expect_byte(0xb0)])
@@ -237,6 +662,8 @@ pub struct BNE;
 
 impl Offset for BNE {}
 
+impl MemoryReads for BNE {}
+
 impl<'a> Parser<'a, &'a [u8], BNE> for BNE {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BNE> {
         parcel::parsers::byte::expect_byte(0xd0)
@@ -251,6 +678,8 @@ pub struct BEQ;
 
 impl Offset for BEQ {}
 
+impl MemoryReads for BEQ {}
+
 impl<'a> Parser<'a, &'a [u8], BEQ> for BEQ {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BEQ> {
         parcel::parsers::byte::expect_byte(0xf0)
@@ -259,24 +688,78 @@ impl<'a> Parser<'a, &'a [u8], BEQ> for BEQ {
     }
 }
 
+/// Branch on Result Plus. Follows branch when the Negative flag is not set.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BPL;
 
+impl Offset for BPL {}
+
+impl MemoryReads for BPL {}
+
+impl<'a> Parser<'a, &'a [u8], BPL> for BPL {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BPL> {
+        parcel::parsers::byte::expect_byte(0x10)
+            .map(|_| BPL)
+            .parse(input)
+    }
+}
+
+/// Branch on Result Minus. Follows branch when the Negative flag is set.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BMI;
 
+impl Offset for BMI {}
+
+impl MemoryReads for BMI {}
+
+impl<'a> Parser<'a, &'a [u8], BMI> for BMI {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BMI> {
+        parcel::parsers::byte::expect_byte(0x30)
+            .map(|_| BMI)
+            .parse(input)
+    }
+}
+
+/// Branch on Overflow Clear. Follows branch when the Overflow flag is not set.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BVC;
 
+impl Offset for BVC {}
+
+impl MemoryReads for BVC {}
+
+impl<'a> Parser<'a, &'a [u8], BVC> for BVC {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BVC> {
+        parcel::parsers::byte::expect_byte(0x50)
+            .map(|_| BVC)
+            .parse(input)
+    }
+}
+
+/// Branch on Overflow Set. Follows branch when the Overflow flag is set.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BVS;
 
+impl Offset for BVS {}
+
+impl MemoryReads for BVS {}
+
+impl<'a> Parser<'a, &'a [u8], BVS> for BVS {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BVS> {
+        parcel::parsers::byte::expect_byte(0x70)
+            .map(|_| BVS)
+            .parse(input)
+    }
+}
+
 // Transfer
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct TAX;
 
 impl Offset for TAX {}
 
+impl MemoryReads for TAX {}
+
 impl<'a> Parser<'a, &'a [u8], TAX> for TAX {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TAX> {
         parcel::parsers::byte::expect_byte(0xaa)
@@ -290,6 +773,8 @@ pub struct TXA;
 
 impl Offset for TXA {}
 
+impl MemoryReads for TXA {}
+
 impl<'a> Parser<'a, &'a [u8], TXA> for TXA {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TXA> {
         parcel::parsers::byte::expect_byte(0x8a)
@@ -303,6 +788,8 @@ pub struct TAY;
 
 impl Offset for TAY {}
 
+impl MemoryReads for TAY {}
+
 impl<'a> Parser<'a, &'a [u8], TAY> for TAY {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TAY> {
         parcel::parsers::byte::expect_byte(0xa8)
@@ -316,6 +803,8 @@ pub struct TYA;
 
 impl Offset for TYA {}
 
+impl MemoryReads for TYA {}
+
 impl<'a> Parser<'a, &'a [u8], TYA> for TYA {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TYA> {
         parcel::parsers::byte::expect_byte(0x98)
@@ -329,6 +818,8 @@ pub struct TSX;
 
 impl Offset for TSX {}
 
+impl MemoryReads for TSX {}
+
 impl<'a> Parser<'a, &'a [u8], TSX> for TSX {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TSX> {
         parcel::parsers::byte::expect_byte(0xba)
@@ -342,6 +833,8 @@ pub struct TXS;
 
 impl Offset for TXS {}
 
+impl MemoryReads for TXS {}
+
 impl<'a> Parser<'a, &'a [u8], TXS> for TXS {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TXS> {
         parcel::parsers::byte::expect_byte(0x9a)
@@ -358,6 +851,8 @@ pub struct PHA;
 
 impl Offset for PHA {}
 
+impl MemoryReads for PHA {}
+
 impl<'a> Parser<'a, &'a [u8], PHA> for PHA {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PHA> {
         parcel::parsers::byte::expect_byte(0x48)
@@ -372,6 +867,12 @@ pub struct PLA;
 
 impl Offset for PLA {}
 
+impl MemoryReads for PLA {
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        stack_reads(cpu, 1)
+    }
+}
+
 impl<'a> Parser<'a, &'a [u8], PLA> for PLA {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PLA> {
         parcel::parsers::byte::expect_byte(0x46)
@@ -386,6 +887,8 @@ pub struct PHP;
 
 impl Offset for PHP {}
 
+impl MemoryReads for PHP {}
+
 impl<'a> Parser<'a, &'a [u8], PHP> for PHP {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PHP> {
         parcel::parsers::byte::expect_byte(0x08)
@@ -400,6 +903,12 @@ pub struct PLP;
 
 impl Offset for PLP {}
 
+impl MemoryReads for PLP {
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        stack_reads(cpu, 1)
+    }
+}
+
 impl<'a> Parser<'a, &'a [u8], PLP> for PLP {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PLP> {
         parcel::parsers::byte::expect_byte(0x28)
@@ -417,6 +926,12 @@ pub struct JMP;
 
 impl Offset for JMP {}
 
+impl MemoryReads for JMP {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+}
+
 impl<'a> Parser<'a, &'a [u8], JMP> for JMP {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], JMP> {
         parcel::one_of(vec![
@@ -428,21 +943,85 @@ impl<'a> Parser<'a, &'a [u8], JMP> for JMP {
     }
 }
 
+/// Represents a `jsr` instruction, pushing the address of the last byte of
+/// the instruction to the stack before jumping to a new location in memory.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct JSR;
 
+impl Offset for JSR {}
+
+impl MemoryReads for JSR {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], JSR> for JSR {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], JSR> {
+        parcel::parsers::byte::expect_byte(0x20)
+            .map(|_| JSR)
+            .parse(input)
+    }
+}
+
+/// Represents a `rts` instruction, pulling a return address pushed by a `jsr`
+/// from the stack and resuming execution at the following instruction.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct RTS;
 
+impl Offset for RTS {}
+
+impl MemoryReads for RTS {
+    /// The pulled return address is read, and discarded, while it is
+    /// incremented to the following instruction.
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        let mut reads = stack_reads(cpu, 2);
+        let return_addr = u16::from_le_bytes([
+            cpu.address_map.read(reads[1]),
+            cpu.address_map.read(reads[2]),
+        ]);
+        reads.push(return_addr);
+        reads
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], RTS> for RTS {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], RTS> {
+        parcel::parsers::byte::expect_byte(0x60)
+            .map(|_| RTS)
+            .parse(input)
+    }
+}
+
+/// Represents a `rti` instruction, pulling the processor status and program
+/// counter pushed by an interrupt from the stack.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct RTI;
 
+impl Offset for RTI {}
+
+impl MemoryReads for RTI {
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        stack_reads(cpu, 3)
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], RTI> for RTI {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], RTI> {
+        parcel::parsers::byte::expect_byte(0x40)
+            .map(|_| RTI)
+            .parse(input)
+    }
+}
+
 // Set and Clear
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct CLC;
 
 impl Offset for CLC {}
 
+impl MemoryReads for CLC {}
+
 impl<'a> Parser<'a, &'a [u8], CLC> for CLC {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CLC> {
         parcel::parsers::byte::expect_byte(0xad)
@@ -456,6 +1035,8 @@ pub struct SEC;
 
 impl Offset for SEC {}
 
+impl MemoryReads for SEC {}
+
 impl<'a> Parser<'a, &'a [u8], SEC> for SEC {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SEC> {
         parcel::parsers::byte::expect_byte(0x38)
@@ -469,6 +1050,8 @@ pub struct CLD;
 
 impl Offset for CLD {}
 
+impl MemoryReads for CLD {}
+
 impl<'a> Parser<'a, &'a [u8], CLD> for CLD {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CLD> {
         parcel::parsers::byte::expect_byte(0xd8)
@@ -482,6 +1065,8 @@ pub struct SED;
 
 impl Offset for SED {}
 
+impl MemoryReads for SED {}
+
 impl<'a> Parser<'a, &'a [u8], SED> for SED {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SED> {
         parcel::parsers::byte::expect_byte(0xf8)
@@ -495,6 +1080,8 @@ pub struct CLI;
 
 impl Offset for CLI {}
 
+impl MemoryReads for CLI {}
+
 impl<'a> Parser<'a, &'a [u8], CLI> for CLI {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CLI> {
         parcel::parsers::byte::expect_byte(0x58)
@@ -508,6 +1095,8 @@ pub struct SEI;
 
 impl Offset for SEI {}
 
+impl MemoryReads for SEI {}
+
 impl<'a> Parser<'a, &'a [u8], SEI> for SEI {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SEI> {
         parcel::parsers::byte::expect_byte(0x78)
@@ -521,6 +1110,8 @@ pub struct CLV;
 
 impl Offset for CLV {}
 
+impl MemoryReads for CLV {}
+
 impl<'a> Parser<'a, &'a [u8], CLV> for CLV {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], CLV> {
         parcel::parsers::byte::expect_byte(0xb8)
@@ -530,9 +1121,28 @@ impl<'a> Parser<'a, &'a [u8], CLV> for CLV {
 }
 
 // Misc
+/// Represents a `brk` instruction, forcing a software interrupt through the
+/// IRQ vector.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BRK;
 
+impl Offset for BRK {}
+
+impl MemoryReads for BRK {
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        let vector = cpu.vectors.irq;
+        vec![vector, vector.wrapping_add(1)]
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], BRK> for BRK {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BRK> {
+        parcel::parsers::byte::expect_byte(0x00)
+            .map(|_| BRK)
+            .parse(input)
+    }
+}
+
 /// Represents a `nop` instruction, only implemented for the implied address
 /// mode and functions as a "No Instruction".
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -540,6 +1150,8 @@ pub struct NOP;
 
 impl Offset for NOP {}
 
+impl MemoryReads for NOP {}
+
 impl<'a> Parser<'a, &'a [u8], NOP> for NOP {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], NOP> {
         parcel::parsers::byte::expect_byte(0xea)
@@ -0,0 +1,334 @@
+//! Mnemonics for the instructions added by the 65C02, including the Rockwell
+//! bit manipulation instructions.
+
+use super::{stack_reads, MemoryReads};
+use crate::cpu::{mos6502::MOS6502, Offset};
+use parcel::{ParseResult, Parser};
+
+/// Branch always
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BRA;
+
+impl Offset for BRA {}
+
+impl MemoryReads for BRA {}
+
+impl<'a> Parser<'a, &'a [u8], BRA> for BRA {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BRA> {
+        parcel::parsers::byte::expect_byte(0x80)
+            .map(|_| BRA)
+            .parse(input)
+    }
+}
+
+/// Push Index X on Stack
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PHX;
+
+impl Offset for PHX {}
+
+impl MemoryReads for PHX {}
+
+impl<'a> Parser<'a, &'a [u8], PHX> for PHX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PHX> {
+        parcel::parsers::byte::expect_byte(0xda)
+            .map(|_| PHX)
+            .parse(input)
+    }
+}
+
+/// Push Index Y on Stack
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PHY;
+
+impl Offset for PHY {}
+
+impl MemoryReads for PHY {}
+
+impl<'a> Parser<'a, &'a [u8], PHY> for PHY {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PHY> {
+        parcel::parsers::byte::expect_byte(0x5a)
+            .map(|_| PHY)
+            .parse(input)
+    }
+}
+
+/// Pull Index X from Stack
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PLX;
+
+impl Offset for PLX {}
+
+impl MemoryReads for PLX {
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        stack_reads(cpu, 1)
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], PLX> for PLX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PLX> {
+        parcel::parsers::byte::expect_byte(0xfa)
+            .map(|_| PLX)
+            .parse(input)
+    }
+}
+
+/// Pull Index Y from Stack
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PLY;
+
+impl Offset for PLY {}
+
+impl MemoryReads for PLY {
+    fn reads(&self, cpu: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        stack_reads(cpu, 1)
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], PLY> for PLY {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], PLY> {
+        parcel::parsers::byte::expect_byte(0x7a)
+            .map(|_| PLY)
+            .parse(input)
+    }
+}
+
+/// Store zero in memory
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct STZ;
+
+impl Offset for STZ {}
+
+impl MemoryReads for STZ {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], STZ> for STZ {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], STZ> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x64),
+            parcel::parsers::byte::expect_byte(0x74),
+            parcel::parsers::byte::expect_byte(0x9c),
+            parcel::parsers::byte::expect_byte(0x9e),
+        ])
+        .map(|_| STZ)
+        .parse(input)
+    }
+}
+
+/// Test and reset memory bits with the Accumulator
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TRB;
+
+impl Offset for TRB {}
+
+impl MemoryReads for TRB {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], TRB> for TRB {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TRB> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x14),
+            parcel::parsers::byte::expect_byte(0x1c),
+        ])
+        .map(|_| TRB)
+        .parse(input)
+    }
+}
+
+/// Test and set memory bits with the Accumulator
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TSB;
+
+impl Offset for TSB {}
+
+impl MemoryReads for TSB {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], TSB> for TSB {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], TSB> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x04),
+            parcel::parsers::byte::expect_byte(0x0c),
+        ])
+        .map(|_| TSB)
+        .parse(input)
+    }
+}
+
+/// Wait for an interrupt
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WAI;
+
+impl Offset for WAI {}
+
+impl MemoryReads for WAI {}
+
+impl<'a> Parser<'a, &'a [u8], WAI> for WAI {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], WAI> {
+        parcel::parsers::byte::expect_byte(0xcb)
+            .map(|_| WAI)
+            .parse(input)
+    }
+}
+
+/// Stop the clock until the cpu is reset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct STP;
+
+impl Offset for STP {}
+
+impl MemoryReads for STP {}
+
+impl<'a> Parser<'a, &'a [u8], STP> for STP {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], STP> {
+        parcel::parsers::byte::expect_byte(0xdb)
+            .map(|_| STP)
+            .parse(input)
+    }
+}
+
+/// Branch if the given bit of a zeropage value is reset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BBR<const BIT: u8>;
+
+impl<const BIT: u8> Offset for BBR<BIT> {}
+
+impl<const BIT: u8> MemoryReads for BBR<BIT> {}
+
+impl<'a, const BIT: u8> Parser<'a, &'a [u8], BBR<BIT>> for BBR<BIT> {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BBR<BIT>> {
+        parcel::parsers::byte::expect_byte(0x0f + (BIT << 4))
+            .map(|_| BBR)
+            .parse(input)
+    }
+}
+
+/// Branch if the given bit of a zeropage value is set
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BBS<const BIT: u8>;
+
+impl<const BIT: u8> Offset for BBS<BIT> {}
+
+impl<const BIT: u8> MemoryReads for BBS<BIT> {}
+
+impl<'a, const BIT: u8> Parser<'a, &'a [u8], BBS<BIT>> for BBS<BIT> {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], BBS<BIT>> {
+        parcel::parsers::byte::expect_byte(0x8f + (BIT << 4))
+            .map(|_| BBS)
+            .parse(input)
+    }
+}
+
+/// Reset the given bit of a zeropage value
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RMB<const BIT: u8>;
+
+impl<const BIT: u8> Offset for RMB<BIT> {}
+
+impl<const BIT: u8> MemoryReads for RMB<BIT> {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a, const BIT: u8> Parser<'a, &'a [u8], RMB<BIT>> for RMB<BIT> {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], RMB<BIT>> {
+        parcel::parsers::byte::expect_byte(0x07 + (BIT << 4))
+            .map(|_| RMB)
+            .parse(input)
+    }
+}
+
+/// Set the given bit of a zeropage value
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SMB<const BIT: u8>;
+
+impl<const BIT: u8> Offset for SMB<BIT> {}
+
+impl<const BIT: u8> MemoryReads for SMB<BIT> {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a, const BIT: u8> Parser<'a, &'a [u8], SMB<BIT>> for SMB<BIT> {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SMB<BIT>> {
+        parcel::parsers::byte::expect_byte(0x87 + (BIT << 4))
+            .map(|_| SMB)
+            .parse(input)
+    }
+}
+
+/// Reserved opcodes, which the 65C02 executes as no-ops of varying sizes
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RSV;
+
+impl Offset for RSV {}
+
+impl MemoryReads for RSV {}
+
+impl<'a> Parser<'a, &'a [u8], RSV> for RSV {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], RSV> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x02),
+            parcel::parsers::byte::expect_byte(0x03),
+            parcel::parsers::byte::expect_byte(0x0b),
+            parcel::parsers::byte::expect_byte(0x13),
+            parcel::parsers::byte::expect_byte(0x1b),
+            parcel::parsers::byte::expect_byte(0x22),
+            parcel::parsers::byte::expect_byte(0x23),
+            parcel::parsers::byte::expect_byte(0x2b),
+            parcel::parsers::byte::expect_byte(0x33),
+            parcel::parsers::byte::expect_byte(0x3b),
+            parcel::parsers::byte::expect_byte(0x42),
+            parcel::parsers::byte::expect_byte(0x43),
+            parcel::parsers::byte::expect_byte(0x44),
+            parcel::parsers::byte::expect_byte(0x4b),
+            parcel::parsers::byte::expect_byte(0x53),
+            parcel::parsers::byte::expect_byte(0x54),
+            parcel::parsers::byte::expect_byte(0x5b),
+            parcel::parsers::byte::expect_byte(0x5c),
+            parcel::parsers::byte::expect_byte(0x62),
+            parcel::parsers::byte::expect_byte(0x63),
+            parcel::parsers::byte::expect_byte(0x6b),
+            parcel::parsers::byte::expect_byte(0x73),
+            parcel::parsers::byte::expect_byte(0x7b),
+            parcel::parsers::byte::expect_byte(0x82),
+            parcel::parsers::byte::expect_byte(0x83),
+            parcel::parsers::byte::expect_byte(0x8b),
+            parcel::parsers::byte::expect_byte(0x93),
+            parcel::parsers::byte::expect_byte(0x9b),
+            parcel::parsers::byte::expect_byte(0xa3),
+            parcel::parsers::byte::expect_byte(0xab),
+            parcel::parsers::byte::expect_byte(0xb3),
+            parcel::parsers::byte::expect_byte(0xbb),
+            parcel::parsers::byte::expect_byte(0xc2),
+            parcel::parsers::byte::expect_byte(0xc3),
+            parcel::parsers::byte::expect_byte(0xd3),
+            parcel::parsers::byte::expect_byte(0xd4),
+            parcel::parsers::byte::expect_byte(0xdc),
+            parcel::parsers::byte::expect_byte(0xe2),
+            parcel::parsers::byte::expect_byte(0xe3),
+            parcel::parsers::byte::expect_byte(0xeb),
+            parcel::parsers::byte::expect_byte(0xf3),
+            parcel::parsers::byte::expect_byte(0xf4),
+            parcel::parsers::byte::expect_byte(0xfb),
+            parcel::parsers::byte::expect_byte(0xfc),
+        ])
+        .map(|_| RSV)
+        .parse(input)
+    }
+}
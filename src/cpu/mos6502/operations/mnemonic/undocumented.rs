@@ -0,0 +1,327 @@
+//! Mnemonics for the stable undocumented opcodes of the NMOS 6502.
+
+use super::MemoryReads;
+use crate::cpu::{mos6502::MOS6502, Offset};
+use parcel::{ParseResult, Parser};
+
+/// Load operand into both the Accumulator and X Register
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LAX;
+
+impl Offset for LAX {}
+
+impl MemoryReads for LAX {}
+
+impl<'a> Parser<'a, &'a [u8], LAX> for LAX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], LAX> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xa7),
+            parcel::parsers::byte::expect_byte(0xb7),
+            parcel::parsers::byte::expect_byte(0xaf),
+            parcel::parsers::byte::expect_byte(0xbf),
+            parcel::parsers::byte::expect_byte(0xa3),
+            parcel::parsers::byte::expect_byte(0xb3),
+        ])
+        .map(|_| LAX)
+        .parse(input)
+    }
+}
+
+/// Store the Accumulator ANDed with the X Register in memory
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SAX;
+
+impl Offset for SAX {}
+
+impl MemoryReads for SAX {
+    fn reads(&self, _: &MOS6502, _: Option<u16>) -> Vec<u16> {
+        vec![]
+    }
+
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], SAX> for SAX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SAX> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x87),
+            parcel::parsers::byte::expect_byte(0x97),
+            parcel::parsers::byte::expect_byte(0x8f),
+            parcel::parsers::byte::expect_byte(0x83),
+        ])
+        .map(|_| SAX)
+        .parse(input)
+    }
+}
+
+/// Decrement memory by one then compare it with the Accumulator
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DCP;
+
+impl Offset for DCP {}
+
+impl MemoryReads for DCP {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], DCP> for DCP {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], DCP> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xc7),
+            parcel::parsers::byte::expect_byte(0xd7),
+            parcel::parsers::byte::expect_byte(0xcf),
+            parcel::parsers::byte::expect_byte(0xdf),
+            parcel::parsers::byte::expect_byte(0xdb),
+            parcel::parsers::byte::expect_byte(0xc3),
+            parcel::parsers::byte::expect_byte(0xd3),
+        ])
+        .map(|_| DCP)
+        .parse(input)
+    }
+}
+
+/// Increment memory by one then subtract it from the Accumulator with borrow
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ISC;
+
+impl Offset for ISC {}
+
+impl MemoryReads for ISC {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], ISC> for ISC {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ISC> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0xe7),
+            parcel::parsers::byte::expect_byte(0xf7),
+            parcel::parsers::byte::expect_byte(0xef),
+            parcel::parsers::byte::expect_byte(0xff),
+            parcel::parsers::byte::expect_byte(0xfb),
+            parcel::parsers::byte::expect_byte(0xe3),
+            parcel::parsers::byte::expect_byte(0xf3),
+        ])
+        .map(|_| ISC)
+        .parse(input)
+    }
+}
+
+/// Shift memory left one bit then OR it with the Accumulator
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SLO;
+
+impl Offset for SLO {}
+
+impl MemoryReads for SLO {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], SLO> for SLO {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SLO> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x07),
+            parcel::parsers::byte::expect_byte(0x17),
+            parcel::parsers::byte::expect_byte(0x0f),
+            parcel::parsers::byte::expect_byte(0x1f),
+            parcel::parsers::byte::expect_byte(0x1b),
+            parcel::parsers::byte::expect_byte(0x03),
+            parcel::parsers::byte::expect_byte(0x13),
+        ])
+        .map(|_| SLO)
+        .parse(input)
+    }
+}
+
+/// Rotate memory left one bit then AND it with the Accumulator
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RLA;
+
+impl Offset for RLA {}
+
+impl MemoryReads for RLA {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], RLA> for RLA {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], RLA> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x27),
+            parcel::parsers::byte::expect_byte(0x37),
+            parcel::parsers::byte::expect_byte(0x2f),
+            parcel::parsers::byte::expect_byte(0x3f),
+            parcel::parsers::byte::expect_byte(0x3b),
+            parcel::parsers::byte::expect_byte(0x23),
+            parcel::parsers::byte::expect_byte(0x33),
+        ])
+        .map(|_| RLA)
+        .parse(input)
+    }
+}
+
+/// Shift memory right one bit then EOR it with the Accumulator
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SRE;
+
+impl Offset for SRE {}
+
+impl MemoryReads for SRE {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], SRE> for SRE {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SRE> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x47),
+            parcel::parsers::byte::expect_byte(0x57),
+            parcel::parsers::byte::expect_byte(0x4f),
+            parcel::parsers::byte::expect_byte(0x5f),
+            parcel::parsers::byte::expect_byte(0x5b),
+            parcel::parsers::byte::expect_byte(0x43),
+            parcel::parsers::byte::expect_byte(0x53),
+        ])
+        .map(|_| SRE)
+        .parse(input)
+    }
+}
+
+/// Rotate memory right one bit then add it to the Accumulator with carry
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RRA;
+
+impl Offset for RRA {}
+
+impl MemoryReads for RRA {
+    fn writes(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Parser<'a, &'a [u8], RRA> for RRA {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], RRA> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x67),
+            parcel::parsers::byte::expect_byte(0x77),
+            parcel::parsers::byte::expect_byte(0x6f),
+            parcel::parsers::byte::expect_byte(0x7f),
+            parcel::parsers::byte::expect_byte(0x7b),
+            parcel::parsers::byte::expect_byte(0x63),
+            parcel::parsers::byte::expect_byte(0x73),
+        ])
+        .map(|_| RRA)
+        .parse(input)
+    }
+}
+
+/// AND operand with the Accumulator, copying bit 7 of the result into carry
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ANC;
+
+impl Offset for ANC {}
+
+impl MemoryReads for ANC {}
+
+impl<'a> Parser<'a, &'a [u8], ANC> for ANC {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ANC> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x0b),
+            parcel::parsers::byte::expect_byte(0x2b),
+        ])
+        .map(|_| ANC)
+        .parse(input)
+    }
+}
+
+/// AND operand with the Accumulator then shift the Accumulator right one bit
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ALR;
+
+impl Offset for ALR {}
+
+impl MemoryReads for ALR {}
+
+impl<'a> Parser<'a, &'a [u8], ALR> for ALR {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ALR> {
+        parcel::parsers::byte::expect_byte(0x4b)
+            .map(|_| ALR)
+            .parse(input)
+    }
+}
+
+/// AND operand with the Accumulator then rotate the Accumulator right one bit
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ARR;
+
+impl Offset for ARR {}
+
+impl MemoryReads for ARR {}
+
+impl<'a> Parser<'a, &'a [u8], ARR> for ARR {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], ARR> {
+        parcel::parsers::byte::expect_byte(0x6b)
+            .map(|_| ARR)
+            .parse(input)
+    }
+}
+
+/// Subtract operand from the Accumulator ANDed with the X Register, storing
+/// the result in the X Register
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SBX;
+
+impl Offset for SBX {}
+
+impl MemoryReads for SBX {}
+
+impl<'a> Parser<'a, &'a [u8], SBX> for SBX {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], SBX> {
+        parcel::parsers::byte::expect_byte(0xcb)
+            .map(|_| SBX)
+            .parse(input)
+    }
+}
+
+/// Lock up the cpu until it is reset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct JAM;
+
+// the program counter is never advanced past a jam.
+impl Offset for JAM {
+    fn offset(&self) -> usize {
+        0
+    }
+}
+
+impl MemoryReads for JAM {}
+
+impl<'a> Parser<'a, &'a [u8], JAM> for JAM {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], JAM> {
+        parcel::one_of(vec![
+            parcel::parsers::byte::expect_byte(0x02),
+            parcel::parsers::byte::expect_byte(0x12),
+            parcel::parsers::byte::expect_byte(0x22),
+            parcel::parsers::byte::expect_byte(0x32),
+            parcel::parsers::byte::expect_byte(0x42),
+            parcel::parsers::byte::expect_byte(0x52),
+            parcel::parsers::byte::expect_byte(0x62),
+            parcel::parsers::byte::expect_byte(0x72),
+            parcel::parsers::byte::expect_byte(0x92),
+            parcel::parsers::byte::expect_byte(0xb2),
+            parcel::parsers::byte::expect_byte(0xd2),
+            parcel::parsers::byte::expect_byte(0xf2),
+        ])
+        .map(|_| JAM)
+        .parse(input)
+    }
+}
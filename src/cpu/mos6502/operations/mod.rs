@@ -1,11 +1,12 @@
 extern crate parcel;
 use crate::address_map::Addressable;
 use crate::cpu::{
-    mos6502::{microcode::Microcode, register::*, Generate, MOS6502},
+    mops::BusCycle,
+    mos6502::{microcode::Microcode, register::*, Generate, Variant, MOS6502},
     register::Register,
     Cyclable, Offset,
 };
-use parcel::{parsers::byte::expect_byte, ParseResult, Parser};
+use parcel::{parsers::byte::expect_byte, BoxedParser, ParseResult, Parser};
 use std::fmt::Debug;
 use std::num::Wrapping;
 use std::ops::{Add, RangeInclusive, Sub};
@@ -13,6 +14,9 @@ use std::ops::{Add, RangeInclusive, Sub};
 pub mod address_mode;
 pub mod mnemonic;
 
+use address_mode::EffectiveAddress;
+use mnemonic::MemoryReads;
+
 #[cfg(test)]
 mod tests;
 
@@ -138,11 +142,11 @@ fn add_index_to_zeropage_address(zeropage_addr: u8, index: u8) -> u16 {
 /// indirect address. This is effectively taking the value at
 /// (Operand + Index, addr at Operand + Index + 1).
 fn dereference_indexed_indirect_address(cpu: &MOS6502, base_addr: u8, index: u8) -> u16 {
+    let indexed_addr = base_addr.overflowing_add(index).0;
     u16::from_le_bytes([
+        cpu.address_map.read(indexed_addr as u16),
         cpu.address_map
-            .read(base_addr.overflowing_add(index).0 as u16),
-        cpu.address_map
-            .read(base_addr.overflowing_add(index + 1).0 as u16),
+            .read(indexed_addr.overflowing_add(1).0 as u16),
     ])
 }
 
@@ -150,10 +154,11 @@ fn dereference_indexed_indirect_address(cpu: &MOS6502, base_addr: u8, index: u8)
 /// address and then adding an index to that indirect address. This is
 /// effectively the value at (Operand, Operand + 1) + Index.
 fn dereference_indirect_indexed_address(cpu: &MOS6502, base_addr: u8, index: u8) -> u16 {
-    u16::from_le_bytes([
+    let indirect_addr = u16::from_le_bytes([
         cpu.address_map.read(base_addr as u16),
         cpu.address_map.read(base_addr.overflowing_add(1).0 as u16),
-    ]) + index as u16
+    ]);
+    add_index_to_address(indirect_addr, index)
 }
 
 /// Provides a wrapper around the common operation of dereferencing and address
@@ -166,6 +171,14 @@ fn dereference_address_to_operand(cpu: &MOS6502, addr: u16, index: u8) -> Operan
     )
 }
 
+/// Reads the little-endian address stored at a vector, such as the address
+/// of an interrupt handler.
+fn dereference_vector(cpu: &MOS6502, vector: u16) -> u16 {
+    let lsb = cpu.address_map.read(vector);
+    let msb = cpu.address_map.read(vector.overflowing_add(1).0);
+    u16::from_le_bytes([lsb, msb])
+}
+
 /// Provides a wrapper around generating a 16-bit address from the stack
 /// pointer. This exists as a function solely to not lose intent in the type
 /// conversion to u16.
@@ -173,62 +186,319 @@ fn stack_pointer_from_byte_value(value: u8) -> u16 {
     u16::from_le_bytes([value, 0x01])
 }
 
-/// MOps functions as a concrete wrapper around a microcode operation with
-/// metadata around sizing and cycles. This trait does NOT represent a cycle
-/// but rather the microcode equivalent of a CPU instruction.
-#[derive(Debug, Clone, PartialEq)]
-pub struct MOps {
-    offset: usize,
-    cycles: usize,
-    microcode: Vec<Microcode>,
+// Arithmetic
+
+/// Returns the additional cycle the 65C02 takes to correct the result of an
+/// ADC or SBC in decimal mode.
+fn decimal_penalty(cpu: &MOS6502) -> usize {
+    (cpu.variant == Variant::W65C02 && cpu.decimal_mode()) as usize
 }
 
-impl MOps {
-    pub fn new(offset: usize, cycles: usize, microcode: Vec<Microcode>) -> Self {
+/// Generates the microcode for adding an operand and the carry flag to the
+/// accumulator. When the decimal flag is set, both values are treated as
+/// packed BCD. On the NMOS 6502, N and V are derived from the intermediate
+/// result prior to the high nibble adjustment and Z reflects the binary sum,
+/// while the 65C02 derives N and Z from the corrected result.
+fn add_with_carry(cpu: &MOS6502, operand: Operand<u8>) -> Vec<Microcode> {
+    add_with_carry_in(cpu, operand, cpu.ps.carry)
+}
+
+/// Generates the microcode for an add with carry, as `add_with_carry`, using
+/// the provided carry in place of the carry flag. This supports instructions
+/// that modify the carry prior to the addition.
+fn add_with_carry_in(cpu: &MOS6502, operand: Operand<u8>, carry: bool) -> Vec<Microcode> {
+    let (lhs, rhs) = (cpu.acc.read(), operand.unwrap());
+    let carry_in = carry as u16;
+    let binary_sum = lhs as u16 + rhs as u16 + carry_in;
+    let (sum, carry, negative, overflow) = if cpu.decimal_mode() {
+        let mut low_nibble = (lhs & 0x0f) as u16 + (rhs & 0x0f) as u16 + carry_in;
+        if low_nibble >= 0x0a {
+            low_nibble = ((low_nibble + 0x06) & 0x0f) + 0x10;
+        }
+
+        // N and V are taken from the signed sum prior to adjusting the high nibble.
+        let signed_sum = (lhs & 0xf0) as i8 as i16 + (rhs & 0xf0) as i8 as i16 + low_nibble as i16;
+        let negative = (signed_sum & 0x80) != 0;
+        let overflow = !(-128..=127).contains(&signed_sum);
+
+        let mut sum = (lhs & 0xf0) as u16 + (rhs & 0xf0) as u16 + low_nibble;
+        if sum >= 0xa0 {
+            sum += 0x60;
+        }
+
+        let negative = match cpu.variant {
+            Variant::W65C02 => sum as u8 > 127,
+            _ => negative,
+        };
+
+        (sum as u8, sum > 0xff, negative, overflow)
+    } else {
+        let sum = binary_sum as u8;
+        let overflow = ((lhs ^ sum) & (rhs ^ sum) & 0x80) != 0;
+
+        (sum, binary_sum > 0xff, sum > 127, overflow)
+    };
+    let zero = match cpu.variant {
+        Variant::W65C02 => sum == 0,
+        _ => binary_sum as u8 == 0,
+    };
+
+    vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, negative),
+        gen_flag_set_microcode!(ProgramStatusFlags::Overflow, overflow),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, zero),
+        gen_write_8bit_register_microcode!(ByteRegisters::ACC, sum),
+    ]
+}
+
+/// Generates the microcode for subtracting an operand and the borrow, the
+/// inverse of the carry flag, from the accumulator. When the decimal flag is
+/// set the accumulator is adjusted as packed BCD. On the NMOS 6502 all flags
+/// continue to reflect the binary difference, while the 65C02 corrects the
+/// high nibble before the low and derives N and Z from the corrected result.
+fn subtract_with_borrow(cpu: &MOS6502, operand: Operand<u8>) -> Vec<Microcode> {
+    let (lhs, rhs) = (cpu.acc.read(), operand.unwrap());
+    let borrow = (!cpu.ps.carry) as i16;
+    let binary_difference = lhs as i16 - rhs as i16 - borrow;
+    let binary_result = binary_difference as u8;
+    let carry = binary_difference >= 0;
+    let overflow = ((lhs ^ rhs) & (lhs ^ binary_result) & 0x80) != 0;
+
+    let difference = if cpu.decimal_mode() && cpu.variant == Variant::W65C02 {
+        let low_nibble = (lhs & 0x0f) as i16 - (rhs & 0x0f) as i16 - borrow;
+        let mut difference = binary_difference;
+        if difference < 0 {
+            difference -= 0x60;
+        }
+        if low_nibble < 0 {
+            difference -= 0x06;
+        }
+
+        difference as u8
+    } else if cpu.decimal_mode() {
+        let mut low_nibble = (lhs & 0x0f) as i16 - (rhs & 0x0f) as i16 - borrow;
+        if low_nibble < 0 {
+            low_nibble = ((low_nibble - 0x06) & 0x0f) - 0x10;
+        }
+
+        let mut difference = (lhs & 0xf0) as i16 - (rhs & 0xf0) as i16 + low_nibble;
+        if difference < 0 {
+            difference -= 0x60;
+        }
+
+        difference as u8
+    } else {
+        binary_result
+    };
+    let flag_result = match cpu.variant {
+        Variant::W65C02 => difference,
+        _ => binary_result,
+    };
+
+    vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, flag_result > 127),
+        gen_flag_set_microcode!(ProgramStatusFlags::Overflow, overflow),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, flag_result == 0),
+        gen_write_8bit_register_microcode!(ByteRegisters::ACC, difference),
+    ]
+}
+
+// Interrupts
+
+/// The address of the vector that the NMI handler is loaded from.
+pub const NMI_VECTOR: u16 = 0xfffa;
+
+/// The address of the vector that the program counter is loaded from on
+/// reset.
+pub const RESET_VECTOR: u16 = 0xfffc;
+
+/// The address of the vector that the IRQ and BRK handlers are loaded from.
+pub const IRQ_VECTOR: u16 = 0xfffe;
+
+/// Vectors holds the addresses of the NMI, reset and IRQ vectors for a cpu.
+/// These default to the standard 6502 locations at the top of the address
+/// space but can be relocated for hardware with a non-standard layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vectors {
+    pub nmi: u16,
+    pub reset: u16,
+    pub irq: u16,
+}
+
+impl Default for Vectors {
+    fn default() -> Self {
         Self {
-            offset,
-            cycles,
-            microcode,
+            nmi: NMI_VECTOR,
+            reset: RESET_VECTOR,
+            irq: IRQ_VECTOR,
+        }
+    }
+}
+
+/// Represents the hardware interrupts that the cpu services between
+/// instructions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interrupt {
+    Nmi,
+    Irq,
+}
+
+impl Interrupt {
+    /// Returns the address of the vector that the interrupt handler is loaded
+    /// from.
+    pub fn vector(self, vectors: &Vectors) -> u16 {
+        match self {
+            Self::Nmi => vectors.nmi,
+            Self::Irq => vectors.irq,
         }
     }
+
+    /// Returns the addresses read when entering the interrupt handler, being
+    /// the two discarded reads of the program counter followed by the bytes of
+    /// its vector.
+    pub fn reads(self, cpu: &MOS6502) -> Vec<u16> {
+        let (pc, vector) = (cpu.pc.read(), self.vector(&cpu.vectors));
+        vec![pc, pc, vector, vector.wrapping_add(1)]
+    }
 }
 
-impl Cyclable for MOps {
+impl Cyclable for Interrupt {
     fn cycles(&self) -> usize {
-        self.cycles
+        7
     }
 }
 
-impl Offset for MOps {
+impl Offset for Interrupt {
     fn offset(&self) -> usize {
-        self.offset
+        0
     }
 }
 
-impl From<MOps> for Vec<Vec<Microcode>> {
-    fn from(src: MOps) -> Self {
-        let cycles = src.cycles();
-        let offset = src.offset() as u16;
-        let mut mcs = vec![Vec::<Microcode>::new(); cycles - 1];
+impl Generate<MOS6502, MOps> for Interrupt {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let handler = dereference_vector(cpu, self.vector(&cpu.vectors));
+
+        push_interrupt_frame(
+            cpu,
+            self.offset(),
+            cpu.pc.read(),
+            false,
+            handler - self.offset() as u16,
+        )
+    }
+}
+
+/// Generates the operation for entering an interrupt handler, pushing the
+/// return address and processor status to the stack on cycles 3 through 5,
+/// disabling interrupts and jumping to the handler. The break flag is only
+/// set in the pushed status for a `brk`.
+fn push_interrupt_frame(
+    cpu: &MOS6502,
+    offset: usize,
+    return_addr: u16,
+    brk: bool,
+    pc: u16,
+) -> MOps {
+    let sp = cpu.sp.read();
+    let [lsb, msb] = return_addr.to_le_bytes();
+
+    // the unused bit is always set when pushed to the stack.
+    let status = (cpu.ps.read() & 0xcf) | 0x20 | ((brk as u8) << 4);
+
+    // the 65C02 clears decimal mode on entering a handler.
+    let decimal = match cpu.variant {
+        Variant::MOS6502 | Variant::Ricoh2A03 => vec![],
+        Variant::W65C02 => vec![gen_flag_set_microcode!(ProgramStatusFlags::Decimal, false)],
+    };
+
+    MOps::new(
+        offset,
+        7,
+        vec![
+            gen_dec_8bit_register_microcode!(ByteRegisters::SP, 3),
+            gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
+            gen_write_16bit_register_microcode!(WordRegisters::PC, pc),
+        ]
+        .into_iter()
+        .chain(decimal)
+        .collect(),
+    )
+    .with_microcode_on_cycle(
+        3,
+        vec![gen_write_memory_microcode!(
+            stack_pointer_from_byte_value(sp),
+            msb
+        )],
+    )
+    .with_microcode_on_cycle(
+        4,
+        vec![gen_write_memory_microcode!(
+            stack_pointer_from_byte_value(sp.overflowing_sub(1).0),
+            lsb
+        )],
+    )
+    .with_microcode_on_cycle(
+        5,
+        vec![gen_write_memory_microcode!(
+            stack_pointer_from_byte_value(sp.overflowing_sub(2).0),
+            status
+        )],
+    )
+}
+
+/// MOps represents the microcode equivalent of a 6502 instruction, scheduled
+/// cycle by cycle.
+pub type MOps = crate::cpu::mops::MOps<Microcode>;
+
+impl BusCycle for Microcode {
+    type Address = u16;
+
+    fn read(address: u16) -> Self {
+        gen_read_memory_microcode!(address)
+    }
+
+    fn fetch(address: u16, offset: usize) -> Self {
+        gen_read_memory_microcode!(address.wrapping_add(offset as u16))
+    }
+
+    fn advance(offset: usize) -> Self {
+        gen_inc_16bit_register_microcode!(WordRegisters::PC, offset as u16)
+    }
+
+    fn accesses_memory(&self) -> bool {
+        matches!(self, Microcode::ReadMemory(_) | Microcode::WriteMemory(_))
+    }
+}
 
-        mcs.push(
-            src.microcode
-                .into_iter()
-                .chain(
-                    vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, offset)].into_iter(),
-                )
-                .collect(),
-        );
-        mcs
+impl MOps {
+    /// Schedules the write of the unmodified value that a read-modify-write
+    /// instruction performs on the cycle prior to writing the result. The
+    /// 65C02 replaces this with a second read of the address.
+    pub fn with_dummy_write(self, cpu: &MOS6502, addr: u16, value: u8) -> Self {
+        let cycle = self.cycles() - 1;
+        match cpu.variant {
+            Variant::MOS6502 | Variant::Ricoh2A03 => {
+                self.with_microcode_on_cycle(cycle, vec![gen_write_memory_microcode!(addr, value)])
+            }
+            Variant::W65C02 => {
+                self.with_microcode_on_cycle(cycle, vec![gen_read_memory_microcode!(addr)])
+            }
+        }
     }
 }
 
+/// Returns the addresses an operation reads from memory on a given cpu.
+type Reads = Box<dyn Fn(&MOS6502) -> Vec<u16>>;
+
 /// Operation functions as a concrete wrapper around all executable components
 /// of a 6502 operation.
 pub struct Operation {
     offset: usize,
     cycles: usize,
     generator: Box<dyn Fn(&MOS6502) -> MOps>,
+    reads: Reads,
 }
 
 impl Operation {
@@ -237,8 +507,22 @@ impl Operation {
             offset,
             cycles,
             generator,
+            reads: Box::new(|_| vec![]),
         }
     }
+
+    /// Sets the function returning the addresses the operation reads from
+    /// memory, returning the modified operation.
+    pub fn with_reads(mut self, reads: Reads) -> Self {
+        self.reads = reads;
+        self
+    }
+
+    /// Returns the addresses, excluding the instruction itself, that the
+    /// operation reads from memory when executed on the provided cpu.
+    pub fn reads(&self, cpu: &MOS6502) -> Vec<u16> {
+        (self.reads)(cpu)
+    }
 }
 
 impl Cyclable for Operation {
@@ -259,12 +543,64 @@ impl Generate<MOS6502, MOps> for Operation {
     }
 }
 
+/// DecodeError represents the conditions under which bytes can't be decoded
+/// into an operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecodeError {
+    /// The opcode doesn't match any operation in the instruction set,
+    /// carrying the opcode and the two bytes following it.
+    UndecodableOpcode { opcode: u8, operands: [u8; 2] },
+}
+
+impl DecodeError {
+    fn undecodable(values: &[u8; 3]) -> Self {
+        DecodeError::UndecodableOpcode {
+            opcode: values[0],
+            operands: [values[1], values[2]],
+        }
+    }
+
+    /// Returns the opcode that failed to decode.
+    pub fn opcode(&self) -> u8 {
+        match self {
+            DecodeError::UndecodableOpcode { opcode, .. } => *opcode,
+        }
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UndecodableOpcode { opcode, operands } => write!(
+                f,
+                "no operation matches opcode {:#04x} with operands {:#04x} {:#04x}",
+                opcode, operands[0], operands[1]
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Decodes an operation from the instruction set of the provided variant.
+pub(crate) fn decode(variant: Variant, values: &[u8; 3]) -> Result<Operation, DecodeError> {
+    let result = match variant {
+        Variant::MOS6502 | Variant::Ricoh2A03 => OperationParser.parse(values),
+        Variant::W65C02 => CmosOperationParser.parse(values),
+    };
+
+    match result {
+        Ok(parcel::MatchStatus::Match((_, op))) => Ok(op),
+        _ => Err(DecodeError::undecodable(values)),
+    }
+}
+
 impl std::convert::TryFrom<&[u8; 3]> for Operation {
-    type Error = String;
+    type Error = DecodeError;
     fn try_from(values: &[u8; 3]) -> std::result::Result<Self, Self::Error> {
         match OperationParser.parse(values) {
             Ok(parcel::MatchStatus::Match((_, op))) => Ok(op),
-            _ => Err(format!("No match found for {}", values[0])),
+            _ => Err(DecodeError::undecodable(values)),
         }
     }
 }
@@ -285,72 +621,186 @@ struct OperationParser;
 
 impl<'a> Parser<'a, &'a [u8], Operation> for OperationParser {
     fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], Operation> {
-        parcel::one_of(vec![
-            inst_to_operation!(mnemonic::BCC, address_mode::Relative::default()),
-            inst_to_operation!(mnemonic::BCS, address_mode::Relative::default()),
-            inst_to_operation!(mnemonic::BEQ, address_mode::Relative::default()),
-            inst_to_operation!(mnemonic::BNE, address_mode::Relative::default()),
-            inst_to_operation!(mnemonic::CLC, address_mode::Implied),
-            inst_to_operation!(mnemonic::CLD, address_mode::Implied),
-            inst_to_operation!(mnemonic::CLI, address_mode::Implied),
-            inst_to_operation!(mnemonic::CLV, address_mode::Implied),
-            inst_to_operation!(mnemonic::CMP, address_mode::Immediate::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::AbsoluteIndexedWithX::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::AbsoluteIndexedWithY::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::IndirectYIndexed::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::XIndexedIndirect::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::ZeroPage::default()),
-            inst_to_operation!(mnemonic::CMP, address_mode::ZeroPageIndexedWithX::default()),
-            inst_to_operation!(mnemonic::INC, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::INX, address_mode::Implied),
-            inst_to_operation!(mnemonic::INY, address_mode::Implied),
-            inst_to_operation!(mnemonic::JMP, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::JMP, address_mode::Indirect::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::AbsoluteIndexedWithX::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::AbsoluteIndexedWithY::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::IndirectYIndexed::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::Immediate::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::XIndexedIndirect::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::ZeroPage::default()),
-            inst_to_operation!(mnemonic::LDA, address_mode::ZeroPageIndexedWithX::default()),
-            inst_to_operation!(mnemonic::LDX, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::LDX, address_mode::AbsoluteIndexedWithY::default()),
-            inst_to_operation!(mnemonic::LDX, address_mode::Immediate::default()),
-            inst_to_operation!(mnemonic::LDX, address_mode::ZeroPage::default()),
-            inst_to_operation!(mnemonic::LDX, address_mode::ZeroPageIndexedWithY::default()),
-            inst_to_operation!(mnemonic::LDY, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::LDY, address_mode::AbsoluteIndexedWithX::default()),
-            inst_to_operation!(mnemonic::LDY, address_mode::Immediate::default()),
-            inst_to_operation!(mnemonic::LDY, address_mode::ZeroPage::default()),
-            inst_to_operation!(mnemonic::LDY, address_mode::ZeroPageIndexedWithX::default()),
-            inst_to_operation!(mnemonic::NOP, address_mode::Implied),
-            inst_to_operation!(mnemonic::PHA, address_mode::Implied),
-            inst_to_operation!(mnemonic::PHP, address_mode::Implied),
-            inst_to_operation!(mnemonic::PLA, address_mode::Implied),
-            inst_to_operation!(mnemonic::PLP, address_mode::Implied),
-            inst_to_operation!(mnemonic::STA, address_mode::Absolute::default()),
-            inst_to_operation!(mnemonic::STA, address_mode::AbsoluteIndexedWithX::default()),
-            inst_to_operation!(mnemonic::STA, address_mode::AbsoluteIndexedWithY::default()),
-            inst_to_operation!(mnemonic::STA, address_mode::IndirectYIndexed::default()),
-            inst_to_operation!(mnemonic::STA, address_mode::XIndexedIndirect::default()),
-            inst_to_operation!(mnemonic::STA, address_mode::ZeroPage::default()),
-            inst_to_operation!(mnemonic::STA, address_mode::ZeroPageIndexedWithX::default()),
-            inst_to_operation!(mnemonic::SEC, address_mode::Implied),
-            inst_to_operation!(mnemonic::SED, address_mode::Implied),
-            inst_to_operation!(mnemonic::SEI, address_mode::Implied),
-            inst_to_operation!(mnemonic::TAX, address_mode::Implied),
-            inst_to_operation!(mnemonic::TAY, address_mode::Implied),
-            inst_to_operation!(mnemonic::TSX, address_mode::Implied),
-            inst_to_operation!(mnemonic::TXA, address_mode::Implied),
-            inst_to_operation!(mnemonic::TXS, address_mode::Implied),
-            inst_to_operation!(mnemonic::TYA, address_mode::Implied),
-        ])
-        .parse(input)
+        #[allow(unused_mut)]
+        let mut parsers = documented_operation_parsers();
+
+        #[cfg(feature = "illegal-opcodes")]
+        parsers.extend(undocumented::operation_parsers());
+
+        parcel::one_of(parsers).parse(input)
+    }
+}
+
+/// Provides a wrapper type for parsing byte slices into the Operations of the
+/// 65C02, whose additions take precedence over the documented NMOS opcodes.
+struct CmosOperationParser;
+
+impl<'a> Parser<'a, &'a [u8], Operation> for CmosOperationParser {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], Operation> {
+        let mut parsers = cmos::operation_parsers();
+        parsers.extend(documented_operation_parsers());
+
+        parcel::one_of(parsers).parse(input)
     }
 }
 
+/// Returns parsers for each of the documented operations of the NMOS 6502.
+fn documented_operation_parsers<'a>() -> Vec<BoxedParser<'a, &'a [u8], Operation>> {
+    vec![
+        inst_to_operation!(mnemonic::ADC, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::ADC, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::AND, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ASL, address_mode::Accumulator),
+        inst_to_operation!(mnemonic::ASL, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::ASL, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ASL, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::ASL, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::BCC, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BCS, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BEQ, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BIT, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::BIT, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::BMI, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BNE, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BPL, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BRK, address_mode::Implied),
+        inst_to_operation!(mnemonic::BVC, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::BVS, address_mode::Relative::default()),
+        inst_to_operation!(mnemonic::CLC, address_mode::Implied),
+        inst_to_operation!(mnemonic::CLD, address_mode::Implied),
+        inst_to_operation!(mnemonic::CLI, address_mode::Implied),
+        inst_to_operation!(mnemonic::CLV, address_mode::Implied),
+        inst_to_operation!(mnemonic::CMP, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::CMP, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::EOR, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::CPX, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::CPX, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::CPX, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::CPY, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::CPY, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::CPY, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::DEC, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::DEC, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::DEC, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::DEC, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::DEX, address_mode::Implied),
+        inst_to_operation!(mnemonic::DEY, address_mode::Implied),
+        inst_to_operation!(mnemonic::INC, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::INC, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::INC, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::INC, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::INX, address_mode::Implied),
+        inst_to_operation!(mnemonic::INY, address_mode::Implied),
+        inst_to_operation!(mnemonic::JMP, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::JMP, address_mode::Indirect::default()),
+        inst_to_operation!(mnemonic::JSR, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::LDA, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::LDX, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::LDX, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::LDX, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::LDX, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::LDX, address_mode::ZeroPageIndexedWithY::default()),
+        inst_to_operation!(mnemonic::LDY, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::LDY, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::LDY, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::LDY, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::LDY, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::LSR, address_mode::Accumulator),
+        inst_to_operation!(mnemonic::LSR, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::LSR, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::LSR, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::LSR, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::NOP, address_mode::Implied),
+        inst_to_operation!(mnemonic::ORA, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::ORA, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::PHA, address_mode::Implied),
+        inst_to_operation!(mnemonic::PHP, address_mode::Implied),
+        inst_to_operation!(mnemonic::PLA, address_mode::Implied),
+        inst_to_operation!(mnemonic::PLP, address_mode::Implied),
+        inst_to_operation!(mnemonic::STA, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::STA, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ROL, address_mode::Accumulator),
+        inst_to_operation!(mnemonic::ROL, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::ROL, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ROL, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::ROL, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ROR, address_mode::Accumulator),
+        inst_to_operation!(mnemonic::ROR, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::ROR, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ROR, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::ROR, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::RTI, address_mode::Implied),
+        inst_to_operation!(mnemonic::RTS, address_mode::Implied),
+        inst_to_operation!(mnemonic::SBC, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SBC, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::SEC, address_mode::Implied),
+        inst_to_operation!(mnemonic::SED, address_mode::Implied),
+        inst_to_operation!(mnemonic::SEI, address_mode::Implied),
+        inst_to_operation!(mnemonic::STX, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::STX, address_mode::ZeroPageIndexedWithY::default()),
+        inst_to_operation!(mnemonic::STX, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::STY, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::STY, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::STY, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::TAX, address_mode::Implied),
+        inst_to_operation!(mnemonic::TAY, address_mode::Implied),
+        inst_to_operation!(mnemonic::TSX, address_mode::Implied),
+        inst_to_operation!(mnemonic::TXA, address_mode::Implied),
+        inst_to_operation!(mnemonic::TXS, address_mode::Implied),
+        inst_to_operation!(mnemonic::TYA, address_mode::Implied),
+    ]
+}
+
 /// Instruction takes a mnemonic and address mode as arguments for sizing
 /// and operations.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -388,8 +838,8 @@ where
 
 impl<M, A> Into<Operation> for Instruction<M, A>
 where
-    M: Offset + Copy + Debug + PartialEq + 'static,
-    A: Offset + Copy + Debug + PartialEq + 'static,
+    M: Offset + MemoryReads + Copy + Debug + PartialEq + 'static,
+    A: Offset + EffectiveAddress + Copy + Debug + PartialEq + 'static,
     Self: Generate<MOS6502, MOps> + Cyclable + 'static,
 {
     fn into(self) -> Operation {
@@ -398,6 +848,21 @@ where
             self.cycles(),
             Box::new(move |cpu| self.generate(cpu)),
         )
+        .with_reads(Box::new(move |cpu| {
+            // an instruction completing on its opcode fetch, such as the
+            // single cycle NOPs of the 65C02, reads nothing further.
+            if self.cycles() < 2 {
+                return vec![];
+            }
+
+            let mut reads = self.address_mode.pointer_reads(cpu);
+            reads.extend(self.address_mode.carry_read(cpu, self.mnemonic.writes()));
+            reads.extend(
+                self.mnemonic
+                    .reads(cpu, self.address_mode.effective_address(cpu)),
+            );
+            reads
+        }))
     }
 }
 
@@ -423,204 +888,278 @@ macro_rules! gen_instruction_cycles_and_parser {
             }
         }
     };
-}
-
-// Branching
+    ($mnemonic:ty, $address_mode:ty, [$($opcode:literal),+], $cycles:literal) => {
+        impl Cyclable for Instruction<$mnemonic, $address_mode> {
+            fn cycles(&self) -> usize {
+                $cycles
+            }
+        }
 
-fn branch_on_case(
-    cond: bool,
-    branch_offset: i8,
-    inst_offset: usize,
-    cycles: usize,
-    cpu: &MOS6502,
-) -> MOps {
-    let jmp_on_eq = (Wrapping(cpu.pc.read()) + Wrapping(branch_offset as u16)).0;
-    let mc = if cond {
-        vec![gen_write_16bit_register_microcode!(
-            WordRegisters::PC,
-            // handle for underflow
-            (Wrapping(jmp_on_eq) - Wrapping(inst_offset as u16)).0
-        )]
-    } else {
-        vec![]
+        impl<'a> Parser<'a, &'a [u8], Instruction<$mnemonic, $address_mode>>
+            for Instruction<$mnemonic, $address_mode>
+        {
+            fn parse(
+                &self,
+                input: &'a [u8],
+            ) -> ParseResult<&'a [u8], Instruction<$mnemonic, $address_mode>> {
+                parcel::one_of(vec![$(expect_byte($opcode)),+])
+                    .and_then(|_| <$address_mode>::default())
+                    .map(|am| Instruction::new(<$mnemonic>::default(), am))
+                    .parse(input)
+            }
+        }
     };
+}
 
-    // if the branch is true and that branch crosses a page boundary pay a 1 cycle penalty.
-    let branch_penalty = match (cond, Page::from(cpu.pc.read()).contains(jmp_on_eq)) {
-        (true, false) => 2,
-        (true, true) => 1,
-        _ => 0,
-    };
+mod cmos;
 
-    MOps::new(inst_offset, cycles + branch_penalty, mc)
-}
+#[cfg(feature = "illegal-opcodes")]
+mod undocumented;
 
-// BCC
+// ADC
 
-gen_instruction_cycles_and_parser!(mnemonic::BCC, address_mode::Relative, 0x90, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::Immediate, 0x69, 2);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::BCC, address_mode::Relative> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::Immediate> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let offset = self.address_mode.unwrap();
+        let value = Operand::new(self.address_mode.unwrap());
 
-        branch_on_case(!cpu.ps.carry, offset, self.offset(), self.cycles(), cpu)
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            add_with_carry(cpu, value),
+        )
     }
 }
 
-// BCS
-
-gen_instruction_cycles_and_parser!(mnemonic::BCS, address_mode::Relative, 0xb0, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::ZeroPage, 0x65, 3);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::BCS, address_mode::Relative> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::ZeroPage> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let offset = self.address_mode.unwrap();
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
-        branch_on_case(cpu.ps.carry, offset, self.offset(), self.cycles(), cpu)
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            add_with_carry(cpu, value),
+        )
     }
 }
 
-// BEQ
-
-gen_instruction_cycles_and_parser!(mnemonic::BEQ, address_mode::Relative, 0xf0, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::ZeroPageIndexedWithX, 0x75, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::BEQ, address_mode::Relative> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::ZeroPageIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let offset = self.address_mode.unwrap();
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
-        branch_on_case(cpu.ps.zero, offset, self.offset(), self.cycles(), cpu)
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            add_with_carry(cpu, value),
+        )
     }
 }
 
-// BNE
-
-gen_instruction_cycles_and_parser!(mnemonic::BNE, address_mode::Relative, 0xd0, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::Absolute, 0x6d, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::BNE, address_mode::Relative> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::Absolute> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let offset = self.address_mode.unwrap();
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
-        branch_on_case(!cpu.ps.zero, offset, self.offset(), self.cycles(), cpu)
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            add_with_carry(cpu, value),
+        )
     }
 }
 
-// CLC
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::AbsoluteIndexedWithX, 0x7d, 4);
 
-gen_instruction_cycles_and_parser!(mnemonic::CLC, address_mode::Implied, 0x18, 2);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLC, address_mode::Implied> {
-    fn generate(self, _: &MOS6502) -> MOps {
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![gen_flag_set_microcode!(ProgramStatusFlags::Carry, false)],
+            self.cycles() + decimal_penalty(cpu) + page_penalty,
+            add_with_carry(cpu, value),
         )
     }
 }
 
-// CLD
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::AbsoluteIndexedWithY, 0x79, 4);
 
-gen_instruction_cycles_and_parser!(mnemonic::CLD, address_mode::Implied, 0xd8, 2);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLD, address_mode::Implied> {
-    fn generate(self, _: &MOS6502) -> MOps {
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![gen_flag_set_microcode!(ProgramStatusFlags::Decimal, false)],
+            self.cycles() + decimal_penalty(cpu) + page_penalty,
+            add_with_carry(cpu, value),
         )
     }
 }
 
-// CLI
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::XIndexedIndirect, 0x61, 6);
 
-gen_instruction_cycles_and_parser!(mnemonic::CLI, address_mode::Implied, 0x58, 2);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLI, address_mode::Implied> {
-    fn generate(self, _: &MOS6502) -> MOps {
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![gen_flag_set_microcode!(
-                ProgramStatusFlags::Interrupt,
-                false
-            )],
+            self.cycles() + decimal_penalty(cpu),
+            add_with_carry(cpu, value),
         )
     }
 }
 
-// CLV
+gen_instruction_cycles_and_parser!(mnemonic::ADC, address_mode::IndirectYIndexed, 0x71, 5);
 
-gen_instruction_cycles_and_parser!(mnemonic::CLV, address_mode::Implied, 0xb8, 2);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ADC, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), 0);
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu) + page_penalty,
+            add_with_carry(cpu, value),
+        )
+    }
+}
+
+// AND
+
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::Immediate, 0x29, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(self.address_mode.unwrap());
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLV, address_mode::Implied> {
-    fn generate(self, _: &MOS6502) -> MOps {
         MOps::new(
             self.offset(),
             self.cycles(),
-            vec![gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false)],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
         )
     }
 }
 
-// CMP
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::ZeroPage, 0x25, 3);
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::Immediate, 0xc9, 2);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Immediate> {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::ZeroPageIndexedWithX, 0x35, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::ZeroPageIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let address_mode::Immediate(am_value) = self.address_mode;
-        let rhs = Operand::new(am_value);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::Absolute, 0xcd, 4);
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::Absolute, 0x2d, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Absolute> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::Absolute> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let rhs = dereference_address_to_operand(cpu, self.address_mode.unwrap(), 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::AbsoluteIndexedWithX, 0xdd, 4);
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::AbsoluteIndexedWithX, 0x3d, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::AbsoluteIndexedWithX> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::AbsoluteIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
         let index = cpu.x.read();
         let base_addr = self.address_mode.unwrap();
         let indexed_addr = add_index_to_address(base_addr, index);
-        let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
             1
         } else {
             0
@@ -628,30 +1167,28 @@ impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Absolu
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles() + page_penalty,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::AbsoluteIndexedWithY, 0xd9, 4);
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::AbsoluteIndexedWithY, 0x39, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::AbsoluteIndexedWithY> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::AbsoluteIndexedWithY> {
     fn generate(self, cpu: &MOS6502) -> MOps {
         let index = cpu.y.read();
         let base_addr = self.address_mode.unwrap();
         let indexed_addr = add_index_to_address(base_addr, index);
-        let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
             1
         } else {
             0
@@ -659,30 +1196,49 @@ impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Absolu
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles() + page_penalty,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::IndirectYIndexed, 0xd1, 5);
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::XIndexedIndirect, 0x21, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::IndirectYIndexed> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::AND, address_mode::IndirectYIndexed, 0x31, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::AND, address_mode::IndirectYIndexed> {
     fn generate(self, cpu: &MOS6502) -> MOps {
         let index = cpu.y.read();
-        let base_addr = self.address_mode.unwrap();
-        let indirect_addr = dereference_indirect_indexed_address(cpu, base_addr, index);
-        let rhs = dereference_address_to_operand(cpu, indirect_addr, 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let base_addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), 0);
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() & value.unwrap());
 
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(base_addr as u16).contains(indirect_addr) {
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
             1
         } else {
             0
@@ -690,232 +1246,2020 @@ impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Indire
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// ASL
+
+gen_instruction_cycles_and_parser!(mnemonic::ASL, address_mode::Accumulator, 0x0a, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ASL, address_mode::Accumulator> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.acc.read();
+        let carry = value & 0x80 != 0;
+        let result = Operand::new(value << 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::XIndexedIndirect, 0xc1, 6);
+gen_instruction_cycles_and_parser!(mnemonic::ASL, address_mode::ZeroPage, 0x06, 5);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::XIndexedIndirect> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ASL, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new(value << 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ASL, address_mode::ZeroPageIndexedWithX, 0x16, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ASL, address_mode::ZeroPageIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
         let index = cpu.x.read();
-        let indirect_addr =
-            dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
-        let rhs = dereference_address_to_operand(cpu, indirect_addr, 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new(value << 1);
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::ZeroPage, 0xc5, 3);
+gen_instruction_cycles_and_parser!(mnemonic::ASL, address_mode::Absolute, 0x0e, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::ZeroPage> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ASL, address_mode::Absolute> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let rhs = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new(value << 1);
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ASL, address_mode::AbsoluteIndexedWithX, 0x1e, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ASL, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new(value << 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// Branching
+
+fn branch_on_case(
+    cond: bool,
+    branch_offset: i8,
+    inst_offset: usize,
+    cycles: usize,
+    cpu: &MOS6502,
+) -> MOps {
+    let jmp_on_eq = (Wrapping(cpu.pc.read()) + Wrapping(branch_offset as u16)).0;
+    let mc = if cond {
+        vec![gen_write_16bit_register_microcode!(
+            WordRegisters::PC,
+            // handle for underflow
+            (Wrapping(jmp_on_eq) - Wrapping(inst_offset as u16)).0
+        )]
+    } else {
+        vec![]
+    };
+
+    // if the branch is true and that branch crosses a page boundary pay a 1 cycle penalty.
+    let branch_penalty = match (cond, Page::from(cpu.pc.read()).contains(jmp_on_eq)) {
+        (true, false) => 2,
+        (true, true) => 1,
+        _ => 0,
+    };
+
+    MOps::new(inst_offset, cycles + branch_penalty, mc)
+}
+
+// BCC
+
+gen_instruction_cycles_and_parser!(mnemonic::BCC, address_mode::Relative, 0x90, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BCC, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(!cpu.ps.carry, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BCS
+
+gen_instruction_cycles_and_parser!(mnemonic::BCS, address_mode::Relative, 0xb0, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BCS, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(cpu.ps.carry, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BEQ
+
+gen_instruction_cycles_and_parser!(mnemonic::BEQ, address_mode::Relative, 0xf0, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BEQ, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(cpu.ps.zero, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BIT
+
+gen_instruction_cycles_and_parser!(mnemonic::BIT, address_mode::ZeroPage, 0x24, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BIT, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let value = value.unwrap();
+        let result = Operand::new(cpu.acc.read() & value);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value & 0x80 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, value & 0x40 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::BIT, address_mode::Absolute, 0x2c, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BIT, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let value = value.unwrap();
+        let result = Operand::new(cpu.acc.read() & value);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value & 0x80 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, value & 0x40 != 0),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+            ],
+        )
+    }
+}
+
+// BMI
+
+gen_instruction_cycles_and_parser!(mnemonic::BMI, address_mode::Relative, 0x30, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BMI, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(cpu.ps.negative, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BNE
+
+gen_instruction_cycles_and_parser!(mnemonic::BNE, address_mode::Relative, 0xd0, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BNE, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(!cpu.ps.zero, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BPL
+
+gen_instruction_cycles_and_parser!(mnemonic::BPL, address_mode::Relative, 0x10, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BPL, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(!cpu.ps.negative, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BRK
+
+gen_instruction_cycles_and_parser!(mnemonic::BRK, address_mode::Implied, 0x00, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BRK, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let handler = dereference_vector(cpu, cpu.vectors.irq);
+
+        // brk is followed by a padding byte which is skipped on return.
+        let return_addr = cpu.pc.read().overflowing_add(2).0;
+
+        push_interrupt_frame(
+            cpu,
+            self.offset(),
+            return_addr,
+            true,
+            handler.overflowing_sub(self.offset() as u16).0,
+        )
+    }
+}
+
+// BVC
+
+gen_instruction_cycles_and_parser!(mnemonic::BVC, address_mode::Relative, 0x50, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BVC, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(!cpu.ps.overflow, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// BVS
+
+gen_instruction_cycles_and_parser!(mnemonic::BVS, address_mode::Relative, 0x70, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::BVS, address_mode::Relative> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let offset = self.address_mode.unwrap();
+
+        branch_on_case(cpu.ps.overflow, offset, self.offset(), self.cycles(), cpu)
+    }
+}
+
+// CLC
+
+gen_instruction_cycles_and_parser!(mnemonic::CLC, address_mode::Implied, 0x18, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLC, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_flag_set_microcode!(ProgramStatusFlags::Carry, false)],
+        )
+    }
+}
+
+// CLD
+
+gen_instruction_cycles_and_parser!(mnemonic::CLD, address_mode::Implied, 0xd8, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLD, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_flag_set_microcode!(ProgramStatusFlags::Decimal, false)],
+        )
+    }
+}
+
+// CLI
+
+gen_instruction_cycles_and_parser!(mnemonic::CLI, address_mode::Implied, 0x58, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLI, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_flag_set_microcode!(
+                ProgramStatusFlags::Interrupt,
+                false
+            )],
+        )
+    }
+}
+
+// CLV
+
+gen_instruction_cycles_and_parser!(mnemonic::CLV, address_mode::Implied, 0xb8, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CLV, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false)],
+        )
+    }
+}
+
+// CMP
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::Immediate, 0xc9, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let address_mode::Immediate(am_value) = self.address_mode;
+        let rhs = Operand::new(am_value);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::Absolute, 0xcd, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let rhs = dereference_address_to_operand(cpu, self.address_mode.unwrap(), 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::AbsoluteIndexedWithX, 0xdd, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::AbsoluteIndexedWithY, 0xd9, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::IndirectYIndexed, 0xd1, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indirect_addr = dereference_indirect_indexed_address(cpu, base_addr, index);
+        let rhs = dereference_address_to_operand(cpu, indirect_addr, 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(base_addr as u16).contains(indirect_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::XIndexedIndirect, 0xc1, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let indirect_addr =
+            dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let rhs = dereference_address_to_operand(cpu, indirect_addr, 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::ZeroPage, 0xc5, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let rhs = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::ZeroPageIndexedWithX, 0xd5, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let base_addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let rhs = dereference_address_to_operand(cpu, base_addr, 0);
+        let lhs = Operand::new(cpu.acc.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+// EOR
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::Immediate, 0x49, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(self.address_mode.unwrap());
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::ZeroPage, 0x45, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::ZeroPageIndexedWithX, 0x55, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::Absolute, 0x4d, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::AbsoluteIndexedWithX, 0x5d, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::AbsoluteIndexedWithY, 0x59, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::XIndexedIndirect, 0x41, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::EOR, address_mode::IndirectYIndexed, 0x51, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::EOR, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), 0);
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() ^ value.unwrap());
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// CPX
+
+gen_instruction_cycles_and_parser!(mnemonic::CPX, address_mode::Immediate, 0xe0, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CPX, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let rhs = Operand::new(self.address_mode.unwrap());
+        let lhs = Operand::new(cpu.x.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CPX, address_mode::ZeroPage, 0xe4, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CPX, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let rhs = dereference_address_to_operand(cpu, addr, 0);
+        let lhs = Operand::new(cpu.x.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CPX, address_mode::Absolute, 0xec, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CPX, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let rhs = dereference_address_to_operand(cpu, addr, 0);
+        let lhs = Operand::new(cpu.x.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+// CPY
+
+gen_instruction_cycles_and_parser!(mnemonic::CPY, address_mode::Immediate, 0xc0, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CPY, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let rhs = Operand::new(self.address_mode.unwrap());
+        let lhs = Operand::new(cpu.y.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CPY, address_mode::ZeroPage, 0xc4, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CPY, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let rhs = dereference_address_to_operand(cpu, addr, 0);
+        let lhs = Operand::new(cpu.y.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::CPY, address_mode::Absolute, 0xcc, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::CPY, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let rhs = dereference_address_to_operand(cpu, addr, 0);
+        let lhs = Operand::new(cpu.y.read());
+        let carry = lhs >= rhs;
+        let diff = lhs - rhs;
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+    }
+}
+
+// DEC
+
+gen_instruction_cycles_and_parser!(mnemonic::DEC, address_mode::ZeroPage, 0xc6, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEC, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DEC, address_mode::ZeroPageIndexedWithX, 0xd6, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEC, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DEC, address_mode::Absolute, 0xce, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEC, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DEC, address_mode::AbsoluteIndexedWithX, 0xde, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEC, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+// DEX
+
+gen_instruction_cycles_and_parser!(mnemonic::DEX, address_mode::Implied, 0xca, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEX, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(cpu.x.read()) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// DEY
+
+gen_instruction_cycles_and_parser!(mnemonic::DEY, address_mode::Implied, 0x88, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DEY, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(cpu.y.read()) - Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// INC
+
+gen_instruction_cycles_and_parser!(mnemonic::INC, address_mode::Absolute, 0xee, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INC, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let address_mode::Absolute(addr) = self.address_mode;
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::INC, address_mode::ZeroPage, 0xe6, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INC, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::INC, address_mode::ZeroPageIndexedWithX, 0xf6, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INC, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::INC, address_mode::AbsoluteIndexedWithX, 0xfe, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INC, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let original = cpu.address_map.read(addr);
+        let value = Operand::new(original) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_memory_microcode!(addr, value.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, original)
+    }
+}
+
+// INX
+
+gen_instruction_cycles_and_parser!(mnemonic::INX, address_mode::Implied, 0xe8, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INX, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(cpu.x.read()) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// INY
+
+gen_instruction_cycles_and_parser!(mnemonic::INY, address_mode::Implied, 0xc8, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::INY, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(cpu.y.read()) + Operand::new(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// JMP
+
+gen_instruction_cycles_and_parser!(mnemonic::JMP, address_mode::Absolute, 0x4c, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::JMP, address_mode::Absolute> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        let address_mode::Absolute(addr) = self.address_mode;
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::PC,
+                addr - self.offset() as u16
+            )],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::JMP, address_mode::Indirect, 0x6c, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::JMP, address_mode::Indirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let address_mode::Indirect(indirect_addr) = self.address_mode;
+
+        // the NMOS 6502 doesn't carry into the high byte when fetching the
+        // msb, wrapping to the start of the page. The 65C02 fixes this at the
+        // cost of an additional cycle.
+        let (msb_addr, cycles) = match cpu.variant {
+            Variant::MOS6502 | Variant::Ricoh2A03 => (
+                (indirect_addr & 0xff00) | (indirect_addr.wrapping_add(1) & 0x00ff),
+                self.cycles(),
+            ),
+            Variant::W65C02 => (indirect_addr.wrapping_add(1), self.cycles() + 1),
+        };
+        let lsb = cpu.address_map.read(indirect_addr);
+        let msb = cpu.address_map.read(msb_addr);
+        let addr = u16::from_le_bytes([lsb, msb]);
+        MOps::new(
+            self.offset(),
+            cycles,
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::PC,
+                addr - self.offset() as u16
+            )],
+        )
+    }
+}
+
+// JSR
+
+gen_instruction_cycles_and_parser!(mnemonic::JSR, address_mode::Absolute, 0x20, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::JSR, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let address_mode::Absolute(addr) = self.address_mode;
+        let sp = cpu.sp.read();
+
+        // the return address points to the last byte of the jsr instruction.
+        let [lsb, msb] = cpu.pc.read().overflowing_add(2).0.to_le_bytes();
+
+        // the return address is pushed on cycles 4 and 5, after the
+        // low byte of the target has been fetched.
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 2),
+                gen_write_16bit_register_microcode!(
+                    WordRegisters::PC,
+                    addr.overflowing_sub(self.offset() as u16).0
+                ),
+            ],
+        )
+        .with_microcode_on_cycle(
+            4,
+            vec![gen_write_memory_microcode!(
+                stack_pointer_from_byte_value(sp),
+                msb
+            )],
+        )
+        .with_microcode_on_cycle(
+            5,
+            vec![gen_write_memory_microcode!(
+                stack_pointer_from_byte_value(sp.overflowing_sub(1).0),
+                lsb
+            )],
+        )
+    }
+}
+
+// LDA
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::Immediate, 0xa9, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::Immediate> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        let value = Operand::new(self.address_mode.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::ZeroPage, 0xa5, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::ZeroPageIndexedWithX, 0xb5, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr as u16, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::Absolute, 0xad, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let address_mode::Absolute(addr) = self.address_mode;
+        let value = Operand::new(cpu.address_map.read(addr));
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::AbsoluteIndexedWithX, 0xbd, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(addr, index);
+        let value = dereference_address_to_operand(cpu, addr, index);
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::AbsoluteIndexedWithY, 0xb9, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::IndirectYIndexed, 0xb1, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let zpage_base_addr = self.address_mode.unwrap();
+        let indirect_addr =
+            dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
+        let value = Operand::new(cpu.address_map.read(indirect_addr));
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::XIndexedIndirect, 0xa1, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let indirect_addr =
+            dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), cpu.x.read());
+        let value = Operand::new(cpu.address_map.read(indirect_addr));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// LDX
+
+gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::Absolute, 0xae, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::AbsoluteIndexedWithY, 0xbe, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::Immediate, 0xa2, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::Immediate> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        let value = Operand::new(self.address_mode.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::ZeroPage, 0xa6, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::ZeroPageIndexedWithY, 0xb6, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::ZeroPageIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr as u16, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// LDY
+
+gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::Absolute, 0xac, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::AbsoluteIndexedWithX, 0xbc, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the branch crosses a page boundary pay a 1 cycle penalty.
+        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + branch_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::Immediate, 0xa0, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::Immediate> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        let value = Operand::new(self.address_mode.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::ZeroPage, 0xa4, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::ZeroPageIndexedWithX, 0xb4, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr as u16, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// LSR
+
+gen_instruction_cycles_and_parser!(mnemonic::LSR, address_mode::Accumulator, 0x4a, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LSR, address_mode::Accumulator> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.acc.read();
+        let carry = value & 0x01 != 0;
+        let result = Operand::new(value >> 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LSR, address_mode::ZeroPage, 0x46, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LSR, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new(value >> 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LSR, address_mode::ZeroPageIndexedWithX, 0x56, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LSR, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new(value >> 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LSR, address_mode::Absolute, 0x4e, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LSR, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new(value >> 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LSR, address_mode::AbsoluteIndexedWithX, 0x5e, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LSR, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new(value >> 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// NOP
+
+gen_instruction_cycles_and_parser!(mnemonic::NOP, address_mode::Implied, 0xea, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+// ORA
+
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::Immediate, 0x09, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = Operand::new(self.address_mode.unwrap());
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::ZeroPage, 0x05, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::CMP, address_mode::ZeroPageIndexedWithX, 0xd5, 4);
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::ZeroPageIndexedWithX, 0x15, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::CMP, address_mode::ZeroPageIndexedWithX> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::ZeroPageIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
         let index = cpu.x.read();
-        let base_addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
-        let rhs = dereference_address_to_operand(cpu, base_addr, 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let carry = lhs >= rhs;
-        let diff = lhs - rhs;
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-// INC
-
-gen_instruction_cycles_and_parser!(mnemonic::INC, address_mode::Absolute, 0xee, 6);
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::Absolute, 0x0d, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::INC, address_mode::Absolute> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::Absolute> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let address_mode::Absolute(addr) = self.address_mode;
-        let value = Operand::new(cpu.address_map.read(addr)) + Operand::new(1);
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_memory_microcode!(addr, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-// INX
-
-gen_instruction_cycles_and_parser!(mnemonic::INX, address_mode::Implied, 0xe8, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::AbsoluteIndexedWithX, 0x1d, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::INX, address_mode::Implied> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::AbsoluteIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = Operand::new(cpu.x.read()) + Operand::new(1);
+        let index = cpu.x.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
         MOps::new(
             self.offset(),
-            self.cycles(),
+            self.cycles() + page_penalty,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-// INY
-
-gen_instruction_cycles_and_parser!(mnemonic::INY, address_mode::Implied, 0xc8, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::AbsoluteIndexedWithY, 0x19, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::INY, address_mode::Implied> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::AbsoluteIndexedWithY> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = Operand::new(cpu.y.read()) + Operand::new(1);
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
         MOps::new(
             self.offset(),
-            self.cycles(),
+            self.cycles() + page_penalty,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-// JMP
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::XIndexedIndirect, 0x01, 6);
 
-gen_instruction_cycles_and_parser!(mnemonic::JMP, address_mode::Absolute, 0x4c, 3);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::JMP, address_mode::Absolute> {
-    fn generate(self, _: &MOS6502) -> MOps {
-        let address_mode::Absolute(addr) = self.address_mode;
         MOps::new(
             self.offset(),
             self.cycles(),
-            vec![gen_write_16bit_register_microcode!(
-                WordRegisters::PC,
-                addr - self.offset() as u16
-            )],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::JMP, address_mode::Indirect, 0x6c, 5);
+gen_instruction_cycles_and_parser!(mnemonic::ORA, address_mode::IndirectYIndexed, 0x11, 5);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::JMP, address_mode::Indirect> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ORA, address_mode::IndirectYIndexed> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let address_mode::Indirect(indirect_addr) = self.address_mode;
-        let lsb = cpu.address_map.read(indirect_addr);
-        let msb = cpu.address_map.read(indirect_addr + 1);
-        let addr = u16::from_le_bytes([lsb, msb]);
+        let index = cpu.y.read();
+        let base_addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), 0);
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let result = Operand::new(cpu.acc.read() | value.unwrap());
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![gen_write_16bit_register_microcode!(
-                WordRegisters::PC,
-                addr - self.offset() as u16
-            )],
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
         )
     }
 }
 
-// LDA
+// PHA
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::Immediate, 0xa9, 2);
+gen_instruction_cycles_and_parser!(mnemonic::PHA, address_mode::Implied, 0x48, 3);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::Immediate> {
-    fn generate(self, _: &MOS6502) -> MOps {
-        let value = Operand::new(self.address_mode.unwrap());
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PHA, address_mode::Implied> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.acc.read();
+        let sp = cpu.sp.read();
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_memory_microcode!(stack_pointer_from_byte_value(sp), value),
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::ZeroPage, 0xa5, 3);
+// PHP
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::ZeroPage> {
+gen_instruction_cycles_and_parser!(mnemonic::PHP, address_mode::Implied, 0x08, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PHP, address_mode::Implied> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+        let value = cpu.ps.read();
+        let sp = cpu.sp.read();
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_memory_microcode!(stack_pointer_from_byte_value(sp), value),
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::ZeroPageIndexedWithX, 0xb5, 4);
+// PLA
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::ZeroPageIndexedWithX> {
+gen_instruction_cycles_and_parser!(mnemonic::PLA, address_mode::Implied, 0x68, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PLA, address_mode::Implied> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let index = cpu.x.read();
-        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
-        let value = dereference_address_to_operand(cpu, addr as u16, 0);
+        let sp = cpu.sp.read().overflowing_add(1).0;
+        let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
                 gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
@@ -924,426 +3268,471 @@ impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::ZeroPa
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::Absolute, 0xad, 4);
+// PLP
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::Absolute> {
+gen_instruction_cycles_and_parser!(mnemonic::PLP, address_mode::Implied, 0x28, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::PLP, address_mode::Implied> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let address_mode::Absolute(addr) = self.address_mode;
-        let value = Operand::new(cpu.address_map.read(addr));
+        let sp = cpu.sp.read().overflowing_add(1).0;
+        let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
+
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_write_8bit_register_microcode!(ByteRegisters::PS, value.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::AbsoluteIndexedWithX, 0xbd, 4);
+// ROL
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::AbsoluteIndexedWithX> {
-    fn generate(self, cpu: &MOS6502) -> MOps {
-        let index = cpu.x.read();
-        let addr = self.address_mode.unwrap();
-        let indexed_addr = add_index_to_address(addr, index);
-        let value = dereference_address_to_operand(cpu, addr, index);
+gen_instruction_cycles_and_parser!(mnemonic::ROL, address_mode::Accumulator, 0x2a, 2);
 
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROL, address_mode::Accumulator> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.acc.read();
+        let carry = value & 0x80 != 0;
+        let result = Operand::new((value << 1) | cpu.ps.carry as u8);
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::AbsoluteIndexedWithY, 0xb9, 4);
+gen_instruction_cycles_and_parser!(mnemonic::ROL, address_mode::ZeroPage, 0x26, 5);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::AbsoluteIndexedWithY> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROL, address_mode::ZeroPage> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let index = cpu.y.read();
-        let addr = self.address_mode.unwrap();
-        let indexed_addr = add_index_to_address(addr, index);
-        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
-
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new((value << 1) | cpu.ps.carry as u8);
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::IndirectYIndexed, 0xb1, 5);
+gen_instruction_cycles_and_parser!(mnemonic::ROL, address_mode::ZeroPageIndexedWithX, 0x36, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::IndirectYIndexed> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROL, address_mode::ZeroPageIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let zpage_base_addr = self.address_mode.unwrap();
-        let indirect_addr =
-            dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
-        let value = Operand::new(cpu.address_map.read(indirect_addr));
-
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new((value << 1) | cpu.ps.carry as u8);
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDA, address_mode::XIndexedIndirect, 0xa1, 6);
+gen_instruction_cycles_and_parser!(mnemonic::ROL, address_mode::Absolute, 0x2e, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDA, address_mode::XIndexedIndirect> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROL, address_mode::Absolute> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let indirect_addr =
-            dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), cpu.x.read());
-        let value = Operand::new(cpu.address_map.read(indirect_addr));
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new((value << 1) | cpu.ps.carry as u8);
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-// LDX
-
-gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::Absolute, 0xae, 4);
+gen_instruction_cycles_and_parser!(mnemonic::ROL, address_mode::AbsoluteIndexedWithX, 0x3e, 7);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::Absolute> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROL, address_mode::AbsoluteIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let addr = self.address_mode.unwrap();
-        let value = dereference_address_to_operand(cpu, addr, 0);
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let result = Operand::new((value << 1) | cpu.ps.carry as u8);
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::AbsoluteIndexedWithY, 0xbe, 4);
+// ROR
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::AbsoluteIndexedWithY> {
+gen_instruction_cycles_and_parser!(mnemonic::ROR, address_mode::Accumulator, 0x6a, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROR, address_mode::Accumulator> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let index = cpu.y.read();
-        let addr = self.address_mode.unwrap();
-        let indexed_addr = add_index_to_address(addr, index);
-        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let value = cpu.acc.read();
+        let carry = value & 0x01 != 0;
+        let result = Operand::new((value >> 1) | ((cpu.ps.carry as u8) << 7));
 
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ROR, address_mode::ZeroPage, 0x66, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROR, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new((value >> 1) | ((cpu.ps.carry as u8) << 7));
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::Immediate, 0xa2, 2);
+gen_instruction_cycles_and_parser!(mnemonic::ROR, address_mode::ZeroPageIndexedWithX, 0x76, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::Immediate> {
-    fn generate(self, _: &MOS6502) -> MOps {
-        let value = Operand::new(self.address_mode.unwrap());
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROR, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new((value >> 1) | ((cpu.ps.carry as u8) << 7));
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::ZeroPage, 0xa6, 3);
+gen_instruction_cycles_and_parser!(mnemonic::ROR, address_mode::Absolute, 0x6e, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::ZeroPage> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROR, address_mode::Absolute> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new((value >> 1) | ((cpu.ps.carry as u8) << 7));
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDX, address_mode::ZeroPageIndexedWithY, 0xb6, 4);
+gen_instruction_cycles_and_parser!(mnemonic::ROR, address_mode::AbsoluteIndexedWithX, 0x7e, 7);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDX, address_mode::ZeroPageIndexedWithY> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ROR, address_mode::AbsoluteIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let index = cpu.y.read();
-        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
-        let value = dereference_address_to_operand(cpu, addr as u16, 0);
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let result = Operand::new((value >> 1) | ((cpu.ps.carry as u8) << 7));
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_memory_microcode!(addr, result.unwrap()),
             ],
         )
+        .with_dummy_write(cpu, addr, value)
     }
 }
 
-// LDY
+// RTI
 
-gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::Absolute, 0xac, 4);
+gen_instruction_cycles_and_parser!(mnemonic::RTI, address_mode::Implied, 0x40, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::Absolute> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RTI, address_mode::Implied> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let addr = self.address_mode.unwrap();
-        let value = dereference_address_to_operand(cpu, addr, 0);
+        let sp = cpu.sp.read();
+        let status = cpu
+            .address_map
+            .read(stack_pointer_from_byte_value(sp.overflowing_add(1).0));
+        let lsb = cpu
+            .address_map
+            .read(stack_pointer_from_byte_value(sp.overflowing_add(2).0));
+        let msb = cpu
+            .address_map
+            .read(stack_pointer_from_byte_value(sp.overflowing_add(3).0));
+        let return_addr = u16::from_le_bytes([lsb, msb]);
+
+        // the break and unused bits only exist on the stack and are left as is.
+        let status = (status & 0xcf) | (cpu.ps.read() & 0x30);
 
         MOps::new(
             self.offset(),
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 3),
+                gen_write_8bit_register_microcode!(ByteRegisters::PS, status),
+                gen_write_16bit_register_microcode!(
+                    WordRegisters::PC,
+                    return_addr.overflowing_sub(self.offset() as u16).0
+                ),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::AbsoluteIndexedWithX, 0xbc, 4);
+// RTS
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::AbsoluteIndexedWithX> {
+gen_instruction_cycles_and_parser!(mnemonic::RTS, address_mode::Implied, 0x60, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RTS, address_mode::Implied> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let index = cpu.x.read();
-        let addr = self.address_mode.unwrap();
-        let indexed_addr = add_index_to_address(addr, index);
-        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+        let sp = cpu.sp.read();
+        let lsb = cpu
+            .address_map
+            .read(stack_pointer_from_byte_value(sp.overflowing_add(1).0));
+        let msb = cpu
+            .address_map
+            .read(stack_pointer_from_byte_value(sp.overflowing_add(2).0));
 
-        // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        // the pulled address points to the last byte of the jsr instruction,
+        // with the instruction offset incrementing it to the next instruction.
+        let return_addr = u16::from_le_bytes([lsb, msb]);
 
         MOps::new(
             self.offset(),
-            self.cycles() + branch_penalty,
+            self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 2),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, return_addr),
             ],
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::Immediate, 0xa0, 2);
+// SBC
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::Immediate> {
-    fn generate(self, _: &MOS6502) -> MOps {
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::Immediate, 0xe9, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
         let value = Operand::new(self.address_mode.unwrap());
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
-            ],
+            self.cycles() + decimal_penalty(cpu),
+            subtract_with_borrow(cpu, value),
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::ZeroPage, 0xa4, 3);
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::ZeroPage, 0xe5, 3);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::ZeroPage> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::ZeroPage> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = dereference_address_to_operand(cpu, self.address_mode.unwrap() as u16, 0);
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
-            ],
+            self.cycles() + decimal_penalty(cpu),
+            subtract_with_borrow(cpu, value),
         )
     }
 }
 
-gen_instruction_cycles_and_parser!(mnemonic::LDY, address_mode::ZeroPageIndexedWithX, 0xb4, 4);
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::ZeroPageIndexedWithX, 0xf5, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::LDY, address_mode::ZeroPageIndexedWithX> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::ZeroPageIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
         let index = cpu.x.read();
         let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
-        let value = dereference_address_to_operand(cpu, addr as u16, 0);
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, value.unwrap()),
-            ],
+            self.cycles() + decimal_penalty(cpu),
+            subtract_with_borrow(cpu, value),
         )
     }
 }
 
-// NOP
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::Absolute, 0xed, 4);
 
-gen_instruction_cycles_and_parser!(mnemonic::NOP, address_mode::Implied, 0xea, 2);
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::Implied> {
-    fn generate(self, _: &MOS6502) -> MOps {
-        MOps::new(self.offset(), self.cycles(), vec![])
+        MOps::new(
+            self.offset(),
+            self.cycles() + decimal_penalty(cpu),
+            subtract_with_borrow(cpu, value),
+        )
     }
 }
 
-// PHA
-
-gen_instruction_cycles_and_parser!(mnemonic::PHA, address_mode::Implied, 0x48, 3);
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::AbsoluteIndexedWithX, 0xfd, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::PHA, address_mode::Implied> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::AbsoluteIndexedWithX> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = cpu.acc.read();
-        let sp = cpu.sp.read();
+        let index = cpu.x.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_write_memory_microcode!(stack_pointer_from_byte_value(sp), value),
-                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
-            ],
+            self.cycles() + decimal_penalty(cpu) + page_penalty,
+            subtract_with_borrow(cpu, value),
         )
     }
 }
 
-// PHP
-
-gen_instruction_cycles_and_parser!(mnemonic::PHP, address_mode::Implied, 0x08, 3);
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::AbsoluteIndexedWithY, 0xf9, 4);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::PHP, address_mode::Implied> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::AbsoluteIndexedWithY> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let value = cpu.ps.read();
-        let sp = cpu.sp.read();
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_write_memory_microcode!(stack_pointer_from_byte_value(sp), value),
-                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
-            ],
+            self.cycles() + decimal_penalty(cpu) + page_penalty,
+            subtract_with_borrow(cpu, value),
         )
     }
 }
 
-// PLA
-
-gen_instruction_cycles_and_parser!(mnemonic::PLA, address_mode::Implied, 0x68, 4);
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::XIndexedIndirect, 0xe1, 6);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::PLA, address_mode::Implied> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::XIndexedIndirect> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let sp = cpu.sp.read().overflowing_add(1).0;
-        let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
-            ],
+            self.cycles() + decimal_penalty(cpu),
+            subtract_with_borrow(cpu, value),
         )
     }
 }
 
-// PLP
-
-gen_instruction_cycles_and_parser!(mnemonic::PLP, address_mode::Implied, 0x28, 4);
+gen_instruction_cycles_and_parser!(mnemonic::SBC, address_mode::IndirectYIndexed, 0xf1, 5);
 
-impl Generate<MOS6502, MOps> for Instruction<mnemonic::PLP, address_mode::Implied> {
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBC, address_mode::IndirectYIndexed> {
     fn generate(self, cpu: &MOS6502) -> MOps {
-        let sp = cpu.sp.read().overflowing_add(1).0;
-        let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
+        let index = cpu.y.read();
+        let base_addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), 0);
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
 
         MOps::new(
             self.offset(),
-            self.cycles(),
-            vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
-                gen_write_8bit_register_microcode!(ByteRegisters::PS, value.unwrap()),
-            ],
+            self.cycles() + decimal_penalty(cpu) + page_penalty,
+            subtract_with_borrow(cpu, value),
         )
     }
 }
@@ -1498,6 +3887,102 @@ impl Generate<MOS6502, MOps> for Instruction<mnemonic::STA, address_mode::ZeroPa
     }
 }
 
+// STX
+
+gen_instruction_cycles_and_parser!(mnemonic::STX, address_mode::ZeroPage, 0x86, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STX, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STX, address_mode::ZeroPageIndexedWithY, 0x96, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STX, address_mode::ZeroPageIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STX, address_mode::Absolute, 0x8e, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STX, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+// STY
+
+gen_instruction_cycles_and_parser!(mnemonic::STY, address_mode::ZeroPage, 0x84, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STY, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.y.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STY, address_mode::ZeroPageIndexedWithX, 0x94, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STY, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.y.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::STY, address_mode::Absolute, 0x8c, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::STY, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.y.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
 // TAX
 
 gen_instruction_cycles_and_parser!(mnemonic::TAX, address_mode::Implied, 0xaa, 2);
@@ -1,7 +1,10 @@
-use crate::address_map::Addressable;
+use crate::address_map::{
+    memory::{Memory, ReadWrite},
+    Addressable,
+};
 use crate::cpu::mos6502::{
     microcode::*,
-    operations::{address_mode, mnemonic, Instruction, MOps, Operation},
+    operations::{address_mode, mnemonic, Instruction, Interrupt, MOps, Operation},
     register::{
         ByteRegisters, GPRegister, GeneralPurpose, ProcessorStatus, ProgramCounter,
         ProgramStatusFlags, StackPointer, WordRegisters,
@@ -10,284 +13,1256 @@ use crate::cpu::mos6502::{
 };
 use crate::cpu::register::Register;
 
-// BCC
+// ADC
 
 #[test]
-fn should_generate_bcc_machine_code_with_branch_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.carry = false;
+fn should_generate_immediate_address_mode_adc_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50));
 
-    let op: Operation = Instruction::new(mnemonic::BCC, address_mode::Relative(8)).into();
+    let op: Operation = Instruction::new(mnemonic::ADC, address_mode::Immediate(0x50)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() + 8 - 2;
-
     assert_eq!(
         MOps::new(
             2,
-            3,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_bcc_machine_code_with_branch_and_page_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.carry = false;
+fn should_generate_zeropage_address_mode_adc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50));
+    cpu.address_map.write(0x0a, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BCC, address_mode::Relative(-8)).into();
+    let op: Operation = Instruction::new(mnemonic::ADC, address_mode::ZeroPage(0x0a)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() - 8 - 2;
-
     assert_eq!(
         MOps::new(
             2,
-            4,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_bcc_machine_code_with_no_jump() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.carry = true;
-
-    let op: Operation = Instruction::new(mnemonic::BCC, address_mode::Relative(-8)).into();
-    let mc = op.generate(&cpu);
-
-    assert_eq!(MOps::new(2, 2, vec![]), mc);
-}
-
-// BCS
-
-#[test]
-fn should_generate_bcs_machine_code_with_branch_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.carry = true;
+fn should_generate_zeropage_indexed_with_x_address_mode_adc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BCS, address_mode::Relative(8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() + 8 - 2;
-
     assert_eq!(
         MOps::new(
             2,
-            3,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_bcs_machine_code_with_branch_and_page_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.carry = true;
+fn should_generate_absolute_address_mode_adc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BCS, address_mode::Relative(-8)).into();
+    let op: Operation = Instruction::new(mnemonic::ADC, address_mode::Absolute(0x0105)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() - 8 - 2;
-
     assert_eq!(
         MOps::new(
-            2,
+            3,
             4,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_bcs_machine_code_with_no_jump() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.carry = false;
+fn should_generate_absolute_indexed_with_x_address_mode_adc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BCS, address_mode::Relative(-8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::AbsoluteIndexedWithX(0x0100)).into();
     let mc = op.generate(&cpu);
 
-    assert_eq!(MOps::new(2, 2, vec![]), mc);
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
+        ),
+        mc
+    );
 }
 
-// BEQ
-
 #[test]
-fn should_generate_beq_machine_code_with_branch_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.zero = true;
+fn should_generate_absolute_indexed_with_y_address_mode_adc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BEQ, address_mode::Relative(8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::AbsoluteIndexedWithY(0x0100)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() + 8 - 2;
-
     assert_eq!(
         MOps::new(
-            2,
             3,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_beq_machine_code_with_branch_and_page_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.zero = true;
+fn should_generate_x_indexed_indirect_address_mode_adc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BEQ, address_mode::Relative(-8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::XIndexedIndirect(0x05)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() - 8 - 2;
-
     assert_eq!(
         MOps::new(
             2,
-            4,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_beq_machine_code_with_no_jump() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.zero = false;
+fn should_generate_indirect_y_indexed_address_mode_adc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BEQ, address_mode::Relative(-8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::IndirectYIndexed(0x0a)).into();
     let mc = op.generate(&cpu);
 
-    assert_eq!(MOps::new(2, 2, vec![]), mc);
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
+        ),
+        mc
+    );
 }
 
-// BNE
-
 #[test]
-fn should_generate_bne_machine_code_with_branch_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.zero = false;
+fn should_generate_absolute_indexed_with_x_address_mode_adc_machine_code_with_page_penalty() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x07));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BNE, address_mode::Relative(8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::AbsoluteIndexedWithX(0x00fe)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() + 8 - 2;
-
     assert_eq!(
         MOps::new(
-            2,
             3,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_bne_machine_code_with_branch_and_page_penalty() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.zero = false;
+fn should_generate_indirect_y_indexed_address_mode_adc_machine_code_with_page_penalty() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x07));
+    cpu.address_map.write(0x0a, 0xfe).unwrap();
+    cpu.address_map.write(0x0b, 0x00).unwrap();
+    cpu.address_map.write(0x0105, 0x50).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::BNE, address_mode::Relative(-8)).into();
+    let op: Operation =
+        Instruction::new(mnemonic::ADC, address_mode::IndirectYIndexed(0x0a)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address - inst size
-    let pc = cpu.pc.read() - 8 - 2;
-
     assert_eq!(
         MOps::new(
             2,
-            4,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
+            ]
         ),
         mc
     );
 }
 
 #[test]
-fn should_generate_bne_machine_code_with_no_jump() {
-    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
-    cpu.ps.zero = true;
+fn should_generate_immediate_address_mode_adc_machine_code_in_decimal_mode() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x58));
+    cpu.ps.decimal = true;
+    cpu.ps.carry = true;
 
-    let op: Operation = Instruction::new(mnemonic::BNE, address_mode::Relative(-8)).into();
+    let op: Operation = Instruction::new(mnemonic::ADC, address_mode::Immediate(0x46)).into();
     let mc = op.generate(&cpu);
 
-    assert_eq!(MOps::new(2, 2, vec![]), mc);
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x05),
+            ]
+        ),
+        mc
+    );
 }
 
-// CLC
+// AND
 
 #[test]
-fn should_generate_implied_address_mode_clc_machine_code() {
-    let mut ps = ProcessorStatus::new();
-    ps.carry = true;
-    let cpu = MOS6502::default().with_ps_register(ps);
-    let op: Operation = Instruction::new(mnemonic::CLC, address_mode::Implied).into();
+fn should_generate_immediate_address_mode_and_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0));
+
+    let op: Operation = Instruction::new(mnemonic::AND, address_mode::Immediate(0x8f)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            1,
             2,
-            vec![gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),]
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
         ),
         mc
     );
 }
 
-// CLD
-
 #[test]
-fn should_generate_implied_address_mode_cld_machine_code() {
-    let mut ps = ProcessorStatus::new();
-    ps.carry = true;
-    let cpu = MOS6502::default().with_ps_register(ps);
-    let op: Operation = Instruction::new(mnemonic::CLD, address_mode::Implied).into();
+fn should_generate_zeropage_address_mode_and_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0));
+    cpu.address_map.write(0x0a, 0x8f).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::AND, address_mode::ZeroPage(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            1,
             2,
-            vec![gen_flag_set_microcode!(ProgramStatusFlags::Decimal, false),]
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
         ),
         mc
     );
 }
 
-// CLI
-
 #[test]
-fn should_generate_implied_address_mode_cli_machine_code() {
-    let mut ps = ProcessorStatus::new();
-    ps.interrupt_disable = true;
-    let cpu = MOS6502::default().with_ps_register(ps);
-    let op: Operation = Instruction::new(mnemonic::CLI, address_mode::Implied).into();
+fn should_generate_zeropage_indexed_with_x_address_mode_and_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x8f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::AND, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            1,
             2,
-            vec![gen_flag_set_microcode!(
-                ProgramStatusFlags::Interrupt,
-                false
-            )]
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_and_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::AND, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_and_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::AND, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_y_address_mode_and_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::AND, address_mode::AbsoluteIndexedWithY(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_x_indexed_indirect_address_mode_and_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::AND, address_mode::XIndexedIndirect(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_indirect_y_indexed_address_mode_and_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::AND, address_mode::IndirectYIndexed(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_immediate_address_mode_and_machine_code_with_zero_result() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0));
+
+    let op: Operation = Instruction::new(mnemonic::AND, address_mode::Immediate(0x0f)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_and_machine_code_with_page_penalty() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x07));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::AND, address_mode::AbsoluteIndexedWithX(0x00fe)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+// ASL
+
+#[test]
+fn should_generate_accumulator_address_mode_asl_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x81));
+
+    let op: Operation = Instruction::new(mnemonic::ASL, address_mode::Accumulator).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x02),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_asl_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::ASL, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0a, 0x02),
+            ]
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_asl_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::ASL, address_mode::ZeroPageIndexedWithX(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0a, 0x02),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_asl_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::ASL, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0x02),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_asl_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::ASL, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            7,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0x02),
+            ]
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
+        mc
+    );
+}
+
+// BCC
+
+#[test]
+fn should_generate_bcc_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.carry = false;
+
+    let op: Operation = Instruction::new(mnemonic::BCC, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bcc_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.carry = false;
+
+    let op: Operation = Instruction::new(mnemonic::BCC, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bcc_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.carry = true;
+
+    let op: Operation = Instruction::new(mnemonic::BCC, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BCS
+
+#[test]
+fn should_generate_bcs_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.carry = true;
+
+    let op: Operation = Instruction::new(mnemonic::BCS, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bcs_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.carry = true;
+
+    let op: Operation = Instruction::new(mnemonic::BCS, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bcs_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.carry = false;
+
+    let op: Operation = Instruction::new(mnemonic::BCS, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BEQ
+
+#[test]
+fn should_generate_beq_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.zero = true;
+
+    let op: Operation = Instruction::new(mnemonic::BEQ, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_beq_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.zero = true;
+
+    let op: Operation = Instruction::new(mnemonic::BEQ, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_beq_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.zero = false;
+
+    let op: Operation = Instruction::new(mnemonic::BEQ, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BIT
+
+#[test]
+fn should_generate_zeropage_address_mode_bit_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x0f));
+    cpu.address_map.write(0x0a, 0xc0).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::BIT, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_bit_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x0f));
+    cpu.address_map.write(0x0105, 0xc0).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::BIT, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+// BMI
+
+#[test]
+fn should_generate_bmi_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.negative = true;
+
+    let op: Operation = Instruction::new(mnemonic::BMI, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bmi_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.negative = true;
+
+    let op: Operation = Instruction::new(mnemonic::BMI, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bmi_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.negative = false;
+
+    let op: Operation = Instruction::new(mnemonic::BMI, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BNE
+
+#[test]
+fn should_generate_bne_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.zero = false;
+
+    let op: Operation = Instruction::new(mnemonic::BNE, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bne_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.zero = false;
+
+    let op: Operation = Instruction::new(mnemonic::BNE, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bne_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.zero = true;
+
+    let op: Operation = Instruction::new(mnemonic::BNE, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BPL
+
+#[test]
+fn should_generate_bpl_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.negative = false;
+
+    let op: Operation = Instruction::new(mnemonic::BPL, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bpl_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.negative = false;
+
+    let op: Operation = Instruction::new(mnemonic::BPL, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bpl_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.negative = true;
+
+    let op: Operation = Instruction::new(mnemonic::BPL, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BRK
+
+#[test]
+fn should_generate_implied_address_mode_brk_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_pc_register(ProgramCounter::with_value(0x6000))
+        .with_ps_register(ProcessorStatus::with_value(0x00))
+        .register_address_space(0xff00..=0xffff, Memory::<ReadWrite>::new(0xff00, 0xffff))
+        .unwrap();
+    cpu.address_map.write(0xfffe, 0x00).unwrap();
+    cpu.address_map.write(0xffff, 0x80).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::BRK, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            // should push the address following the padding byte
+            vec![gen_write_memory_microcode!(0x01ff, 0x60)],
+            vec![gen_write_memory_microcode!(0x01fe, 0x02)],
+            // should push the status with the break flag set
+            vec![gen_write_memory_microcode!(0x01fd, 0x30)],
+            vec![],
+            vec![
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 3),
+                gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x7fff),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    );
+}
+
+// BVC
+
+#[test]
+fn should_generate_bvc_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.overflow = false;
+
+    let op: Operation = Instruction::new(mnemonic::BVC, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bvc_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.overflow = false;
+
+    let op: Operation = Instruction::new(mnemonic::BVC, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bvc_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.overflow = true;
+
+    let op: Operation = Instruction::new(mnemonic::BVC, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// BVS
+
+#[test]
+fn should_generate_bvs_machine_code_with_branch_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.overflow = true;
+
+    let op: Operation = Instruction::new(mnemonic::BVS, address_mode::Relative(8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() + 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bvs_machine_code_with_branch_and_page_penalty() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.overflow = true;
+
+    let op: Operation = Instruction::new(mnemonic::BVS, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // pc - relative address - inst size
+    let pc = cpu.pc.read() - 8 - 2;
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::PC, pc)]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_bvs_machine_code_with_no_jump() {
+    let mut cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    cpu.ps.overflow = false;
+
+    let op: Operation = Instruction::new(mnemonic::BVS, address_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(MOps::new(2, 2, vec![]), mc);
+}
+
+// CLC
+
+#[test]
+fn should_generate_implied_address_mode_clc_machine_code() {
+    let mut ps = ProcessorStatus::new();
+    ps.carry = true;
+    let cpu = MOS6502::default().with_ps_register(ps);
+    let op: Operation = Instruction::new(mnemonic::CLC, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),]
+        ),
+        mc
+    );
+}
+
+// CLD
+
+#[test]
+fn should_generate_implied_address_mode_cld_machine_code() {
+    let mut ps = ProcessorStatus::new();
+    ps.carry = true;
+    let cpu = MOS6502::default().with_ps_register(ps);
+    let op: Operation = Instruction::new(mnemonic::CLD, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![gen_flag_set_microcode!(ProgramStatusFlags::Decimal, false),]
+        ),
+        mc
+    );
+}
+
+// CLI
+
+#[test]
+fn should_generate_implied_address_mode_cli_machine_code() {
+    let mut ps = ProcessorStatus::new();
+    ps.interrupt_disable = true;
+    let cpu = MOS6502::default().with_ps_register(ps);
+    let op: Operation = Instruction::new(mnemonic::CLI, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![gen_flag_set_microcode!(
+                ProgramStatusFlags::Interrupt,
+                false
+            )]
         ),
         mc
     );
@@ -296,164 +1271,1722 @@ fn should_generate_implied_address_mode_cli_machine_code() {
 // CLV
 
 #[test]
-fn should_generate_implied_address_mode_clv_machine_code() {
-    let mut ps = ProcessorStatus::new();
-    ps.overflow = true;
-    let cpu = MOS6502::default().with_ps_register(ps);
-    let op: Operation = Instruction::new(mnemonic::CLV, address_mode::Implied).into();
+fn should_generate_implied_address_mode_clv_machine_code() {
+    let mut ps = ProcessorStatus::new();
+    ps.overflow = true;
+    let cpu = MOS6502::default().with_ps_register(ps);
+    let op: Operation = Instruction::new(mnemonic::CLV, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false)]
+        ),
+        mc
+    );
+}
+
+// CMP
+
+#[test]
+fn should_generate_immediate_address_mode_cmp_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
+    let op: Operation = Instruction::new(mnemonic::CMP, address_mode::Immediate::default()).into();
+    let mc = op.generate(&cpu);
+    let expected_mops = vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+    ];
+
+    assert_eq!(MOps::new(2, 2, expected_mops.clone()), mc);
+
+    assert_eq!(
+        vec![
+            vec![],
+            expected_mops
+                .clone()
+                .into_iter()
+                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)].into_iter())
+                .collect()
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_address_mode_cmp_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
+    let op: Operation = Instruction::new(mnemonic::CMP, address_mode::Absolute::default()).into();
+    let mc = op.generate(&cpu);
+    let expected_mops = vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+    ];
+
+    assert_eq!(MOps::new(3, 4, expected_mops.clone()), mc);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            expected_mops
+                .clone()
+                .into_iter()
+                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)].into_iter())
+                .collect()
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_cmp_machine_code() {
+    let cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x00));
+    let op: Operation =
+        Instruction::new(mnemonic::CMP, address_mode::AbsoluteIndexedWithX::default()).into();
+    let mc = op.generate(&cpu);
+    let expected_mops = vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+    ];
+
+    assert_eq!(MOps::new(3, 4, expected_mops.clone()), mc);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            expected_mops
+                .clone()
+                .into_iter()
+                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)].into_iter())
+                .collect()
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_y_address_mode_cmp_machine_code() {
+    let cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x00));
+    let op: Operation =
+        Instruction::new(mnemonic::CMP, address_mode::AbsoluteIndexedWithY::default()).into();
+    let mc = op.generate(&cpu);
+    let expected_mops = vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+    ];
+
+    assert_eq!(MOps::new(3, 4, expected_mops.clone()), mc);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            expected_mops
+                .clone()
+                .into_iter()
+                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)].into_iter())
+                .collect()
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_indirect_y_indexed_address_mode_cmp_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05))
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xea));
+    cpu.address_map.write(0x00, 0xfa).unwrap();
+    cpu.address_map.write(0x01, 0x00).unwrap();
+    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+
+    let op: Operation =
+        Instruction::new(mnemonic::CMP, address_mode::IndirectYIndexed(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_x_indexed_indirect_address_mode_cmp_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05))
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xea));
+    cpu.address_map.write(0x05, 0xff).unwrap();
+    cpu.address_map.write(0x06, 0x00).unwrap();
+    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+
+    let op: Operation =
+        Instruction::new(mnemonic::CMP, address_mode::XIndexedIndirect(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_cmp_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
+    let op: Operation = Instruction::new(mnemonic::CMP, address_mode::ZeroPage::default()).into();
+    let mc = op.generate(&cpu);
+    let expected_mops = vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+    ];
+
+    assert_eq!(MOps::new(2, 3, expected_mops.clone()), mc);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            expected_mops
+                .clone()
+                .into_iter()
+                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)].into_iter())
+                .collect()
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_cmp_machine_code() {
+    let cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    let op: Operation =
+        Instruction::new(mnemonic::CMP, address_mode::ZeroPageIndexedWithX::default()).into();
+    let mc = op.generate(&cpu);
+    let expected_mops = vec![
+        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+    ];
+
+    assert_eq!(MOps::new(2, 4, expected_mops.clone()), mc);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            expected_mops
+                .clone()
+                .into_iter()
+                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)].into_iter())
+                .collect()
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+// EOR
+
+#[test]
+fn should_generate_immediate_address_mode_eor_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+
+    let op: Operation = Instruction::new(mnemonic::EOR, address_mode::Immediate(0x7f)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_eor_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+    cpu.address_map.write(0x0a, 0x7f).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::EOR, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_eor_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x7f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::EOR, address_mode::ZeroPageIndexedWithX(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_eor_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::EOR, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_eor_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::EOR, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_y_address_mode_eor_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::EOR, address_mode::AbsoluteIndexedWithY(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_x_indexed_indirect_address_mode_eor_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::EOR, address_mode::XIndexedIndirect(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_indirect_y_indexed_address_mode_eor_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::EOR, address_mode::IndirectYIndexed(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_immediate_address_mode_eor_machine_code_with_zero_result() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x5a));
+
+    let op: Operation = Instruction::new(mnemonic::EOR, address_mode::Immediate(0x5a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_eor_machine_code_with_page_penalty() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x07));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::EOR, address_mode::AbsoluteIndexedWithX(0x00fe)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+// CPX
+
+#[test]
+fn should_generate_immediate_address_mode_cpx_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x10));
+
+    let op: Operation = Instruction::new(mnemonic::CPX, address_mode::Immediate(0x10)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_cpx_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0a, 0x10).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::CPX, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_cpx_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0105, 0x10).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::CPX, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+// CPY
+
+#[test]
+fn should_generate_immediate_address_mode_cpy_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x10));
+
+    let op: Operation = Instruction::new(mnemonic::CPY, address_mode::Immediate(0x10)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_cpy_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0a, 0x10).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::CPY, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_cpy_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0105, 0x10).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::CPY, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
+// DEC
+
+#[test]
+fn should_generate_zeropage_address_mode_dec_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0a, 0x01).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::DEC, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0a, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0x01)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_dec_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x01).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::DEC, address_mode::ZeroPageIndexedWithX(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0a, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0x01)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_dec_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0105, 0x01).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::DEC, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0105, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0105, 0x01)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_dec_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x01).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::DEC, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            7,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0105, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0105, 0x01)]),
+        mc
+    );
+}
+
+// DEX
+
+#[test]
+fn should_generate_implied_address_mode_dex_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x00));
+
+    let op: Operation = Instruction::new(mnemonic::DEX, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, 0xff),
+            ]
+        ),
+        mc
+    );
+}
+
+// DEY
+
+#[test]
+fn should_generate_implied_address_mode_dey_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x00));
+
+    let op: Operation = Instruction::new(mnemonic::DEY, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0xff),
+            ]
+        ),
+        mc
+    );
+}
+
+// INC
+
+#[test]
+fn should_generate_implied_address_mode_inc_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x01ff, 0x05).unwrap();
+    let op: Operation = Instruction::new(mnemonic::INC, address_mode::Absolute(0x01ff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x01ff, 0x06),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x01ff, 0x05)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_inc_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0a, 0xff).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::INC, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0a, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0xff)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_inc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0xff).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::INC, address_mode::ZeroPageIndexedWithX(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0a, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0xff)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_inc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0xff).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::INC, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            7,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_memory_microcode!(0x0105, 0x00),
+            ]
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0105, 0xff)]),
+        mc
+    );
+}
+
+// INX
+
+#[test]
+fn should_generate_implied_address_mode_inx_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x12));
+    let op: Operation = Instruction::new(mnemonic::INX, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x13)
+            ]
+        ),
+        mc
+    );
+}
+
+// INY
+
+#[test]
+fn should_generate_implied_address_mode_iny_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x12));
+    let op: Operation = Instruction::new(mnemonic::INY, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0x13)
+            ]
+        ),
+        mc
+    );
+}
+
+// JMP
+
+#[test]
+fn should_generate_absolute_address_mode_jmp_machine_code() {
+    let cpu = MOS6502::default();
+    let addr = 0x0100;
+    let op: Operation = Instruction::new(mnemonic::JMP, address_mode::Absolute(addr)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            3,
+            vec![Microcode::Write16bitRegister(Write16bitRegister::new(
+                WordRegisters::PC,
+                addr - 3
+            ))]
+        ),
+        mc
+    );
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![
+                Microcode::Write16bitRegister(Write16bitRegister::new(WordRegisters::PC, addr - 3)),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_indirect_address_mode_jmp_machine_code() {
+    let mut cpu = MOS6502::default();
+    let base_addr = 0x0100;
+    let indirect_addr = 0x0150;
+    cpu.address_map.write(base_addr, 0x50).unwrap();
+    cpu.address_map.write(base_addr + 1, 0x01).unwrap();
+    let op: Operation = Instruction::new(mnemonic::JMP, address_mode::Indirect(base_addr)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            5,
+            vec![Microcode::Write16bitRegister(Write16bitRegister::new(
+                WordRegisters::PC,
+                indirect_addr - 3
+            )),]
+        ),
+        mc
+    );
+
+    // validate mops -> vector looks correct
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                Microcode::Write16bitRegister(Write16bitRegister::new(
+                    WordRegisters::PC,
+                    indirect_addr - 3
+                )),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+// JSR
+
+#[test]
+fn should_generate_absolute_address_mode_jsr_machine_code() {
+    let cpu = MOS6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
+    let op: Operation = Instruction::new(mnemonic::JSR, address_mode::Absolute(0x1234)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            // should push the address of the last byte of the instruction
+            vec![gen_write_memory_microcode!(0x01ff, 0x60)],
+            vec![gen_write_memory_microcode!(0x01fe, 0x02)],
+            vec![
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 2),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x1231),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_address_mode_jsr_machine_code_with_stack_pointer_wrap() {
+    let cpu = MOS6502::default()
+        .with_pc_register(ProgramCounter::with_value(0x6000))
+        .with_sp_register(StackPointer::with_value(0x00));
+    let op: Operation = Instruction::new(mnemonic::JSR, address_mode::Absolute(0x1234)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![gen_write_memory_microcode!(0x0100, 0x60)],
+            vec![gen_write_memory_microcode!(0x01ff, 0x02)],
+            vec![
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 2),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x1231),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    );
+}
+
+// LDA
+
+#[test]
+fn should_generate_immediate_address_mode_lda_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDA, address_mode::Immediate(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xff)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_lda_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDA, address_mode::ZeroPage(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(
+                    ByteRegisters::ACC,
+                    0x00 // memory defaults to null
+                )
+            ]
+        ),
+        mc
+    );
+
+    // validate mops -> vector looks correct
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_lda_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x05, 0xff).unwrap();
+    let op: Operation =
+        Instruction::new(mnemonic::LDA, address_mode::ZeroPageIndexedWithX(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(
+                    ByteRegisters::ACC,
+                    0xff // value at 0x05 in memory should be 0xff
+                )
+            ]
+        ),
+        mc
+    );
+
+    // validate mops -> vector looks correct
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xff),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_address_mode_lda_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDA, address_mode::Absolute(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00)
+            ]
+        ),
+        mc
+    );
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_lda_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    let op: Operation =
+        Instruction::new(mnemonic::LDA, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00)
+            ]
+        ),
+        mc
+    );
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_y_address_mode_lda_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    let op: Operation =
+        Instruction::new(mnemonic::LDA, address_mode::AbsoluteIndexedWithY(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00)
+            ]
+        ),
+        mc
+    );
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_indirect_y_indexed_address_mode_lda_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x00, 0xfa).unwrap();
+    cpu.address_map.write(0x01, 0x00).unwrap();
+    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+
+    let op: Operation =
+        Instruction::new(mnemonic::LDA, address_mode::IndirectYIndexed(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xea)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_x_indexed_indirect_address_mode_lda_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x05, 0xff).unwrap();
+    cpu.address_map.write(0x06, 0x00).unwrap();
+    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+
+    let op: Operation =
+        Instruction::new(mnemonic::LDA, address_mode::XIndexedIndirect(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xea)
+            ]
+        ),
+        mc
+    );
+}
+
+// LDX
+
+#[test]
+fn should_generate_absolute_address_mode_ldx_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDX, address_mode::Absolute(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x00)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_y_address_mode_ldx_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    let op: Operation =
+        Instruction::new(mnemonic::LDX, address_mode::AbsoluteIndexedWithY(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x00)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_immediate_address_mode_ldx_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDX, address_mode::Immediate(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, 0xff)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_ldx_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDX, address_mode::ZeroPage(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(
+                    ByteRegisters::X,
+                    0x00 // memory defaults to null
+                )
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_y_address_mode_ldx_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x05, 0xff).unwrap();
+    let op: Operation =
+        Instruction::new(mnemonic::LDX, address_mode::ZeroPageIndexedWithY(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(
+                    ByteRegisters::X,
+                    0xff // value at 0x05 in memory should be 0xff
+                )
+            ]
+        ),
+        mc
+    );
+}
+
+// LDY
+
+#[test]
+fn should_generate_absolute_address_mode_ldy_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDY, address_mode::Absolute(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0x00)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_indexed_with_x_address_mode_ldy_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    let op: Operation =
+        Instruction::new(mnemonic::LDY, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0x00)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_immediate_address_mode_ldy_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDY, address_mode::Immediate(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0xff)
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_ldy_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::LDY, address_mode::ZeroPage(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(
+                    ByteRegisters::Y,
+                    0x00 // memory defaults to null
+                )
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_ldy_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x05, 0xff).unwrap();
+    let op: Operation =
+        Instruction::new(mnemonic::LDY, address_mode::ZeroPageIndexedWithX(0x00)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(
+                    ByteRegisters::Y,
+                    0xff // value at 0x05 in memory should be 0xff
+                )
+            ]
+        ),
+        mc
+    );
+}
+
+// LSR
+
+#[test]
+fn should_generate_accumulator_address_mode_lsr_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x81));
+
+    let op: Operation = Instruction::new(mnemonic::LSR, address_mode::Accumulator).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x40),
+            ]
+        ),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_lsr_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::LSR, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0a, 0x40),
+            ]
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_lsr_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::LSR, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            1,
             2,
-            vec![gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false)]
-        ),
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0a, 0x40),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
         mc
     );
 }
 
-// CMP
-
 #[test]
-fn should_generate_immediate_address_mode_cmp_machine_code() {
-    let cpu =
-        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
-    let op: Operation = Instruction::new(mnemonic::CMP, address_mode::Immediate::default()).into();
-    let mc = op.generate(&cpu);
-    let expected_mops = vec![
-        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-    ];
+fn should_generate_absolute_address_mode_lsr_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.address_map.write(0x0105, 0x81).unwrap();
 
-    assert_eq!(MOps::new(2, 2, expected_mops.clone()), mc);
+    let op: Operation = Instruction::new(mnemonic::LSR, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            expected_mops
-                .clone()
-                .into_iter()
-                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)].into_iter())
-                .collect()
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        MOps::new(
+            3,
+            6,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0x40),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
+        mc
+    );
 }
 
 #[test]
-fn should_generate_absolute_address_mode_cmp_machine_code() {
-    let cpu =
-        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
-    let op: Operation = Instruction::new(mnemonic::CMP, address_mode::Absolute::default()).into();
-    let mc = op.generate(&cpu);
-    let expected_mops = vec![
-        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-    ];
+fn should_generate_absolute_indexed_with_x_address_mode_lsr_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x81).unwrap();
 
-    assert_eq!(MOps::new(3, 4, expected_mops.clone()), mc);
+    let op: Operation =
+        Instruction::new(mnemonic::LSR, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
-            expected_mops
-                .clone()
-                .into_iter()
-                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)].into_iter())
-                .collect()
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        MOps::new(
+            3,
+            7,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0x40),
+            ]
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
+        mc
+    );
 }
 
+// NOP
+
 #[test]
-fn should_generate_absolute_indexed_with_x_address_mode_cmp_machine_code() {
-    let cpu = MOS6502::default()
-        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00))
-        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x00));
-    let op: Operation =
-        Instruction::new(mnemonic::CMP, address_mode::AbsoluteIndexedWithX::default()).into();
+fn should_generate_implied_address_mode_nop_machine_code() {
+    let cpu = MOS6502::default();
+    let op: Operation = Instruction::new(mnemonic::NOP, address_mode::Implied).into();
     let mc = op.generate(&cpu);
-    let expected_mops = vec![
-        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-    ];
 
-    assert_eq!(MOps::new(3, 4, expected_mops.clone()), mc);
+    assert_eq!(MOps::new(1, 2, vec![]), mc);
 
+    // validate mops -> vector looks correct
     assert_eq!(
         vec![
             vec![],
-            vec![],
-            vec![],
-            expected_mops
-                .clone()
-                .into_iter()
-                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)].into_iter())
-                .collect()
+            vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)]
         ],
         Into::<Vec<Vec<Microcode>>>::into(mc)
     )
 }
 
+// ORA
+
 #[test]
-fn should_generate_absolute_indexed_with_y_address_mode_cmp_machine_code() {
-    let cpu = MOS6502::default()
-        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00))
-        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x00));
-    let op: Operation =
-        Instruction::new(mnemonic::CMP, address_mode::AbsoluteIndexedWithY::default()).into();
-    let mc = op.generate(&cpu);
-    let expected_mops = vec![
-        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-    ];
+fn should_generate_immediate_address_mode_ora_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80));
 
-    assert_eq!(MOps::new(3, 4, expected_mops.clone()), mc);
+    let op: Operation = Instruction::new(mnemonic::ORA, address_mode::Immediate(0x0f)).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
-            expected_mops
-                .clone()
-                .into_iter()
-                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)].into_iter())
-                .collect()
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        MOps::new(
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
+            ]
+        ),
+        mc
+    );
 }
 
 #[test]
-fn should_generate_indirect_y_indexed_address_mode_cmp_machine_code() {
-    let mut cpu = MOS6502::default()
-        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05))
-        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xea));
-    cpu.address_map.write(0x00, 0xfa).unwrap();
-    cpu.address_map.write(0x01, 0x00).unwrap();
-    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+fn should_generate_zeropage_address_mode_ora_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80));
+    cpu.address_map.write(0x0a, 0x0f).unwrap();
 
-    let op: Operation =
-        Instruction::new(mnemonic::CMP, address_mode::IndirectYIndexed(0x00)).into();
+    let op: Operation = Instruction::new(mnemonic::ORA, address_mode::ZeroPage(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             2,
-            5,
+            3,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
             ]
         ),
         mc
@@ -461,26 +2994,24 @@ fn should_generate_indirect_y_indexed_address_mode_cmp_machine_code() {
 }
 
 #[test]
-fn should_generate_x_indexed_indirect_address_mode_cmp_machine_code() {
+fn should_generate_zeropage_indexed_with_x_address_mode_ora_machine_code() {
     let mut cpu = MOS6502::default()
-        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05))
-        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xea));
-    cpu.address_map.write(0x05, 0xff).unwrap();
-    cpu.address_map.write(0x06, 0x00).unwrap();
-    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x0f).unwrap();
 
     let op: Operation =
-        Instruction::new(mnemonic::CMP, address_mode::XIndexedIndirect(0x00)).into();
+        Instruction::new(mnemonic::ORA, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             2,
-            6,
+            4,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
             ]
         ),
         mc
@@ -488,425 +3019,445 @@ fn should_generate_x_indexed_indirect_address_mode_cmp_machine_code() {
 }
 
 #[test]
-fn should_generate_zeropage_address_mode_cmp_machine_code() {
-    let cpu =
-        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
-    let op: Operation = Instruction::new(mnemonic::CMP, address_mode::ZeroPage::default()).into();
-    let mc = op.generate(&cpu);
-    let expected_mops = vec![
-        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-    ];
+fn should_generate_absolute_address_mode_ora_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
 
-    assert_eq!(MOps::new(2, 3, expected_mops.clone()), mc);
+    let op: Operation = Instruction::new(mnemonic::ORA, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            expected_mops
-                .clone()
-                .into_iter()
-                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)].into_iter())
-                .collect()
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
+            ]
+        ),
+        mc
+    );
 }
 
 #[test]
-fn should_generate_zeropage_indexed_with_x_address_mode_cmp_machine_code() {
-    let cpu = MOS6502::default()
-        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00))
+fn should_generate_absolute_indexed_with_x_address_mode_ora_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80))
         .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
     let op: Operation =
-        Instruction::new(mnemonic::CMP, address_mode::ZeroPageIndexedWithX::default()).into();
+        Instruction::new(mnemonic::ORA, address_mode::AbsoluteIndexedWithX(0x0100)).into();
     let mc = op.generate(&cpu);
-    let expected_mops = vec![
-        gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
-        gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-        gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-    ];
-
-    assert_eq!(MOps::new(2, 4, expected_mops.clone()), mc);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
-            expected_mops
-                .clone()
-                .into_iter()
-                .chain(vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)].into_iter())
-                .collect()
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        MOps::new(
+            3,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
+            ]
+        ),
+        mc
+    );
 }
 
-// INC
-
 #[test]
-fn should_generate_implied_address_mode_inc_machine_code() {
-    let mut cpu = MOS6502::default();
-    cpu.address_map.write(0x01ff, 0x05).unwrap();
-    let op: Operation = Instruction::new(mnemonic::INC, address_mode::Absolute(0x01ff)).into();
+fn should_generate_absolute_indexed_with_y_address_mode_ora_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::ORA, address_mode::AbsoluteIndexedWithY(0x0100)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             3,
-            6,
+            4,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_memory_microcode!(0x01ff, 0x06),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
             ]
         ),
         mc
     );
 }
 
-// INX
-
 #[test]
-fn should_generate_implied_address_mode_inx_machine_code() {
-    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x12));
-    let op: Operation = Instruction::new(mnemonic::INX, address_mode::Implied).into();
+fn should_generate_x_indexed_indirect_address_mode_ora_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::ORA, address_mode::XIndexedIndirect(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            1,
             2,
+            6,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x13)
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
             ]
         ),
         mc
     );
 }
 
-// INY
-
 #[test]
-fn should_generate_implied_address_mode_iny_machine_code() {
-    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x12));
-    let op: Operation = Instruction::new(mnemonic::INY, address_mode::Implied).into();
+fn should_generate_indirect_y_indexed_address_mode_ora_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::ORA, address_mode::IndirectYIndexed(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            1,
             2,
+            5,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0x13)
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
             ]
         ),
         mc
     );
 }
 
-// JMP
-
 #[test]
-fn should_generate_absolute_address_mode_jmp_machine_code() {
-    let cpu = MOS6502::default();
-    let addr = 0x0100;
-    let op: Operation = Instruction::new(mnemonic::JMP, address_mode::Absolute(addr)).into();
+fn should_generate_immediate_address_mode_ora_machine_code_with_zero_result() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
+
+    let op: Operation = Instruction::new(mnemonic::ORA, address_mode::Immediate(0x00)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            3,
-            3,
-            vec![Microcode::Write16bitRegister(Write16bitRegister::new(
-                WordRegisters::PC,
-                addr - 3
-            ))]
+            2,
+            2,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
+            ]
         ),
         mc
     );
-
-    assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![
-                Microcode::Write16bitRegister(Write16bitRegister::new(WordRegisters::PC, addr - 3)),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
-            ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
 }
 
 #[test]
-fn should_generate_indirect_address_mode_jmp_machine_code() {
-    let mut cpu = MOS6502::default();
-    let base_addr = 0x0100;
-    let indirect_addr = 0x0150;
-    cpu.address_map.write(base_addr, 0x50).unwrap();
-    cpu.address_map.write(base_addr + 1, 0x01).unwrap();
-    let op: Operation = Instruction::new(mnemonic::JMP, address_mode::Indirect(base_addr)).into();
+fn should_generate_absolute_indexed_with_x_address_mode_ora_machine_code_with_page_penalty() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x07));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::ORA, address_mode::AbsoluteIndexedWithX(0x00fe)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             3,
             5,
-            vec![Microcode::Write16bitRegister(Write16bitRegister::new(
-                WordRegisters::PC,
-                indirect_addr - 3
-            )),]
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x8f),
+            ]
         ),
         mc
     );
+}
+
+// PHA
+
+#[test]
+fn should_generate_implied_address_mode_pha_machine_code() {
+    let cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+    let op: Operation = Instruction::new(mnemonic::PHA, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
 
-    // validate mops -> vector looks correct
     assert_eq!(
         vec![
-            vec![],
-            vec![],
             vec![],
             vec![],
             vec![
-                Microcode::Write16bitRegister(Write16bitRegister::new(
-                    WordRegisters::PC,
-                    indirect_addr - 3
-                )),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+                // should write to the top of the stack
+                gen_write_memory_microcode!(0x01ff, 0xff),
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
             ]
         ],
         Into::<Vec<Vec<Microcode>>>::into(mc)
     )
 }
 
-// LDA
+// PHP
 
 #[test]
-fn should_generate_immediate_address_mode_lda_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDA, address_mode::Immediate(0xff)).into();
+fn should_generate_implied_address_mode_php_machine_code() {
+    let cpu = MOS6502::default().with_ps_register(ProcessorStatus::with_value(0x55));
+    let op: Operation = Instruction::new(mnemonic::PHP, address_mode::Implied).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        MOps::new(
-            2,
-            2,
+        vec![
+            vec![],
+            vec![],
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xff)
+                // should write to the top of the stack
+                gen_write_memory_microcode!(0x01ff, 0x55),
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
             ]
-        ),
-        mc
-    );
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
 }
 
+// PLA
+
 #[test]
-fn should_generate_zeropage_address_mode_lda_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDA, address_mode::ZeroPage(0xff)).into();
-    let mc = op.generate(&cpu);
+fn should_generate_implied_address_mode_pla_machine_code() {
+    let mut cpu = MOS6502::default()
+        // simulate having pushed teh value 0xff to the stack
+        .with_sp_register(StackPointer::with_value(0xfe));
+    cpu.address_map.write(0x01ff, 0xff).unwrap();
 
-    assert_eq!(
-        MOps::new(
-            2,
-            3,
-            vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::ACC,
-                    0x00 // memory defaults to null
-                )
-            ]
-        ),
-        mc
-    );
+    let op: Operation = Instruction::new(mnemonic::PLA, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
 
-    // validate mops -> vector looks correct
     assert_eq!(
         vec![
+            vec![],
             vec![],
             vec![],
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xff),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
             ]
         ],
         Into::<Vec<Vec<Microcode>>>::into(mc)
     )
 }
 
+// PLP
+
 #[test]
-fn should_generate_zeropage_indexed_with_x_address_mode_lda_machine_code() {
-    let mut cpu =
-        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
-    cpu.address_map.write(0x05, 0xff).unwrap();
-    let op: Operation =
-        Instruction::new(mnemonic::LDA, address_mode::ZeroPageIndexedWithX(0x00)).into();
-    let mc = op.generate(&cpu);
+fn should_generate_implied_address_mode_plp_machine_code() {
+    let mut cpu = MOS6502::default()
+        // simulate having pushed the value 0x55 from ps register to the stack
+        .with_sp_register(StackPointer::with_value(0xfe));
+    cpu.address_map.write(0x01ff, 0x55).unwrap();
 
-    assert_eq!(
-        MOps::new(
-            2,
-            4,
-            vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::ACC,
-                    0xff // value at 0x05 in memory should be 0xff
-                )
-            ]
-        ),
-        mc
-    );
+    let op: Operation = Instruction::new(mnemonic::PLP, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
 
-    // validate mops -> vector looks correct
     assert_eq!(
         vec![
             vec![],
             vec![],
             vec![],
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xff),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 2)
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_write_8bit_register_microcode!(ByteRegisters::PS, 0x55),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
             ]
         ],
         Into::<Vec<Vec<Microcode>>>::into(mc)
     )
 }
 
+// ROL
+
 #[test]
-fn should_generate_absolute_address_mode_lda_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDA, address_mode::Absolute(0x0100)).into();
+fn should_generate_accumulator_address_mode_rol_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x81));
+    cpu.ps.carry = true;
+
+    let op: Operation = Instruction::new(mnemonic::ROL, address_mode::Accumulator).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            3,
-            4,
+            1,
+            2,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x03),
             ]
         ),
         mc
     );
+}
+
+#[test]
+fn should_generate_zeropage_address_mode_rol_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::ROL, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
+        MOps::new(
+            2,
+            5,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0a, 0x03),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
+        mc
+    );
 }
 
 #[test]
-fn should_generate_absolute_indexed_with_x_address_mode_lda_machine_code() {
-    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+fn should_generate_zeropage_indexed_with_x_address_mode_rol_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
     let op: Operation =
-        Instruction::new(mnemonic::LDA, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+        Instruction::new(mnemonic::ROL, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            3,
-            4,
+            2,
+            6,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0a, 0x03),
             ]
-        ),
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
         mc
     );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_rol_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::ROL, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
+        MOps::new(
+            3,
+            6,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0x03),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
+        mc
+    );
 }
 
 #[test]
-fn should_generate_absolute_indexed_with_y_address_mode_lda_machine_code() {
-    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+fn should_generate_absolute_indexed_with_x_address_mode_rol_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
     let op: Operation =
-        Instruction::new(mnemonic::LDA, address_mode::AbsoluteIndexedWithY(0x0100)).into();
+        Instruction::new(mnemonic::ROL, address_mode::AbsoluteIndexedWithX(0x0100)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             3,
-            4,
+            7,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0x03),
             ]
-        ),
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
         mc
     );
+}
+
+// ROR
+
+#[test]
+fn should_generate_accumulator_address_mode_ror_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x81));
+    cpu.ps.carry = true;
+
+    let op: Operation = Instruction::new(mnemonic::ROR, address_mode::Accumulator).into();
+    let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
+        MOps::new(
+            1,
+            2,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x00),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 3)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xc0),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        ),
+        mc
+    );
 }
 
 #[test]
-fn should_generate_indirect_y_indexed_address_mode_lda_machine_code() {
-    let mut cpu =
-        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
-    cpu.address_map.write(0x00, 0xfa).unwrap();
-    cpu.address_map.write(0x01, 0x00).unwrap();
-    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+fn should_generate_zeropage_address_mode_ror_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
 
-    let op: Operation =
-        Instruction::new(mnemonic::LDA, address_mode::IndirectYIndexed(0x00)).into();
+    let op: Operation = Instruction::new(mnemonic::ROR, address_mode::ZeroPage(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
@@ -914,25 +3465,26 @@ fn should_generate_indirect_y_indexed_address_mode_lda_machine_code() {
             2,
             5,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xea)
+                gen_write_memory_microcode!(0x0a, 0xc0),
             ]
-        ),
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
         mc
     );
 }
 
 #[test]
-fn should_generate_x_indexed_indirect_address_mode_lda_machine_code() {
+fn should_generate_zeropage_indexed_with_x_address_mode_ror_machine_code() {
     let mut cpu =
         MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
-    cpu.address_map.write(0x05, 0xff).unwrap();
-    cpu.address_map.write(0x06, 0x00).unwrap();
-    cpu.address_map.write(0xff, 0xea).unwrap(); // indirect addr
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
 
     let op: Operation =
-        Instruction::new(mnemonic::LDA, address_mode::XIndexedIndirect(0x00)).into();
+        Instruction::new(mnemonic::ROR, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
@@ -940,95 +3492,169 @@ fn should_generate_x_indexed_indirect_address_mode_lda_machine_code() {
             2,
             6,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xea)
+                gen_write_memory_microcode!(0x0a, 0xc0),
             ]
-        ),
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0x81)]),
         mc
     );
 }
 
-// LDX
-
 #[test]
-fn should_generate_absolute_address_mode_ldx_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDX, address_mode::Absolute(0x0100)).into();
+fn should_generate_absolute_address_mode_ror_machine_code() {
+    let mut cpu = MOS6502::default();
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::ROR, address_mode::Absolute(0x0105)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             3,
-            4,
+            6,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0xc0),
             ]
-        ),
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
         mc
     );
 }
 
 #[test]
-fn should_generate_absolute_indexed_with_y_address_mode_ldx_machine_code() {
-    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+fn should_generate_absolute_indexed_with_x_address_mode_ror_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
     let op: Operation =
-        Instruction::new(mnemonic::LDX, address_mode::AbsoluteIndexedWithY(0x0100)).into();
+        Instruction::new(mnemonic::ROR, address_mode::AbsoluteIndexedWithX(0x0100)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             3,
-            4,
+            7,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_memory_microcode!(0x0105, 0xc0),
+            ]
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0105, 0x81)]),
+        mc
+    );
+}
+
+// RTI
+
+#[test]
+fn should_generate_implied_address_mode_rti_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_ps_register(ProcessorStatus::with_value(0x24))
+        // simulate having pushed the return address 0x6002 and status to the stack
+        .with_sp_register(StackPointer::with_value(0xfc));
+    cpu.address_map.write(0x01ff, 0x60).unwrap();
+    cpu.address_map.write(0x01fe, 0x02).unwrap();
+    cpu.address_map.write(0x01fd, 0xd3).unwrap();
+    let op: Operation = Instruction::new(mnemonic::RTI, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            1,
+            6,
+            vec![
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 3),
+                // the break and unused bits should be left unchanged
+                gen_write_8bit_register_microcode!(ByteRegisters::PS, 0xe3),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x6001),
             ]
         ),
         mc
     );
 }
 
+// RTS
+
 #[test]
-fn should_generate_immediate_address_mode_ldx_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDX, address_mode::Immediate(0xff)).into();
+fn should_generate_implied_address_mode_rts_machine_code() {
+    let mut cpu = MOS6502::default()
+        // simulate having pushed the return address 0x6002 to the stack
+        .with_sp_register(StackPointer::with_value(0xfd));
+    cpu.address_map.write(0x01ff, 0x60).unwrap();
+    cpu.address_map.write(0x01fe, 0x02).unwrap();
+    let op: Operation = Instruction::new(mnemonic::RTS, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 2),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x6002),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    )
+}
+
+#[test]
+fn should_generate_implied_address_mode_rts_machine_code_with_stack_pointer_wrap() {
+    let mut cpu = MOS6502::default().with_sp_register(StackPointer::with_value(0xfe));
+    cpu.address_map.write(0x01ff, 0x02).unwrap();
+    cpu.address_map.write(0x0100, 0x60).unwrap();
+    let op: Operation = Instruction::new(mnemonic::RTS, address_mode::Implied).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            2,
-            2,
+            1,
+            6,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::X, 0xff)
+                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 2),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x6002),
             ]
         ),
         mc
     );
 }
 
+// SBC
+
 #[test]
-fn should_generate_zeropage_address_mode_ldx_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDX, address_mode::ZeroPage(0xff)).into();
+fn should_generate_immediate_address_mode_sbc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50));
+    cpu.ps.carry = true;
+
+    let op: Operation = Instruction::new(mnemonic::SBC, address_mode::Immediate(0xb0)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             2,
-            3,
+            2,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::X,
-                    0x00 // memory defaults to null
-                )
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
@@ -1036,47 +3662,53 @@ fn should_generate_zeropage_address_mode_ldx_machine_code() {
 }
 
 #[test]
-fn should_generate_zeropage_indexed_with_y_address_mode_ldx_machine_code() {
+fn should_generate_zeropage_address_mode_sbc_machine_code() {
     let mut cpu =
-        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
-    cpu.address_map.write(0x05, 0xff).unwrap();
-    let op: Operation =
-        Instruction::new(mnemonic::LDX, address_mode::ZeroPageIndexedWithY(0x00)).into();
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0xb0).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::SBC, address_mode::ZeroPage(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             2,
-            4,
+            3,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::X,
-                    0xff // value at 0x05 in memory should be 0xff
-                )
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
     );
 }
 
-// LDY
-
 #[test]
-fn should_generate_absolute_address_mode_ldy_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDY, address_mode::Absolute(0x0100)).into();
+fn should_generate_zeropage_indexed_with_x_address_mode_sbc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0xb0).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::SBC, address_mode::ZeroPageIndexedWithX(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            3,
+            2,
             4,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
@@ -1084,10 +3716,13 @@ fn should_generate_absolute_address_mode_ldy_machine_code() {
 }
 
 #[test]
-fn should_generate_absolute_indexed_with_x_address_mode_ldy_machine_code() {
-    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
-    let op: Operation =
-        Instruction::new(mnemonic::LDY, address_mode::AbsoluteIndexedWithX(0x0100)).into();
+fn should_generate_absolute_address_mode_sbc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::SBC, address_mode::Absolute(0x0105)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
@@ -1095,9 +3730,11 @@ fn should_generate_absolute_indexed_with_x_address_mode_ldy_machine_code() {
             3,
             4,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0x00)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
@@ -1105,19 +3742,27 @@ fn should_generate_absolute_indexed_with_x_address_mode_ldy_machine_code() {
 }
 
 #[test]
-fn should_generate_immediate_address_mode_ldy_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDY, address_mode::Immediate(0xff)).into();
+fn should_generate_absolute_indexed_with_x_address_mode_sbc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::SBC, address_mode::AbsoluteIndexedWithX(0x0100)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            2,
-            2,
+            3,
+            4,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::Y, 0xff)
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
@@ -1125,22 +3770,27 @@ fn should_generate_immediate_address_mode_ldy_machine_code() {
 }
 
 #[test]
-fn should_generate_zeropage_address_mode_ldy_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::LDY, address_mode::ZeroPage(0xff)).into();
+fn should_generate_absolute_indexed_with_y_address_mode_sbc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::SBC, address_mode::AbsoluteIndexedWithY(0x0100)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
-            2,
             3,
+            4,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::Y,
-                    0x00 // memory defaults to null
-                )
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
@@ -1148,161 +3798,147 @@ fn should_generate_zeropage_address_mode_ldy_machine_code() {
 }
 
 #[test]
-fn should_generate_zeropage_indexed_with_x_address_mode_ldy_machine_code() {
-    let mut cpu =
-        MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
-    cpu.address_map.write(0x05, 0xff).unwrap();
+fn should_generate_x_indexed_indirect_address_mode_sbc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
     let op: Operation =
-        Instruction::new(mnemonic::LDY, address_mode::ZeroPageIndexedWithX(0x00)).into();
+        Instruction::new(mnemonic::SBC, address_mode::XIndexedIndirect(0x05)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
         MOps::new(
             2,
-            4,
+            6,
             vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::Y,
-                    0xff // value at 0x05 in memory should be 0xff
-                )
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
         ),
         mc
     );
 }
 
-// NOP
-
 #[test]
-fn should_generate_implied_address_mode_nop_machine_code() {
-    let cpu = MOS6502::default();
-    let op: Operation = Instruction::new(mnemonic::NOP, address_mode::Implied).into();
-    let mc = op.generate(&cpu);
-
-    assert_eq!(MOps::new(1, 2, vec![]), mc);
-
-    // validate mops -> vector looks correct
-    assert_eq!(
-        vec![
-            vec![],
-            vec![gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
-}
-
-// PHA
+fn should_generate_indirect_y_indexed_address_mode_sbc_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
 
-#[test]
-fn should_generate_implied_address_mode_pha_machine_code() {
-    let cpu = MOS6502::default()
-        .reset()
-        .unwrap()
-        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
-    let op: Operation = Instruction::new(mnemonic::PHA, address_mode::Implied).into();
+    let op: Operation =
+        Instruction::new(mnemonic::SBC, address_mode::IndirectYIndexed(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
+        MOps::new(
+            2,
+            5,
             vec![
-                // should write to the top of the stack
-                gen_write_memory_microcode!(0x01ff, 0xff),
-                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        ),
+        mc
+    );
 }
 
-// PHP
-
 #[test]
-fn should_generate_implied_address_mode_php_machine_code() {
-    let cpu = MOS6502::default()
-        .reset()
-        .unwrap()
-        .with_ps_register(ProcessorStatus::with_value(0x55));
-    let op: Operation = Instruction::new(mnemonic::PHP, address_mode::Implied).into();
+fn should_generate_absolute_indexed_with_x_address_mode_sbc_machine_code_with_page_penalty() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x07));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::SBC, address_mode::AbsoluteIndexedWithX(0x00fe)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
+        MOps::new(
+            3,
+            5,
             vec![
-                // should write to the top of the stack
-                gen_write_memory_microcode!(0x01ff, 0x55),
-                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 1),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        ),
+        mc
+    );
 }
 
-// PLA
-
 #[test]
-fn should_generate_implied_address_mode_pla_machine_code() {
+fn should_generate_indirect_y_indexed_address_mode_sbc_machine_code_with_page_penalty() {
     let mut cpu = MOS6502::default()
-        .reset()
-        .unwrap()
-        // simulate having pushed teh value 0xff to the stack
-        .with_sp_register(StackPointer::with_value(0xfe));
-    cpu.address_map.write(0x01ff, 0xff).unwrap();
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x07));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0xfe).unwrap();
+    cpu.address_map.write(0x0b, 0x00).unwrap();
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
 
-    let op: Operation = Instruction::new(mnemonic::PLA, address_mode::Implied).into();
+    let op: Operation =
+        Instruction::new(mnemonic::SBC, address_mode::IndirectYIndexed(0x0a)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
+        MOps::new(
+            2,
+            6,
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xff),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xa0),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        ),
+        mc
+    );
 }
 
-// PLP
-
 #[test]
-fn should_generate_implied_address_mode_plp_machine_code() {
-    let mut cpu = MOS6502::default()
-        .reset()
-        .unwrap()
-        // simulate having pushed the value 0x55 from ps register to the stack
-        .with_sp_register(StackPointer::with_value(0xfe));
-    cpu.address_map.write(0x01ff, 0x55).unwrap();
+fn should_generate_immediate_address_mode_sbc_machine_code_in_decimal_mode() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x12));
+    cpu.ps.decimal = true;
+    cpu.ps.carry = true;
 
-    let op: Operation = Instruction::new(mnemonic::PLP, address_mode::Implied).into();
+    let op: Operation = Instruction::new(mnemonic::SBC, address_mode::Immediate(0x21)).into();
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        vec![
-            vec![],
-            vec![],
-            vec![],
+        MOps::new(
+            2,
+            2,
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::SP, 1),
-                gen_write_8bit_register_microcode!(ByteRegisters::PS, 0x55),
-                gen_inc_16bit_register_microcode!(WordRegisters::PC, 1)
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x91),
             ]
-        ],
-        Into::<Vec<Vec<Microcode>>>::into(mc)
-    )
+        ),
+        mc
+    );
 }
 
 // SEC
@@ -1684,3 +4320,157 @@ fn should_generate_implied_address_mode_tya_machine_code() {
         mc
     );
 }
+
+// STX
+
+#[test]
+fn should_generate_zeropage_address_mode_stx_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0xab));
+
+    let op: Operation = Instruction::new(mnemonic::STX, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(2, 3, vec![gen_write_memory_microcode!(0x0a, 0xab),]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_y_address_mode_stx_machine_code() {
+    let cpu = MOS6502::default()
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0xab))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+
+    let op: Operation =
+        Instruction::new(mnemonic::STX, address_mode::ZeroPageIndexedWithY(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(2, 4, vec![gen_write_memory_microcode!(0x0a, 0xab),]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_stx_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::X, GeneralPurpose::with_value(0xab));
+
+    let op: Operation = Instruction::new(mnemonic::STX, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(3, 4, vec![gen_write_memory_microcode!(0x0105, 0xab),]),
+        mc
+    );
+}
+
+// STY
+
+#[test]
+fn should_generate_zeropage_address_mode_sty_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0xab));
+
+    let op: Operation = Instruction::new(mnemonic::STY, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(2, 3, vec![gen_write_memory_microcode!(0x0a, 0xab),]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_sty_machine_code() {
+    let cpu = MOS6502::default()
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0xab))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+
+    let op: Operation =
+        Instruction::new(mnemonic::STY, address_mode::ZeroPageIndexedWithX(0x05)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(2, 4, vec![gen_write_memory_microcode!(0x0a, 0xab),]),
+        mc
+    );
+}
+
+#[test]
+fn should_generate_absolute_address_mode_sty_machine_code() {
+    let cpu = MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0xab));
+
+    let op: Operation = Instruction::new(mnemonic::STY, address_mode::Absolute(0x0105)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(3, 4, vec![gen_write_memory_microcode!(0x0105, 0xab),]),
+        mc
+    );
+}
+
+// Interrupts
+
+#[test]
+fn should_generate_nmi_interrupt_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_pc_register(ProgramCounter::with_value(0x6000))
+        .with_ps_register(ProcessorStatus::with_value(0x30))
+        .register_address_space(0xff00..=0xffff, Memory::<ReadWrite>::new(0xff00, 0xffff))
+        .unwrap();
+    cpu.address_map.write(0xfffa, 0x00).unwrap();
+    cpu.address_map.write(0xfffb, 0x90).unwrap();
+
+    let mc = Interrupt::Nmi.generate(&cpu);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            // should push the address of the next instruction
+            vec![gen_write_memory_microcode!(0x01ff, 0x60)],
+            vec![gen_write_memory_microcode!(0x01fe, 0x00)],
+            // should push the status with the break flag unset
+            vec![gen_write_memory_microcode!(0x01fd, 0x20)],
+            vec![],
+            vec![
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 3),
+                gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x9000),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 0)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    );
+}
+
+#[test]
+fn should_generate_irq_interrupt_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_pc_register(ProgramCounter::with_value(0x6000))
+        .with_ps_register(ProcessorStatus::with_value(0x00))
+        .register_address_space(0xff00..=0xffff, Memory::<ReadWrite>::new(0xff00, 0xffff))
+        .unwrap();
+    cpu.address_map.write(0xfffe, 0x00).unwrap();
+    cpu.address_map.write(0xffff, 0x80).unwrap();
+
+    let mc = Interrupt::Irq.generate(&cpu);
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![],
+            vec![gen_write_memory_microcode!(0x01ff, 0x60)],
+            vec![gen_write_memory_microcode!(0x01fe, 0x00)],
+            vec![gen_write_memory_microcode!(0x01fd, 0x20)],
+            vec![],
+            vec![
+                gen_dec_8bit_register_microcode!(ByteRegisters::SP, 3),
+                gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
+                gen_write_16bit_register_microcode!(WordRegisters::PC, 0x8000),
+                gen_inc_16bit_register_microcode!(WordRegisters::PC, 0)
+            ]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
+    );
+}
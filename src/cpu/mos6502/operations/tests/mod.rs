@@ -3,6 +3,8 @@ use std::convert::TryFrom;
 
 #[cfg(test)]
 mod code_generation;
+#[cfg(feature = "illegal-opcodes")]
+mod undocumented;
 
 macro_rules! gen_op_parse_assertion {
     ($bytecode:expr) => {
@@ -10,6 +12,132 @@ macro_rules! gen_op_parse_assertion {
     };
 }
 
+#[test]
+fn should_parse_immediate_address_mode_adc_instruction() {
+    let bytecode = [0x69, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_adc_instruction() {
+    let bytecode = [0x65, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_adc_instruction() {
+    let bytecode = [0x75, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_adc_instruction() {
+    let bytecode = [0x6d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_adc_instruction() {
+    let bytecode = [0x7d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_y_address_mode_adc_instruction() {
+    let bytecode = [0x79, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_x_indexed_indirect_address_mode_adc_instruction() {
+    let bytecode = [0x61, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_indirect_y_indexed_address_mode_adc_instruction() {
+    let bytecode = [0x71, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_immediate_address_mode_and_instruction() {
+    let bytecode = [0x29, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_and_instruction() {
+    let bytecode = [0x25, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_and_instruction() {
+    let bytecode = [0x35, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_and_instruction() {
+    let bytecode = [0x2d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_and_instruction() {
+    let bytecode = [0x3d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_y_address_mode_and_instruction() {
+    let bytecode = [0x39, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_x_indexed_indirect_address_mode_and_instruction() {
+    let bytecode = [0x21, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_indirect_y_indexed_address_mode_and_instruction() {
+    let bytecode = [0x31, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_accumulator_address_mode_asl_instruction() {
+    let bytecode = [0x0a, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_asl_instruction() {
+    let bytecode = [0x06, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_asl_instruction() {
+    let bytecode = [0x16, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_asl_instruction() {
+    let bytecode = [0x0e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_asl_instruction() {
+    let bytecode = [0x1e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_relative_address_mode_bcc_instruction() {
     let bytecode = [0x90, 0x00, 0x00];
@@ -28,12 +156,54 @@ fn should_parse_relative_address_mode_beq_instruction() {
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_zeropage_address_mode_bit_instruction() {
+    let bytecode = [0x24, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_bit_instruction() {
+    let bytecode = [0x2c, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_relative_address_mode_bmi_instruction() {
+    let bytecode = [0x30, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_relative_address_mode_bne_instruction() {
     let bytecode = [0xd0, 0x00, 0x00];
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_relative_address_mode_bpl_instruction() {
+    let bytecode = [0x10, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_implied_address_mode_brk_instruction() {
+    let bytecode = [0x00, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_relative_address_mode_bvc_instruction() {
+    let bytecode = [0x50, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_relative_address_mode_bvs_instruction() {
+    let bytecode = [0x70, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_implied_address_mode_clc_instruction() {
     let bytecode = [0x18, 0x00, 0x00];
@@ -106,12 +276,150 @@ fn should_parse_zeropage_address_mode_cmp_instruction() {
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_immediate_address_mode_eor_instruction() {
+    let bytecode = [0x49, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_eor_instruction() {
+    let bytecode = [0x45, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_eor_instruction() {
+    let bytecode = [0x55, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_eor_instruction() {
+    let bytecode = [0x4d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_eor_instruction() {
+    let bytecode = [0x5d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_y_address_mode_eor_instruction() {
+    let bytecode = [0x59, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_x_indexed_indirect_address_mode_eor_instruction() {
+    let bytecode = [0x41, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_indirect_y_indexed_address_mode_eor_instruction() {
+    let bytecode = [0x51, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_immediate_address_mode_cpx_instruction() {
+    let bytecode = [0xe0, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_cpx_instruction() {
+    let bytecode = [0xe4, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_cpx_instruction() {
+    let bytecode = [0xec, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_immediate_address_mode_cpy_instruction() {
+    let bytecode = [0xc0, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_cpy_instruction() {
+    let bytecode = [0xc4, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_cpy_instruction() {
+    let bytecode = [0xcc, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_dec_instruction() {
+    let bytecode = [0xc6, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_dec_instruction() {
+    let bytecode = [0xd6, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_dec_instruction() {
+    let bytecode = [0xce, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_dec_instruction() {
+    let bytecode = [0xde, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_implied_address_mode_dex_instruction() {
+    let bytecode = [0xca, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_implied_address_mode_dey_instruction() {
+    let bytecode = [0x88, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_implied_address_mode_inc_instruction() {
     let bytecode = [0xee, 0x00, 0x00];
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_zeropage_address_mode_inc_instruction() {
+    let bytecode = [0xe6, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_inc_instruction() {
+    let bytecode = [0xf6, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_inc_instruction() {
+    let bytecode = [0xfe, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_implied_address_mode_inx_instruction() {
     let bytecode = [0xe8, 0x00, 0x00];
@@ -124,12 +432,48 @@ fn should_parse_implied_address_mode_iny_instruction() {
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_accumulator_address_mode_lsr_instruction() {
+    let bytecode = [0x4a, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_lsr_instruction() {
+    let bytecode = [0x46, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_lsr_instruction() {
+    let bytecode = [0x56, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_lsr_instruction() {
+    let bytecode = [0x4e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_lsr_instruction() {
+    let bytecode = [0x5e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_implied_address_mode_nop_instruction() {
     let bytecode = [0xea, 0x00, 0x00];
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_absolute_address_mode_jsr_instruction() {
+    let bytecode = [0x20, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_immediate_address_mode_lda_instruction() {
     let bytecode = [0xa9, 0x12, 0x34];
@@ -238,6 +582,54 @@ fn should_parse_absolute_address_mode_jmp_instruction() {
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_immediate_address_mode_ora_instruction() {
+    let bytecode = [0x09, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_ora_instruction() {
+    let bytecode = [0x05, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_ora_instruction() {
+    let bytecode = [0x15, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_ora_instruction() {
+    let bytecode = [0x0d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_ora_instruction() {
+    let bytecode = [0x1d, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_y_address_mode_ora_instruction() {
+    let bytecode = [0x19, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_x_indexed_indirect_address_mode_ora_instruction() {
+    let bytecode = [0x01, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_indirect_y_indexed_address_mode_ora_instruction() {
+    let bytecode = [0x11, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_implied_address_mode_pha_instruction() {
     let bytecode = [0x48, 0x00, 0x00];
@@ -262,6 +654,126 @@ fn should_parse_implied_address_mode_plp_instruction() {
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_accumulator_address_mode_rol_instruction() {
+    let bytecode = [0x2a, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_rol_instruction() {
+    let bytecode = [0x26, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_rol_instruction() {
+    let bytecode = [0x36, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_rol_instruction() {
+    let bytecode = [0x2e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_rol_instruction() {
+    let bytecode = [0x3e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_accumulator_address_mode_ror_instruction() {
+    let bytecode = [0x6a, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_ror_instruction() {
+    let bytecode = [0x66, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_ror_instruction() {
+    let bytecode = [0x76, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_ror_instruction() {
+    let bytecode = [0x6e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_ror_instruction() {
+    let bytecode = [0x7e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_implied_address_mode_rti_instruction() {
+    let bytecode = [0x40, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_implied_address_mode_rts_instruction() {
+    let bytecode = [0x60, 0x00, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_immediate_address_mode_sbc_instruction() {
+    let bytecode = [0xe9, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_sbc_instruction() {
+    let bytecode = [0xe5, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_sbc_instruction() {
+    let bytecode = [0xf5, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_sbc_instruction() {
+    let bytecode = [0xed, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_x_address_mode_sbc_instruction() {
+    let bytecode = [0xfd, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_indexed_with_y_address_mode_sbc_instruction() {
+    let bytecode = [0xf9, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_x_indexed_indirect_address_mode_sbc_instruction() {
+    let bytecode = [0xe1, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_indirect_y_indexed_address_mode_sbc_instruction() {
+    let bytecode = [0xf1, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_absolute_address_mode_sec_instruction() {
     let bytecode = [0x38, 0x00, 0x00];
@@ -322,6 +834,42 @@ fn should_parse_zeropage_with_x_index_address_mode_sta_instruction() {
     gen_op_parse_assertion!(&bytecode);
 }
 
+#[test]
+fn should_parse_zeropage_address_mode_stx_instruction() {
+    let bytecode = [0x86, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_y_address_mode_stx_instruction() {
+    let bytecode = [0x96, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_stx_instruction() {
+    let bytecode = [0x8e, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_address_mode_sty_instruction() {
+    let bytecode = [0x84, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_zeropage_indexed_with_x_address_mode_sty_instruction() {
+    let bytecode = [0x94, 0x34, 0x00];
+    gen_op_parse_assertion!(&bytecode);
+}
+
+#[test]
+fn should_parse_absolute_address_mode_sty_instruction() {
+    let bytecode = [0x8c, 0x34, 0x12];
+    gen_op_parse_assertion!(&bytecode);
+}
+
 #[test]
 fn should_parse_implied_address_mode_tax_instruction() {
     let bytecode = [0xaa, 0x00, 0x00];
@@ -357,3 +905,46 @@ fn should_parse_implied_address_mode_tya_instruction() {
     let bytecode = [0x98, 0x00, 0x00];
     gen_op_parse_assertion!(&bytecode);
 }
+
+#[test]
+fn should_parse_all_documented_nmos_opcodes() {
+    let opcodes: [u8; 151] = [
+        0x00, 0x01, 0x05, 0x06, 0x08, 0x09, 0x0a, 0x0d, 0x0e, 0x10, 0x11, 0x15, 0x16, 0x18, 0x19,
+        0x1d, 0x1e, 0x20, 0x21, 0x24, 0x25, 0x26, 0x28, 0x29, 0x2a, 0x2c, 0x2d, 0x2e, 0x30, 0x31,
+        0x35, 0x36, 0x38, 0x39, 0x3d, 0x3e, 0x40, 0x41, 0x45, 0x46, 0x48, 0x49, 0x4a, 0x4c, 0x4d,
+        0x4e, 0x50, 0x51, 0x55, 0x56, 0x58, 0x59, 0x5d, 0x5e, 0x60, 0x61, 0x65, 0x66, 0x68, 0x69,
+        0x6a, 0x6c, 0x6d, 0x6e, 0x70, 0x71, 0x75, 0x76, 0x78, 0x79, 0x7d, 0x7e, 0x81, 0x84, 0x85,
+        0x86, 0x88, 0x8a, 0x8c, 0x8d, 0x8e, 0x90, 0x91, 0x94, 0x95, 0x96, 0x98, 0x99, 0x9a, 0x9d,
+        0xa0, 0xa1, 0xa2, 0xa4, 0xa5, 0xa6, 0xa8, 0xa9, 0xaa, 0xac, 0xad, 0xae, 0xb0, 0xb1, 0xb4,
+        0xb5, 0xb6, 0xb8, 0xb9, 0xba, 0xbc, 0xbd, 0xbe, 0xc0, 0xc1, 0xc4, 0xc5, 0xc6, 0xc8, 0xc9,
+        0xca, 0xcc, 0xcd, 0xce, 0xd0, 0xd1, 0xd5, 0xd6, 0xd8, 0xd9, 0xdd, 0xde, 0xe0, 0xe1, 0xe4,
+        0xe5, 0xe6, 0xe8, 0xe9, 0xea, 0xec, 0xed, 0xee, 0xf0, 0xf1, 0xf5, 0xf6, 0xf8, 0xf9, 0xfd,
+        0xfe,
+    ];
+
+    for opcode in opcodes.iter() {
+        assert!(
+            crate::cpu::mos6502::operations::Operation::try_from(&[*opcode, 0x00, 0x00]).is_ok(),
+            "failed to parse opcode {:#04x}",
+            opcode
+        );
+    }
+}
+
+#[test]
+fn should_return_undecodable_opcode_with_its_operands() {
+    use crate::cpu::mos6502::operations::{DecodeError, Operation};
+
+    let err = Operation::try_from(&[0x8b, 0x01, 0x02]).err().unwrap();
+    assert_eq!(
+        DecodeError::UndecodableOpcode {
+            opcode: 0x8b,
+            operands: [0x01, 0x02]
+        },
+        err
+    );
+    assert_eq!(
+        "no operation matches opcode 0x8b with operands 0x01 0x02",
+        (&err as &dyn std::error::Error).to_string()
+    );
+}
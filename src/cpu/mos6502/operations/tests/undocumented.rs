@@ -0,0 +1,301 @@
+use crate::address_map::Addressable;
+use crate::cpu::mos6502::{
+    microcode::HaltReason,
+    operations::{address_mode, mnemonic, Instruction, MOps, Operation},
+    register::{ByteRegisters, GPRegister, GeneralPurpose, ProgramStatusFlags},
+    Generate, MOS6502,
+};
+use crate::cpu::register::Register;
+use std::convert::TryFrom;
+
+/// The opcodes with unstable or analog behavior that remain unsupported.
+const UNSUPPORTED_OPCODES: [u8; 9] = [0x8b, 0x93, 0x9b, 0x9c, 0x9e, 0x9f, 0xab, 0xbb, 0xeb];
+
+#[test]
+fn should_parse_all_stable_undocumented_opcodes() {
+    for opcode in 0x00..=0xffu8 {
+        let parsed = Operation::try_from(&[opcode, 0x00, 0x00]).is_ok();
+        assert_eq!(
+            !UNSUPPORTED_OPCODES.contains(&opcode),
+            parsed,
+            "unexpected parse result for opcode {:#04x}",
+            opcode
+        );
+    }
+}
+
+#[test]
+fn should_parse_undocumented_opcodes_with_correct_cycles_and_offsets() {
+    use crate::cpu::{Cyclable, Offset};
+
+    for (opcode, offset, cycles) in [
+        (0xa7, 2, 3),
+        (0xbf, 3, 4),
+        (0x83, 2, 6),
+        (0xdb, 3, 7),
+        (0xf3, 2, 8),
+        (0x0b, 2, 2),
+        (0x1a, 1, 2),
+        (0x80, 2, 2),
+        (0x04, 2, 3),
+        (0xf4, 2, 4),
+        (0x0c, 3, 4),
+        (0xfc, 3, 4),
+        (0x02, 0, 2),
+    ]
+    .iter()
+    .copied()
+    {
+        let op = Operation::try_from(&[opcode, 0x00, 0x00]).unwrap();
+        assert_eq!(
+            (offset, cycles),
+            (op.offset(), op.cycles()),
+            "{:#04x}",
+            opcode
+        );
+    }
+}
+
+// DCP
+
+#[test]
+fn should_generate_zeropage_address_mode_dcp_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0a, 0x11).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::DCP, address_mode::ZeroPage(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            5,
+            vec![
+                gen_write_memory_microcode!(0x0a, 0x10),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        )
+        .with_microcode_on_cycle(4, vec![gen_write_memory_microcode!(0x0a, 0x11)]),
+        mc
+    );
+}
+
+// ISC
+
+#[test]
+fn should_generate_absolute_address_mode_isc_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x10));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x04).unwrap();
+
+    let op: Operation = Instruction::new(mnemonic::ISC, address_mode::Absolute(0x000a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            3,
+            6,
+            vec![
+                gen_write_memory_microcode!(0x0a, 0x05),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x0b),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0a, 0x04)]),
+        mc
+    );
+}
+
+// JAM
+
+#[test]
+fn should_generate_implied_address_mode_jam_machine_code() {
+    let cpu = MOS6502::default();
+
+    let op: Operation = Instruction::new(mnemonic::JAM, address_mode::Implied).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(0, 2, vec![gen_halt_microcode!(HaltReason::Jam)]),
+        mc
+    );
+}
+
+// LAX
+
+#[test]
+fn should_generate_zeropage_indexed_with_y_address_mode_lax_machine_code() {
+    let mut cpu =
+        MOS6502::default().with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0f, 0x80).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::LAX, address_mode::ZeroPageIndexedWithY(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            4,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x80),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, 0x80),
+            ]
+        ),
+        mc
+    );
+}
+
+// RLA
+
+#[test]
+fn should_generate_zeropage_indexed_with_x_address_mode_rla_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x0f))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0f, 0x81).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::RLA, address_mode::ZeroPageIndexedWithX(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            6,
+            vec![
+                gen_write_memory_microcode!(0x0f, 0x03),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x03),
+            ]
+        )
+        .with_microcode_on_cycle(5, vec![gen_write_memory_microcode!(0x0f, 0x81)]),
+        mc
+    );
+}
+
+// RRA
+
+#[test]
+fn should_generate_x_indexed_indirect_address_mode_rra_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x10))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x02));
+    cpu.address_map.write(0x0c, 0x20).unwrap();
+    cpu.address_map.write(0x0d, 0x00).unwrap();
+    cpu.address_map.write(0x20, 0x05).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::RRA, address_mode::XIndexedIndirect(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    // 0x05 rotates to 0x02 with the shifted out bit carried into the add.
+    assert_eq!(
+        MOps::new(
+            2,
+            8,
+            vec![
+                gen_write_memory_microcode!(0x20, 0x02),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, false),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x13),
+            ]
+        )
+        .with_microcode_on_cycle(7, vec![gen_write_memory_microcode!(0x20, 0x05)]),
+        mc
+    );
+}
+
+// SAX
+
+#[test]
+fn should_generate_absolute_address_mode_sax_machine_code() {
+    let cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x3c))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x0f));
+
+    let op: Operation = Instruction::new(mnemonic::SAX, address_mode::Absolute(0x00ff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(3, 4, vec![gen_write_memory_microcode!(0xff, 0x0c)]),
+        mc
+    );
+}
+
+// SLO
+
+#[test]
+fn should_generate_absolute_indexed_with_y_address_mode_slo_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x01))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0f, 0xc0).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::SLO, address_mode::AbsoluteIndexedWithY(0x000a)).into();
+    let mc = op.generate(&cpu);
+
+    // read-modify-write instructions never incur a page boundary penalty.
+    assert_eq!(
+        MOps::new(
+            3,
+            7,
+            vec![
+                gen_write_memory_microcode!(0x0f, 0x80),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0x81),
+            ]
+        )
+        .with_microcode_on_cycle(6, vec![gen_write_memory_microcode!(0x0f, 0xc0)]),
+        mc
+    );
+}
+
+// SRE
+
+#[test]
+fn should_generate_indirect_y_indexed_address_mode_sre_machine_code() {
+    let mut cpu = MOS6502::default()
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x20).unwrap();
+    cpu.address_map.write(0x0b, 0x00).unwrap();
+    cpu.address_map.write(0x25, 0x03).unwrap();
+
+    let op: Operation =
+        Instruction::new(mnemonic::SRE, address_mode::IndirectYIndexed(0x0a)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        MOps::new(
+            2,
+            8,
+            vec![
+                gen_write_memory_microcode!(0x25, 0x01),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, 0xfe),
+            ]
+        )
+        .with_microcode_on_cycle(7, vec![gen_write_memory_microcode!(0x25, 0x03)]),
+        mc
+    );
+}
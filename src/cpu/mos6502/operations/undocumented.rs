@@ -0,0 +1,1542 @@
+//! The stable undocumented opcodes of the NMOS 6502. These decode to
+//! instructions built from the same microcode as the documented set and are
+//! only available with the `illegal-opcodes` feature enabled.
+
+use super::{
+    add_index_to_address, add_index_to_zeropage_address, add_with_carry_in, address_mode,
+    dereference_address_to_operand, dereference_indexed_indirect_address,
+    dereference_indirect_indexed_address, mnemonic, subtract_with_borrow, Instruction, MOps,
+    Operand, Operation, Page,
+};
+use crate::address_map::Addressable;
+use crate::cpu::{
+    mos6502::{microcode::HaltReason, register::*, Generate, MOS6502},
+    register::Register,
+    Cyclable, Offset,
+};
+use parcel::{parsers::byte::expect_byte, BoxedParser, ParseResult, Parser};
+
+/// Returns parsers for each of the undocumented operations, for inclusion
+/// in the OperationParser.
+pub(super) fn operation_parsers<'a>() -> Vec<BoxedParser<'a, &'a [u8], Operation>> {
+    vec![
+        inst_to_operation!(mnemonic::ALR, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::ANC, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::ARR, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::DCP, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::ISC, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::JAM, address_mode::Implied),
+        inst_to_operation!(mnemonic::LAX, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::LAX, address_mode::ZeroPageIndexedWithY::default()),
+        inst_to_operation!(mnemonic::LAX, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::LAX, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::LAX, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::LAX, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::NOP, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::NOP, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::NOP, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::NOP, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::NOP, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(ImpliedNop),
+        inst_to_operation!(mnemonic::RLA, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RLA, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::RLA, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::RLA, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::RLA, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::RLA, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::RLA, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::RRA, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::SAX, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SAX, address_mode::ZeroPageIndexedWithY::default()),
+        inst_to_operation!(mnemonic::SAX, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::SAX, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::SBX, address_mode::Immediate::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::SLO, address_mode::IndirectYIndexed::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::ZeroPage::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::ZeroPageIndexedWithX::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::Absolute::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::AbsoluteIndexedWithX::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::AbsoluteIndexedWithY::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::XIndexedIndirect::default()),
+        inst_to_operation!(mnemonic::SRE, address_mode::IndirectYIndexed::default()),
+    ]
+}
+
+/// Parses the single byte undocumented NOPs into the same instruction as the
+/// documented `nop`, which they are identical to.
+struct ImpliedNop;
+
+impl<'a> Parser<'a, &'a [u8], Instruction<mnemonic::NOP, address_mode::Implied>> for ImpliedNop {
+    fn parse(
+        &self,
+        input: &'a [u8],
+    ) -> ParseResult<&'a [u8], Instruction<mnemonic::NOP, address_mode::Implied>> {
+        parcel::one_of(vec![
+            expect_byte(0x1a),
+            expect_byte(0x3a),
+            expect_byte(0x5a),
+            expect_byte(0x7a),
+            expect_byte(0xda),
+            expect_byte(0xfa),
+        ])
+        .map(|_| Instruction::new(mnemonic::NOP, address_mode::Implied))
+        .parse(input)
+    }
+}
+
+// ALR
+
+gen_instruction_cycles_and_parser!(mnemonic::ALR, address_mode::Immediate, 0x4b, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ALR, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.acc.read() & self.address_mode.unwrap();
+        let carry = value & 0x01 != 0;
+        let result = Operand::new(value >> 1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// ANC
+
+gen_instruction_cycles_and_parser!(mnemonic::ANC, address_mode::Immediate, [0x0b, 0x2b], 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ANC, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let result = Operand::new(cpu.acc.read() & self.address_mode.unwrap());
+
+        // carry is set from bit 7 of the result, as if it had been shifted.
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// ARR
+
+gen_instruction_cycles_and_parser!(mnemonic::ARR, address_mode::Immediate, 0x6b, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ARR, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let value = cpu.acc.read() & self.address_mode.unwrap();
+        let carry_in = cpu.ps.carry;
+        let rotated = (value >> 1) | ((carry_in as u8) << 7);
+        let zero = rotated == 0;
+
+        let (result, carry, negative, overflow) = if cpu.decimal_mode() {
+            // N reflects the carry in and V a change of bit 6, with each
+            // nibble of the rotated value then adjusted as packed BCD.
+            let overflow = (value ^ rotated) & 0x40 != 0;
+            let mut result = rotated;
+            if (value & 0x0f) + (value & 0x01) > 0x05 {
+                result = (result & 0xf0) | (result.wrapping_add(0x06) & 0x0f);
+            }
+
+            let carry = (value & 0xf0) as u16 + (value & 0x10) as u16 > 0x50;
+            if carry {
+                result = result.wrapping_add(0x60);
+            }
+
+            (result, carry, carry_in, overflow)
+        } else {
+            let carry = rotated & 0x40 != 0;
+            let overflow = ((rotated >> 6) ^ (rotated >> 5)) & 0x01 != 0;
+
+            (rotated, carry, rotated & 0x80 != 0, overflow)
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, overflow),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result),
+            ],
+        )
+    }
+}
+
+// DCP
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::ZeroPage, 0xc7, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::ZeroPageIndexedWithX, 0xd7, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::Absolute, 0xcf, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::AbsoluteIndexedWithX, 0xdf, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::AbsoluteIndexedWithY, 0xdb, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::XIndexedIndirect, 0xc3, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::DCP, address_mode::IndirectYIndexed, 0xd3, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::DCP, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_sub(1);
+        let acc = cpu.acc.read();
+        let diff = Operand::new(acc.wrapping_sub(result));
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, acc >= result),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, diff.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, diff.zero),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// ISC
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::ZeroPage, 0xe7, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::ZeroPageIndexedWithX, 0xf7, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::Absolute, 0xef, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::AbsoluteIndexedWithX, 0xff, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::AbsoluteIndexedWithY, 0xfb, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::XIndexedIndirect, 0xe3, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::ISC, address_mode::IndirectYIndexed, 0xf3, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::ISC, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let result = value.wrapping_add(1);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, result)]
+                .into_iter()
+                .chain(subtract_with_borrow(cpu, Operand::new(result)))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// JAM
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::JAM,
+    address_mode::Implied,
+    [0x02, 0x12, 0x22, 0x32, 0x42, 0x52, 0x62, 0x72, 0x92, 0xb2, 0xd2, 0xf2],
+    2
+);
+
+// JAM has no offset, leaving the program counter, and the pc of the reported
+// fault, on the jam opcode.
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::JAM, address_mode::Implied> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_halt_microcode!(HaltReason::Jam)],
+        )
+    }
+}
+
+// LAX
+
+gen_instruction_cycles_and_parser!(mnemonic::LAX, address_mode::ZeroPage, 0xa7, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LAX, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LAX, address_mode::ZeroPageIndexedWithY, 0xb7, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LAX, address_mode::ZeroPageIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LAX, address_mode::Absolute, 0xaf, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LAX, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LAX, address_mode::AbsoluteIndexedWithY, 0xbf, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LAX, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LAX, address_mode::XIndexedIndirect, 0xa3, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LAX, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = dereference_address_to_operand(cpu, addr, 0);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::LAX, address_mode::IndirectYIndexed, 0xb3, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::LAX, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let base_addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), 0);
+        let indexed_addr = add_index_to_address(base_addr, index);
+        let value = dereference_address_to_operand(cpu, indexed_addr, 0);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(
+            self.offset(),
+            self.cycles() + page_penalty,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, value.unwrap()),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, value.unwrap()),
+            ],
+        )
+    }
+}
+
+// NOP
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::NOP,
+    address_mode::Immediate,
+    [0x80, 0x82, 0x89, 0xc2, 0xe2],
+    2
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::Immediate> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::NOP, address_mode::ZeroPage, [0x04, 0x44, 0x64], 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::ZeroPage> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::NOP,
+    address_mode::ZeroPageIndexedWithX,
+    [0x14, 0x34, 0x54, 0x74, 0xd4, 0xf4],
+    4
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::NOP, address_mode::Absolute, 0x0c, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::Absolute> {
+    fn generate(self, _: &MOS6502) -> MOps {
+        MOps::new(self.offset(), self.cycles(), vec![])
+    }
+}
+
+gen_instruction_cycles_and_parser!(
+    mnemonic::NOP,
+    address_mode::AbsoluteIndexedWithX,
+    [0x1c, 0x3c, 0x5c, 0x7c, 0xdc, 0xfc],
+    4
+);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::NOP, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let base_addr = self.address_mode.unwrap();
+        let indexed_addr = add_index_to_address(base_addr, index);
+
+        // if the indexed address crosses a page boundary pay a 1 cycle penalty.
+        let page_penalty = if !Page::from(base_addr).contains(indexed_addr) {
+            1
+        } else {
+            0
+        };
+
+        MOps::new(self.offset(), self.cycles() + page_penalty, vec![])
+    }
+}
+
+// RLA
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::ZeroPage, 0x27, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::ZeroPageIndexedWithX, 0x37, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::Absolute, 0x2f, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::AbsoluteIndexedWithX, 0x3f, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::AbsoluteIndexedWithY, 0x3b, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::XIndexedIndirect, 0x23, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RLA, address_mode::IndirectYIndexed, 0x33, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RLA, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let rotated = (value << 1) | cpu.ps.carry as u8;
+        let result = Operand::new(cpu.acc.read() & rotated);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, rotated),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// RRA
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::ZeroPage, 0x67, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::ZeroPageIndexedWithX, 0x77, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::Absolute, 0x6f, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::AbsoluteIndexedWithX, 0x7f, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::AbsoluteIndexedWithY, 0x7b, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::XIndexedIndirect, 0x63, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::RRA, address_mode::IndirectYIndexed, 0x73, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::RRA, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let rotated = (value >> 1) | ((cpu.ps.carry as u8) << 7);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, rotated)]
+                .into_iter()
+                .chain(add_with_carry_in(cpu, Operand::new(rotated), carry))
+                .collect(),
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// SAX
+
+gen_instruction_cycles_and_parser!(mnemonic::SAX, address_mode::ZeroPage, 0x87, 3);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SAX, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.acc.read() & cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SAX, address_mode::ZeroPageIndexedWithY, 0x97, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SAX, address_mode::ZeroPageIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.acc.read() & cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SAX, address_mode::Absolute, 0x8f, 4);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SAX, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.acc.read() & cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SAX, address_mode::XIndexedIndirect, 0x83, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SAX, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.acc.read() & cpu.x.read();
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![gen_write_memory_microcode!(addr, value)],
+        )
+    }
+}
+
+// SBX
+
+gen_instruction_cycles_and_parser!(mnemonic::SBX, address_mode::Immediate, 0xcb, 2);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SBX, address_mode::Immediate> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let lhs = cpu.acc.read() & cpu.x.read();
+        let rhs = self.address_mode.unwrap();
+        let result = Operand::new(lhs.wrapping_sub(rhs));
+
+        // the subtraction ignores both the carry in and decimal mode.
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, lhs >= rhs),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::X, result.unwrap()),
+            ],
+        )
+    }
+}
+
+// SLO
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::ZeroPage, 0x07, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::ZeroPageIndexedWithX, 0x17, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::Absolute, 0x0f, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::AbsoluteIndexedWithX, 0x1f, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::AbsoluteIndexedWithY, 0x1b, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::XIndexedIndirect, 0x03, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SLO, address_mode::IndirectYIndexed, 0x13, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SLO, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x80 != 0;
+        let shifted = value << 1;
+        let result = Operand::new(cpu.acc.read() | shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+// SRE
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::ZeroPage, 0x47, 5);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::ZeroPage> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap() as u16;
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::ZeroPageIndexedWithX, 0x57, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::ZeroPageIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_zeropage_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::Absolute, 0x4f, 6);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::Absolute> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let addr = self.address_mode.unwrap();
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::AbsoluteIndexedWithX, 0x5f, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::AbsoluteIndexedWithX> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::AbsoluteIndexedWithY, 0x5b, 7);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::AbsoluteIndexedWithY> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = add_index_to_address(self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::XIndexedIndirect, 0x43, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::XIndexedIndirect> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.x.read();
+        let addr = dereference_indexed_indirect_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
+
+gen_instruction_cycles_and_parser!(mnemonic::SRE, address_mode::IndirectYIndexed, 0x53, 8);
+
+impl Generate<MOS6502, MOps> for Instruction<mnemonic::SRE, address_mode::IndirectYIndexed> {
+    fn generate(self, cpu: &MOS6502) -> MOps {
+        let index = cpu.y.read();
+        let addr = dereference_indirect_indexed_address(cpu, self.address_mode.unwrap(), index);
+        let value = cpu.address_map.read(addr);
+        let carry = value & 0x01 != 0;
+        let shifted = value >> 1;
+        let result = Operand::new(cpu.acc.read() ^ shifted);
+
+        MOps::new(
+            self.offset(),
+            self.cycles(),
+            vec![
+                gen_write_memory_microcode!(addr, shifted),
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, result.negative),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, result.zero),
+                gen_write_8bit_register_microcode!(ByteRegisters::ACC, result.unwrap()),
+            ],
+        )
+        .with_dummy_write(cpu, addr, value)
+    }
+}
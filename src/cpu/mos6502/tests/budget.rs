@@ -0,0 +1,145 @@
+use super::generate_test_cpu_with_instructions;
+use crate::address_map::Addressable;
+use crate::cpu::{mos6502::MOS6502, register::Register, StepState, CPU};
+
+/// Generates a test cpu running a loop that exercises loads, stores,
+/// read-modify-writes, the stack and jumps of varying lengths.
+fn generate_test_cpu_with_loop() -> MOS6502 {
+    let mut program = vec![
+        0xa2, 0x00, // ldx #$00
+        0xe8, // inx
+        0x86, 0x10, // stx $10
+        0xe6, 0x20, // inc $20
+        0xa5, 0x20, // lda $20
+        0x20, 0x10, 0x60, // jsr $6010
+        0x4c, 0x02, 0x60, // jmp $6002
+    ];
+    program.resize(0x10, 0xea);
+    program.extend(vec![
+        0x48, // pha
+        0x0a, // asl a
+        0x68, // pla
+        0x60, // rts
+    ]);
+
+    generate_test_cpu_with_instructions(program)
+}
+
+fn assert_cpus_eq(expected: &MOS6502, actual: &MOS6502) {
+    assert_eq!(expected.pc, actual.pc);
+    assert_eq!(expected.acc, actual.acc);
+    assert_eq!(expected.x, actual.x);
+    assert_eq!(expected.y, actual.y);
+    assert_eq!(expected.sp, actual.sp);
+    assert_eq!(expected.ps, actual.ps);
+    assert_eq!(expected.cycles(), actual.cycles());
+    assert_eq!(expected.retired(), actual.retired());
+    assert_eq!(expected.in_flight(), actual.in_flight());
+    for addr in 0x0000..=0x01ff {
+        assert_eq!(
+            expected.address_map.read(addr),
+            actual.address_map.read(addr)
+        );
+    }
+}
+
+#[test]
+fn should_match_a_single_run_when_run_one_cycle_at_a_time() {
+    let cpu = generate_test_cpu_with_loop();
+
+    let expected = cpu.clone().run(1000).unwrap();
+    let actual = (0..1000)
+        .fold(StepState::from(cpu), |state, _| state.run(1))
+        .unwrap();
+
+    assert_eq!(1000, actual.cycles());
+    assert_cpus_eq(&expected, &actual);
+}
+
+#[test]
+fn should_match_a_single_run_when_run_in_uneven_budgets() {
+    let cpu = generate_test_cpu_with_loop();
+
+    let expected = cpu.clone().run(1000).unwrap();
+    let actual = [3, 7, 1, 2, 5, 11, 971]
+        .iter()
+        .fold(StepState::from(cpu), |state, &cycles| state.run(cycles))
+        .unwrap();
+
+    assert_cpus_eq(&expected, &actual);
+}
+
+#[test]
+fn should_consume_exactly_the_cycle_budget() {
+    let cpu = generate_test_cpu_with_loop();
+
+    for cycles in 0..64 {
+        let state = cpu.clone().run(cycles).unwrap();
+        assert_eq!(cycles, state.cycles());
+    }
+}
+
+#[test]
+fn should_report_cycles_remaining_in_flight() {
+    // lda $6000
+    let cpu = generate_test_cpu_with_instructions(vec![0xad, 0x00, 0x60]);
+
+    let (remaining, state) = cpu.run(1).into();
+    assert_eq!(3, remaining);
+    assert_eq!(0x6000, state.pc.read());
+
+    let (remaining, state) = state.run(3).into();
+    assert_eq!(0, remaining);
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xad, state.acc.read());
+}
+
+#[test]
+fn should_count_instructions_retired() {
+    let cpu = generate_test_cpu_with_instructions(vec![]);
+
+    let state = cpu.clone().run(10).unwrap();
+    assert_eq!(5, state.retired());
+
+    // the fifth nop is still in flight.
+    let state = cpu.run(9).unwrap();
+    assert_eq!(4, state.retired());
+    assert_eq!(1, state.in_flight());
+}
+
+#[test]
+fn should_complete_read_modify_write_after_resuming() {
+    // inc $10
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe6, 0x10]);
+    cpu.address_map.write(0x0010, 0x7f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x7f, state.address_map.read(0x0010));
+
+    let state = state.run(1).unwrap();
+    assert_eq!(0x80, state.address_map.read(0x0010));
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn should_continue_writes_but_halt_on_reads_while_rdy_is_low() {
+    // jsr $6010
+    let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x10, 0x60]);
+
+    // the pushes on cycles four and five proceed while the final read halts.
+    let state = cpu.run(3).unwrap().with_rdy_line(false).run(10).unwrap();
+    assert_eq!(
+        (0x60, 0x02),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+    assert_eq!(0x6000, state.pc.read());
+    assert_eq!(1, state.in_flight());
+    assert_eq!(13, state.cycles());
+
+    let state = state.with_rdy_line(true).run(1).unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(1, state.retired());
+}
@@ -0,0 +1,267 @@
+use super::generate_test_cpu_with_instructions;
+use crate::address_map::{Addressable, WriteError};
+use crate::cpu::{
+    mos6502::{
+        microcode::Microcode,
+        register::{self, GPRegister},
+        Variant, MOS6502,
+    },
+    register::Register,
+    CPU,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A shared log of the writes made to a device, in the order they occurred.
+type WriteLog = Rc<RefCell<Vec<(u16, u8)>>>;
+
+/// A device that records every write it receives, allowing a test to observe
+/// bus activity in the order that it occurred.
+#[derive(Clone)]
+struct RecordingDevice {
+    writes: WriteLog,
+    inner: [u8; 0x100],
+}
+
+impl Default for RecordingDevice {
+    fn default() -> Self {
+        Self {
+            writes: Rc::new(RefCell::new(Vec::new())),
+            inner: [0; 0x100],
+        }
+    }
+}
+
+impl Addressable<u16> for RecordingDevice {
+    fn read(&self, offset: u16) -> u8 {
+        self.inner[(offset - 0x0200) as usize]
+    }
+
+    fn write(&mut self, offset: u16, data: u8) -> Result<u8, WriteError<u16>> {
+        self.writes.borrow_mut().push((offset, data));
+        self.inner[(offset - 0x0200) as usize] = data;
+        Ok(data)
+    }
+}
+
+fn generate_test_cpu_with_recorder(opcodes: Vec<u8>) -> (MOS6502, WriteLog) {
+    let device = RecordingDevice::default();
+    let writes = device.writes.clone();
+    let cpu = generate_test_cpu_with_instructions(opcodes)
+        .register_address_space(0x0200..=0x02ff, device)
+        .unwrap();
+
+    (cpu, writes)
+}
+
+#[test]
+fn should_write_unmodified_value_before_result_on_read_modify_write() {
+    let (mut cpu, writes) = generate_test_cpu_with_recorder(vec![0xee, 0x00, 0x02]);
+    cpu.address_map.write(0x0200, 0x7f).unwrap();
+    writes.borrow_mut().clear();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x80, state.address_map.read(0x0200));
+    assert_eq!(vec![(0x0200, 0x7f), (0x0200, 0x80)], *writes.borrow());
+}
+
+#[test]
+fn should_write_result_of_read_modify_write_on_final_cycle() {
+    let (mut cpu, writes) = generate_test_cpu_with_recorder(vec![0x0e, 0x00, 0x02]);
+    cpu.address_map.write(0x0200, 0x81).unwrap();
+    writes.borrow_mut().clear();
+
+    // stopping prior to the final cycle leaves only the unmodified write.
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x81, state.address_map.read(0x0200));
+    assert_eq!(vec![(0x0200, 0x81)], *writes.borrow());
+}
+
+#[test]
+fn should_push_return_address_on_cycles_four_and_five_of_jsr() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x10, 0x60]);
+
+    let state = cpu.clone().run(3).unwrap();
+    assert_eq!(
+        (0x00, 0x00),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+
+    let state = cpu.clone().run(4).unwrap();
+    assert_eq!(
+        (0x60, 0x00),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(
+        (0x60, 0x02),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+    assert_eq!(0x6000, state.pc.read());
+}
+
+/// Returns the reads and writes made on each cycle of the next instruction.
+fn bus_accesses_by_cycle(cpu: MOS6502) -> Vec<Vec<Microcode>> {
+    let steps: Vec<Vec<Microcode>> = cpu.into_iter().next().unwrap().into();
+
+    steps
+        .into_iter()
+        .map(|step| {
+            step.into_iter()
+                .filter(|mc| matches!(mc, Microcode::ReadMemory(_) | Microcode::WriteMemory(_)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn should_read_uncarried_address_before_store_on_sta_absolute_indexed_with_x() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x9d, 0xf0, 0x02])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x55))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x20));
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x0210)],
+            vec![gen_write_memory_microcode!(0x0310, 0x55)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_read_uncarried_address_on_page_cross_of_lda_absolute_indexed_with_x() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xbd, 0xf0, 0x02])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x20));
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x0210)],
+            vec![gen_read_memory_microcode!(0x0310)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_not_read_uncarried_address_without_page_cross_of_lda_absolute_indexed_with_x() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xbd, 0x00, 0x02])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x20));
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x0220)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_read_before_modifying_on_inc_absolute_indexed_with_x() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xfe, 0xf8, 0x00])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0108, 0x7f).unwrap();
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x0008)],
+            vec![gen_read_memory_microcode!(0x0108)],
+            vec![gen_write_memory_microcode!(0x0108, 0x7f)],
+            vec![gen_write_memory_microcode!(0x0108, 0x80)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_read_pointer_and_operand_on_separate_cycles_of_x_indexed_indirect() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xa1, 0x10])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x04));
+    cpu.address_map.write(0x14, 0x00).unwrap();
+    cpu.address_map.write(0x15, 0x02).unwrap();
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x0010)],
+            vec![gen_read_memory_microcode!(0x0014)],
+            vec![gen_read_memory_microcode!(0x0015)],
+            vec![gen_read_memory_microcode!(0x0200)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_read_following_byte_on_implied_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xe8]);
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_read_stack_on_each_cycle_of_rts() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x60])
+        .with_sp_register(register::StackPointer::with_value(0xfd));
+    cpu.address_map.write(0x01fe, 0x02).unwrap();
+    cpu.address_map.write(0x01ff, 0x60).unwrap();
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x01fd)],
+            vec![gen_read_memory_microcode!(0x01fe)],
+            vec![gen_read_memory_microcode!(0x01ff)],
+            vec![gen_read_memory_microcode!(0x6002)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
+
+#[test]
+fn should_reread_last_instruction_byte_on_page_cross_of_65c02_indexed_load() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xbd, 0xf0, 0x02])
+        .with_variant(Variant::W65C02)
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x20));
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x0310)],
+        ],
+        bus_accesses_by_cycle(cpu)
+    );
+}
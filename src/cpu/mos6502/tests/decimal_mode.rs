@@ -0,0 +1,174 @@
+//! An exhaustive verification of ADC and SBC in decimal mode, ported from
+//! Bruce Clark's "Decimal Mode" tutorial test program. Every combination of
+//! operands and carry is executed and the accumulator and flags are compared
+//! against the values predicted for the NMOS 6502.
+
+use crate::cpu::{
+    mos6502::{
+        microcode::Microcode,
+        operations::{address_mode, mnemonic, Instruction, Operation},
+        register::GeneralPurpose,
+        Execute, Generate, MOS6502,
+    },
+    register::Register,
+};
+
+/// The accumulator and the N, V, Z and C flags following an operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Outcome {
+    acc: u8,
+    negative: bool,
+    overflow: bool,
+    zero: bool,
+    carry: bool,
+}
+
+/// Executes a single immediate mode operation against the cpu, returning the
+/// cpu and the resulting accumulator and flag state.
+fn execute(
+    mut cpu: MOS6502,
+    op: Operation,
+    n1: u8,
+    carry: bool,
+    decimal: bool,
+) -> (MOS6502, Outcome) {
+    cpu.acc = GeneralPurpose::with_value(n1);
+    cpu.ps.carry = carry;
+    cpu.ps.decimal = decimal;
+
+    let mops = op.generate(&cpu);
+    let cpu = Into::<Vec<Vec<Microcode>>>::into(mops)
+        .into_iter()
+        .flatten()
+        .fold(cpu, |cpu, mc| mc.execute(cpu));
+    let outcome = Outcome {
+        acc: cpu.acc.read(),
+        negative: cpu.ps.negative,
+        overflow: cpu.ps.overflow,
+        zero: cpu.ps.zero,
+        carry: cpu.ps.carry,
+    };
+
+    (cpu, outcome)
+}
+
+/// A binary add with carry returning the sum, carry, negative and overflow
+/// results, used to step through the prediction routines.
+fn binary_add(lhs: u8, rhs: u8, carry: bool) -> (u8, bool, bool, bool) {
+    let sum = lhs as u16 + rhs as u16 + carry as u16;
+    let result = sum as u8;
+    let overflow = ((lhs ^ result) & (rhs ^ result) & 0x80) != 0;
+
+    (result, sum > 0xff, result & 0x80 != 0, overflow)
+}
+
+/// A binary subtract with borrow returning the difference and carry.
+fn binary_subtract(lhs: u8, rhs: u8, carry: bool) -> (u8, bool) {
+    let (result, carry, _, _) = binary_add(lhs, !rhs, carry);
+
+    (result, carry)
+}
+
+/// Predicts the ADC accumulator, carry and V results, with the N flag taken
+/// from the same intermediate step as V. Corresponds to the ADD routine.
+fn predict_adc(n1: u8, n2: u8, carry: bool) -> (u8, bool, bool, bool) {
+    let n2h = [n2 & 0xf0, (n2 & 0xf0).wrapping_add(0x0f)];
+
+    let (mut acc, _, _, _) = binary_add(n1 & 0x0f, n2 & 0x0f, carry);
+    let mut x = 0;
+    let mut carry = acc >= 0x0a;
+    if carry {
+        x = 1;
+        acc = binary_add(acc, 0x05, true).0 & 0x0f;
+        carry = true;
+    }
+    acc |= n1 & 0xf0;
+
+    let (sum, c, negative, overflow) = binary_add(acc, n2h[x], carry);
+    let (acc, carry) = if c || sum >= 0xa0 {
+        (binary_add(sum, 0x5f, true).0, true)
+    } else {
+        (sum, false)
+    };
+
+    (acc, carry, negative, overflow)
+}
+
+/// Predicts the SBC accumulator result. Corresponds to the SUB1 routine.
+fn predict_sbc(n1: u8, n2: u8, carry: bool) -> u8 {
+    let n2h = [n2 & 0xf0, (n2 & 0xf0).wrapping_add(0x0f)];
+
+    let (mut acc, mut carry) = binary_subtract(n1 & 0x0f, n2 & 0x0f, carry);
+    let mut x = 0;
+    if !carry {
+        x = 1;
+        acc = binary_subtract(acc, 0x05, false).0 & 0x0f;
+        carry = false;
+    }
+    acc |= n1 & 0xf0;
+
+    let (acc, carry) = binary_subtract(acc, n2h[x], carry);
+    if carry {
+        acc
+    } else {
+        binary_subtract(acc, 0x5f, false).0
+    }
+}
+
+#[test]
+fn should_match_predicted_results_for_all_decimal_mode_adc_operands() {
+    let mut cpu = MOS6502::default();
+
+    for carry in [false, true].iter().copied() {
+        for n1 in 0..=255u8 {
+            for n2 in 0..=255u8 {
+                let op = || Instruction::new(mnemonic::ADC, address_mode::Immediate(n2)).into();
+                let (c, decimal) = execute(cpu, op(), n1, carry, true);
+                let (c, binary) = execute(c, op(), n1, carry, false);
+                cpu = c;
+
+                let (acc, carry_out, negative, overflow) = predict_adc(n1, n2, carry);
+                let predicted = Outcome {
+                    acc,
+                    negative,
+                    overflow,
+                    zero: binary.zero,
+                    carry: carry_out,
+                };
+
+                assert_eq!(
+                    predicted, decimal,
+                    "ADC {:#04x} + {:#04x} with carry {}",
+                    n1, n2, carry
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn should_match_predicted_results_for_all_decimal_mode_sbc_operands() {
+    let mut cpu = MOS6502::default();
+
+    for carry in [false, true].iter().copied() {
+        for n1 in 0..=255u8 {
+            for n2 in 0..=255u8 {
+                let op = || Instruction::new(mnemonic::SBC, address_mode::Immediate(n2)).into();
+                let (c, decimal) = execute(cpu, op(), n1, carry, true);
+                let (c, binary) = execute(c, op(), n1, carry, false);
+                cpu = c;
+
+                let predicted = Outcome {
+                    acc: predict_sbc(n1, n2, carry),
+                    ..binary
+                };
+
+                assert_eq!(
+                    predicted, decimal,
+                    "SBC {:#04x} - {:#04x} with carry {}",
+                    n1, n2, carry
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use super::generate_test_cpu_with_instructions;
+use crate::address_map::WriteError;
+use crate::cpu::{
+    mos6502::{
+        fault::{CpuFault, Fault, FaultPolicy},
+        operations::DecodeError,
+        register::{GPRegister, GeneralPurpose, ProgramCounter},
+        MOS6502,
+    },
+    register::Register,
+    CPU,
+};
+
+#[test]
+fn should_return_cycles_taken_when_stepping_an_instruction() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xad, 0x00, 0x60]);
+
+    assert_eq!(Ok(4), cpu.try_step());
+    assert_eq!(0x6003, cpu.pc.read());
+    assert_eq!(4, cpu.cycles());
+}
+
+/// Returns the fault raised on an illegal opcode followed by nops.
+fn illegal_opcode(opcode: u8) -> CpuFault {
+    CpuFault::IllegalOpcode(DecodeError::UndecodableOpcode {
+        opcode,
+        operands: [0xea, 0xea],
+    })
+}
+
+#[test]
+fn should_halt_on_illegal_opcode() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xea, 0x8b]);
+
+    assert_eq!(Ok(2), cpu.try_step());
+    assert_eq!(
+        Err(Fault::new(illegal_opcode(0x8b), 0x6001, 2)),
+        cpu.try_step()
+    );
+
+    // a halted cpu should not advance.
+    let state = cpu.run(10).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(2, state.cycles());
+    assert_eq!(
+        Some(&Fault::new(illegal_opcode(0x8b), 0x6001, 2)),
+        state.halted()
+    );
+}
+
+#[test]
+fn should_skip_illegal_opcode_when_ignoring_faults() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x8b, 0xea])
+        .with_fault_policy(FaultPolicy::Ignore);
+
+    assert_eq!(
+        Err(Fault::new(illegal_opcode(0x8b), 0x6000, 0)),
+        cpu.try_step()
+    );
+    assert_eq!(0x6001, cpu.pc.read());
+    assert_eq!(2, cpu.cycles());
+    assert!(cpu.halted().is_none());
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn should_invoke_trap_handler_on_illegal_opcode() {
+    fn handler(cpu: MOS6502, fault: Fault) -> MOS6502 {
+        let opcode = match fault.kind {
+            CpuFault::IllegalOpcode(err) => err.opcode(),
+            _ => 0x00,
+        };
+
+        cpu.with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(opcode))
+            .with_pc_register(ProgramCounter::with_value(fault.pc + 1))
+    }
+
+    let cpu = generate_test_cpu_with_instructions(vec![0x8b, 0xea])
+        .with_fault_policy(FaultPolicy::Trap(handler));
+
+    // a single cycle for the trapped fetch and two for the nop.
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x8b, state.acc.read());
+    assert!(state.halted().is_none());
+}
+
+#[test]
+fn should_halt_on_write_to_read_only_memory() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0x8d, 0x00, 0x60, 0xea]);
+
+    let state = cpu.run(10).unwrap();
+    assert_eq!(6, state.cycles());
+    assert_eq!(
+        Some(&Fault::new(
+            CpuFault::BusWrite {
+                address: 0x6000,
+                value: 0xff,
+                reason: WriteError::ReadOnly {
+                    address: 0x6000,
+                    value: 0xff
+                }
+            },
+            0x6002,
+            5
+        )),
+        state.halted()
+    );
+}
+
+#[test]
+fn should_drop_failed_write_when_ignoring_faults() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0x8d, 0x00, 0x80, 0xea])
+        .with_fault_policy(FaultPolicy::Ignore);
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!(0x6006, state.pc.read());
+    assert!(state.halted().is_none());
+}
+
+#[test]
+fn should_fault_on_write_to_unmapped_address() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x8d, 0x00, 0x80]);
+
+    assert_eq!(
+        Err(Fault::new(CpuFault::UnmappedAccess(0x8000), 0x6000, 3)),
+        cpu.try_step()
+    );
+}
+
+#[test]
+fn should_fault_on_fetch_from_unmapped_address() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![])
+        .with_pc_register(ProgramCounter::with_value(0x8000));
+
+    assert_eq!(
+        Err(Fault::new(CpuFault::UnmappedAccess(0x8000), 0x8000, 0)),
+        cpu.try_step()
+    );
+    assert!(cpu.halted().is_some());
+}
+
+#[test]
+fn should_expose_the_write_error_as_the_source_of_a_bus_write_fault() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0x8d, 0x00, 0x60, 0xea]);
+
+    let state = cpu.run(10).unwrap();
+    let fault: &dyn std::error::Error = state.halted().unwrap();
+    let source = fault.source().and_then(|kind| kind.source()).unwrap();
+    assert_eq!(
+        "write of 0xff to read-only address 0x6000",
+        source.to_string()
+    );
+}
+
+#[test]
+fn should_expose_the_decode_error_as_the_source_of_an_illegal_opcode_fault() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x8b]);
+
+    let state = cpu.run(10).unwrap();
+    let fault: &dyn std::error::Error = state.halted().unwrap();
+    let source = fault.source().and_then(|kind| kind.source()).unwrap();
+    assert_eq!("illegal opcode 0x8b", fault.source().unwrap().to_string());
+    assert_eq!(
+        "no operation matches opcode 0x8b with operands 0xea 0xea",
+        source.to_string()
+    );
+}
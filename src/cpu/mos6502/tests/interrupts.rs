@@ -0,0 +1,194 @@
+use super::generate_test_cpu_with_instructions;
+use crate::address_map::{
+    memory::{Memory, ReadWrite},
+    Addressable, WriteError,
+};
+use crate::cpu::{
+    mos6502::{register, MOS6502},
+    register::Register,
+    CPU,
+};
+
+/// A device that asserts the IRQ line for as long as a non-zero value has
+/// been written to it.
+#[derive(Clone, Default)]
+struct InterruptingDevice {
+    asserted: bool,
+}
+
+impl Addressable<u16> for InterruptingDevice {
+    fn read(&self, _: u16) -> u8 {
+        self.asserted as u8
+    }
+
+    fn write(&mut self, _: u16, data: u8) -> Result<u8, WriteError<u16>> {
+        self.asserted = data != 0;
+        Ok(data)
+    }
+
+    fn irq(&self) -> bool {
+        self.asserted
+    }
+}
+
+/// Generates a test cpu with the NMI handler at 0x6100 and the IRQ/BRK
+/// handler at 0x6200, each consisting of a single `rti` instruction.
+fn generate_test_cpu_with_vectors(opcodes: Vec<u8>) -> MOS6502 {
+    let mut program = vec![0xea; 0x300];
+    for (index, val) in opcodes.into_iter().enumerate() {
+        program[index] = val;
+    }
+    program[0x100] = 0x40;
+    program[0x200] = 0x40;
+
+    let mut cpu = generate_test_cpu_with_instructions(program)
+        .register_address_space(0xff00..=0xffff, Memory::<ReadWrite>::new(0xff00, 0xffff))
+        .unwrap();
+    cpu.address_map.write(0xfffa, 0x00).unwrap();
+    cpu.address_map.write(0xfffb, 0x61).unwrap();
+    cpu.address_map.write(0xfffe, 0x00).unwrap();
+    cpu.address_map.write(0xffff, 0x62).unwrap();
+    cpu
+}
+
+#[test]
+fn should_service_irq_when_interrupts_are_enabled() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![]).with_irq_line(true);
+    cpu.ps.interrupt_disable = false;
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6200, state.pc.read());
+    assert_eq!(0xfc, state.sp.read());
+    assert!(state.ps.interrupt_disable);
+    assert_eq!(
+        (0x60, 0x00),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+    // break flag should be unset in the pushed status
+    assert_eq!(0x00, state.address_map.read(0x01fd) & 0x10);
+}
+
+#[test]
+fn should_not_service_irq_when_interrupts_are_disabled() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![]).with_irq_line(true);
+    cpu.ps.interrupt_disable = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+}
+
+#[test]
+fn should_continue_to_service_irq_while_line_is_held() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![]).with_irq_line(true);
+    cpu.ps.interrupt_disable = false;
+
+    // interrupt and rti
+    let state = cpu.run(13).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+    assert!(!state.ps.interrupt_disable);
+
+    // irq is level-triggered and should be taken again
+    let state = state.run(7).unwrap();
+    assert_eq!(0x6200, state.pc.read());
+}
+
+#[test]
+fn should_service_irq_asserted_by_device() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![0xa9, 0x01, 0x8d, 0x00, 0x50])
+        .register_address_space(0x5000..=0x5000, InterruptingDevice::default())
+        .unwrap();
+    cpu.ps.interrupt_disable = false;
+
+    // lda and sta to assert the line
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6005, state.pc.read());
+    assert!(state.irq_asserted());
+
+    let state = state.run(7).unwrap();
+    assert_eq!(0x6200, state.pc.read());
+    assert_eq!(
+        (0x60, 0x05),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+}
+
+#[test]
+fn should_service_nmi_when_interrupts_are_disabled() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![]).with_nmi_line(true);
+    cpu.ps.interrupt_disable = true;
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6100, state.pc.read());
+    assert_eq!(0xfc, state.sp.read());
+    assert_eq!(0x00, state.address_map.read(0x01fd) & 0x10);
+}
+
+#[test]
+fn should_prioritize_nmi_over_irq() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![])
+        .with_irq_line(true)
+        .with_nmi_line(true);
+    cpu.ps.interrupt_disable = false;
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6100, state.pc.read());
+}
+
+#[test]
+fn should_only_service_nmi_on_an_edge() {
+    let cpu = generate_test_cpu_with_vectors(vec![]).with_nmi_line(true);
+
+    // interrupt and rti
+    let state = cpu.run(13).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+
+    // nmi is held so the nop should be executed rather than another nmi
+    let state = state.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+
+    // releasing and reasserting the line should trigger another nmi
+    let state = state.with_nmi_line(false).run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    let state = state.with_nmi_line(true).run(7).unwrap();
+    assert_eq!(0x6100, state.pc.read());
+}
+
+#[test]
+fn should_push_break_flag_and_return_past_padding_byte_on_brk() {
+    let mut cpu = generate_test_cpu_with_vectors(vec![0x00, 0xff]);
+    cpu.ps = register::ProcessorStatus::with_value(0xc3);
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6200, state.pc.read());
+    assert!(state.ps.interrupt_disable);
+    assert_eq!(
+        (0x60, 0x02, 0xf3),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe),
+            state.address_map.read(0x01fd)
+        )
+    );
+
+    // rti should restore the status and return after the padding byte
+    let state = state.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+    assert_eq!(
+        (true, true, false, true, true),
+        (
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.interrupt_disable,
+            state.ps.zero,
+            state.ps.carry
+        )
+    );
+}
@@ -8,6 +8,18 @@ use crate::cpu::{
     CPU,
 };
 
+mod budget;
+mod cycles;
+mod decimal_mode;
+mod faults;
+mod interrupts;
+mod pins;
+mod reads;
+mod reset;
+mod stepping;
+#[cfg(feature = "illegal-opcodes")]
+mod undocumented;
+
 fn generate_test_cpu_with_instructions(opcodes: Vec<u8>) -> MOS6502 {
     let (start_addr, stop_addr) = (0x6000, 0x7000);
     let mut nop_sled = [0xea; 0x7000 - 0x6000].to_vec();
@@ -15,15 +27,760 @@ fn generate_test_cpu_with_instructions(opcodes: Vec<u8>) -> MOS6502 {
         nop_sled[index] = val;
     }
 
-    MOS6502::default()
-        .reset()
-        .unwrap()
-        .with_pc_register(register::ProgramCounter::with_value(start_addr))
-        .register_address_space(
-            start_addr..=stop_addr,
-            Memory::<ReadOnly>::new(0x6000, 0x7000).load(nop_sled),
-        )
-        .unwrap()
+    MOS6502::default()
+        .with_pc_register(register::ProgramCounter::with_value(start_addr))
+        .register_address_space(
+            start_addr..=stop_addr,
+            Memory::<ReadOnly>::new(0x6000, 0x7000).load(nop_sled),
+        )
+        .unwrap()
+}
+
+#[test]
+fn should_cycle_on_adc_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x69, 0x50])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x65, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50));
+    cpu.address_map.write(0x0a, 0x50).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x75, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x50).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6d, 0x05, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x7d, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_absolute_indexed_with_y_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x79, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x50).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_x_indexed_indirect_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x61, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x50).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_adc_indirect_y_indexed_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x71, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x50).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_rol_accumulator_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x2a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x81));
+    cpu.ps.carry = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0x03, state.acc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_rol_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x26, 0x0a]);
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x03, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_rol_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x36, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x03, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_rol_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x2e, 0x05, 0x01]);
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x03, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_rol_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x3e, 0x00, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x03, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_ror_accumulator_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x81));
+    cpu.ps.carry = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0xc0, state.acc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_ror_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x66, 0x0a]);
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xc0, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_ror_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x76, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xc0, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_ror_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6e, 0x05, 0x01]);
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xc0, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_ror_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x7e, 0x00, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xc0, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_rts_implied_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x60])
+        // simulate having pushed the return address 0x6007 to the stack
+        .with_sp_register(register::StackPointer::with_value(0xfd));
+    cpu.address_map.write(0x01ff, 0x60).unwrap();
+    cpu.address_map.write(0x01fe, 0x07).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+}
+
+#[test]
+fn should_cycle_on_rts_implied_operation_with_stack_pointer_wrap() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x60])
+        .with_sp_register(register::StackPointer::with_value(0xfe));
+    cpu.address_map.write(0x01ff, 0x07).unwrap();
+    cpu.address_map.write(0x0100, 0x60).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x00, state.sp.read());
+}
+
+#[test]
+fn should_return_from_nested_subroutines() {
+    let mut program = vec![0xea; 0x30];
+    // 0x6000: jsr 0x6010
+    program[0x00..0x03].copy_from_slice(&[0x20, 0x10, 0x60]);
+    // 0x6010: jsr 0x6020
+    // 0x6013: rts
+    program[0x10..0x14].copy_from_slice(&[0x20, 0x20, 0x60, 0x60]);
+    // 0x6020: rts
+    program[0x20] = 0x60;
+    let cpu = generate_test_cpu_with_instructions(program);
+
+    // both calls
+    let state = cpu.run(12).unwrap();
+    assert_eq!(0x6020, state.pc.read());
+    assert_eq!(0xfb, state.sp.read());
+    assert_eq!(
+        (0x60, 0x02, 0x60, 0x12),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe),
+            state.address_map.read(0x01fd),
+            state.address_map.read(0x01fc)
+        )
+    );
+
+    // first return
+    let state = state.run(6).unwrap();
+    assert_eq!(0x6013, state.pc.read());
+    assert_eq!(0xfd, state.sp.read());
+
+    // second return
+    let state = state.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+}
+
+#[test]
+fn should_cycle_on_sbc_immediate_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe9, 0xb0])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50));
+    cpu.ps.carry = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe5, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0xb0).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf5, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0xb0).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xed, 0x05, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xfd, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_absolute_indexed_with_y_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf9, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_x_indexed_indirect_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe1, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbc_indirect_y_indexed_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf1, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x50))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0xb0).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xa0, state.acc.read());
+    assert_eq!(
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        ),
+        (false, true, true, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_and_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x29, 0x8f])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x25, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0));
+    cpu.address_map.write(0x0a, 0x8f).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x35, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x8f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x2d, 0x05, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x3d, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_absolute_indexed_with_y_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x39, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_x_indexed_indirect_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x21, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_indirect_y_indexed_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x31, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x8f).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_and_immediate_operation_with_zero_result() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x29, 0x0f])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xf0));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x00, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
+#[test]
+fn should_cycle_on_asl_accumulator_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x81));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0x02, state.acc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_asl_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x06, 0x0a]);
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x02, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_asl_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x16, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x02, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_asl_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x0e, 0x05, 0x01]);
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x02, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_asl_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x1e, 0x00, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x02, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
 }
 
 #[test]
@@ -66,9 +823,155 @@ fn bcs_implied_operation_should_jump_when_zero_set() {
 }
 
 #[test]
-fn bcs_implied_operation_should_incur_penalty_at_page_boundary() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xb0, 0xf8]);
-    cpu.ps.carry = true;
+fn bcs_implied_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xb0, 0xf8]);
+    cpu.ps.carry = true;
+
+    // 4 cycles with branch penalty
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x5ff8, state.pc.read());
+}
+
+#[test]
+fn bcs_implied_operation_should_not_jump_when_zero_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xb0, 0x08]);
+    cpu.ps.carry = false;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn beq_implied_operation_should_jump_when_zero_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf0, 0x08]);
+    cpu.ps.zero = true;
+
+    // 3 cycles with branch penalty
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn beq_implied_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf0, 0xf8]);
+    cpu.ps.zero = true;
+
+    // 4 cycles with branch penalty
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x5ff8, state.pc.read());
+}
+
+#[test]
+fn beq_implied_operation_should_not_jump_when_zero_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf0, 0x08]);
+    cpu.ps.zero = false;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn should_cycle_on_bit_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x24, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x0f));
+    cpu.address_map.write(0x0a, 0xc0).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x0f, state.acc.read());
+    assert_eq!(
+        (state.ps.negative, state.ps.overflow, state.ps.zero),
+        (true, true, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_bit_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x2c, 0x05, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x0f));
+    cpu.address_map.write(0x0105, 0xc0).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x0f, state.acc.read());
+    assert_eq!(
+        (state.ps.negative, state.ps.overflow, state.ps.zero),
+        (true, true, true)
+    );
+}
+
+#[test]
+fn bmi_relative_operation_should_jump_when_negative_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x30, 0x08]);
+    cpu.ps.negative = true;
+
+    // 3 cycles with branch penalty
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn bmi_relative_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x30, 0xf8]);
+    cpu.ps.negative = true;
+
+    // 4 cycles with branch penalty
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x5ff8, state.pc.read());
+}
+
+#[test]
+fn bmi_relative_operation_should_not_jump_when_negative_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x30, 0x08]);
+    cpu.ps.negative = false;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn bne_implied_operation_should_jump_when_zero_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0x08]);
+    cpu.ps.zero = false;
+
+    // 3 cycles with branch penalty
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn bne_implied_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0xf8]);
+    cpu.ps.zero = false;
+
+    // 4 cycles with branch penalty
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x5ff8, state.pc.read());
+}
+
+#[test]
+fn bne_implied_operation_should_not_jump_when_zero_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0x08]);
+    cpu.ps.zero = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn bpl_relative_operation_should_jump_when_negative_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x10, 0x08]);
+    cpu.ps.negative = false;
+
+    // 3 cycles with branch penalty
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn bpl_relative_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x10, 0xf8]);
+    cpu.ps.negative = false;
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
@@ -76,18 +979,18 @@ fn bcs_implied_operation_should_incur_penalty_at_page_boundary() {
 }
 
 #[test]
-fn bcs_implied_operation_should_not_jump_when_zero_unset() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xb0, 0x08]);
-    cpu.ps.carry = false;
+fn bpl_relative_operation_should_not_jump_when_negative_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x10, 0x08]);
+    cpu.ps.negative = true;
 
     let state = cpu.run(2).unwrap();
     assert_eq!(0x6002, state.pc.read());
 }
 
 #[test]
-fn beq_implied_operation_should_jump_when_zero_set() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xf0, 0x08]);
-    cpu.ps.zero = true;
+fn bvc_relative_operation_should_jump_when_overflow_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x50, 0x08]);
+    cpu.ps.overflow = false;
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
@@ -95,9 +998,9 @@ fn beq_implied_operation_should_jump_when_zero_set() {
 }
 
 #[test]
-fn beq_implied_operation_should_incur_penalty_at_page_boundary() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xf0, 0xf8]);
-    cpu.ps.zero = true;
+fn bvc_relative_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x50, 0xf8]);
+    cpu.ps.overflow = false;
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
@@ -105,18 +1008,18 @@ fn beq_implied_operation_should_incur_penalty_at_page_boundary() {
 }
 
 #[test]
-fn beq_implied_operation_should_not_jump_when_zero_unset() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xf0, 0x08]);
-    cpu.ps.zero = false;
+fn bvc_relative_operation_should_not_jump_when_overflow_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x50, 0x08]);
+    cpu.ps.overflow = true;
 
     let state = cpu.run(2).unwrap();
     assert_eq!(0x6002, state.pc.read());
 }
 
 #[test]
-fn bne_implied_operation_should_jump_when_zero_set() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0x08]);
-    cpu.ps.zero = false;
+fn bvs_relative_operation_should_jump_when_overflow_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x70, 0x08]);
+    cpu.ps.overflow = true;
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
@@ -124,9 +1027,9 @@ fn bne_implied_operation_should_jump_when_zero_set() {
 }
 
 #[test]
-fn bne_implied_operation_should_incur_penalty_at_page_boundary() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0xf8]);
-    cpu.ps.zero = false;
+fn bvs_relative_operation_should_incur_penalty_at_page_boundary() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x70, 0xf8]);
+    cpu.ps.overflow = true;
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
@@ -134,9 +1037,9 @@ fn bne_implied_operation_should_incur_penalty_at_page_boundary() {
 }
 
 #[test]
-fn bne_implied_operation_should_not_jump_when_zero_unset() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0x08]);
-    cpu.ps.zero = true;
+fn bvs_relative_operation_should_not_jump_when_overflow_unset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x70, 0x08]);
+    cpu.ps.overflow = false;
 
     let state = cpu.run(2).unwrap();
     assert_eq!(0x6002, state.pc.read());
@@ -451,55 +1354,355 @@ fn should_cycle_on_cmp_zeropage_operation_with_equal_operands() {
 }
 
 #[test]
-fn should_cycle_on_cmp_zeropage_indexed_with_x_operation_with_inequal_operands() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xd5, 0xfa])
-        .with_gp_register(
-            register::GPRegister::ACC,
-            register::GeneralPurpose::with_value(0x00),
-        )
-        .with_gp_register(
-            register::GPRegister::X,
-            register::GeneralPurpose::with_value(0x05),
-        );
-    cpu.address_map.write(0x00ff, 0xff).unwrap();
+fn should_cycle_on_cmp_zeropage_indexed_with_x_operation_with_inequal_operands() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd5, 0xfa])
+        .with_gp_register(
+            register::GPRegister::ACC,
+            register::GeneralPurpose::with_value(0x00),
+        )
+        .with_gp_register(
+            register::GPRegister::X,
+            register::GeneralPurpose::with_value(0x05),
+        );
+    cpu.address_map.write(0x00ff, 0xff).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (false, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_cmp_zeropage_indexed_with_x_operation_with_equal_operands() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd5, 0xfa])
+        .with_gp_register(
+            register::GPRegister::ACC,
+            register::GeneralPurpose::with_value(0xff),
+        )
+        .with_gp_register(
+            register::GPRegister::X,
+            register::GeneralPurpose::with_value(0x05),
+        );
+    cpu.address_map.write(0x00ff, 0xff).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_eor_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x49, 0x7f])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x45, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff));
+    cpu.address_map.write(0x0a, 0x7f).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x55, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x7f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x4d, 0x05, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x5d, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_absolute_indexed_with_y_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x59, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_x_indexed_indirect_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x41, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_indirect_y_indexed_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x51, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x7f).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_eor_immediate_operation_with_zero_result() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x49, 0x5a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x5a));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x00, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
+#[test]
+fn should_cycle_on_cpx_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xe0, 0x10])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x10));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_cpx_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe4, 0x0a])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0a, 0x10).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_cpx_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xec, 0x05, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0105, 0x10).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_cpy_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xc0, 0x10])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x10));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_cpy_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xc4, 0x0a])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0a, 0x10).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_cpy_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xcc, 0x05, 0x01])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x0105, 0x10).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, true)
+    );
+}
+
+#[test]
+fn should_cycle_on_dec_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xc6, 0x0a]);
+    cpu.address_map.write(0x0a, 0x01).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x00, state.address_map.read(0x0a));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
+#[test]
+fn should_cycle_on_dec_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd6, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x01).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x00, state.address_map.read(0x0a));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
+#[test]
+fn should_cycle_on_dec_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xce, 0x05, 0x01]);
+    cpu.address_map.write(0x0105, 0x01).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x00, state.address_map.read(0x0105));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
+#[test]
+fn should_cycle_on_dec_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xde, 0x00, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x01).unwrap();
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x00, state.address_map.read(0x0105));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
+#[test]
+fn should_cycle_on_dex_implied_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xca])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x00));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0xff, state.x.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_dey_implied_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x88])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x00));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0xff, state.y.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_inc_implied_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xee, 0xff, 0x01]);
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(1, state.address_map.read(0x01ff));
+    assert_eq!((false, false), (state.ps.negative, state.ps.zero));
+}
+
+#[test]
+fn should_cycle_on_inc_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe6, 0x0a]);
+    cpu.address_map.write(0x0a, 0xff).unwrap();
 
-    let state = cpu.run(4).unwrap();
+    let state = cpu.run(5).unwrap();
     assert_eq!(0x6002, state.pc.read());
-    assert_eq!(
-        (state.ps.carry, state.ps.negative, state.ps.zero),
-        (false, false, false)
-    );
+    assert_eq!(0x00, state.address_map.read(0x0a));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
 }
 
 #[test]
-fn should_cycle_on_cmp_zeropage_indexed_with_x_operation_with_equal_operands() {
-    let mut cpu = generate_test_cpu_with_instructions(vec![0xd5, 0xfa])
-        .with_gp_register(
-            register::GPRegister::ACC,
-            register::GeneralPurpose::with_value(0xff),
-        )
-        .with_gp_register(
-            register::GPRegister::X,
-            register::GeneralPurpose::with_value(0x05),
-        );
-    cpu.address_map.write(0x00ff, 0xff).unwrap();
+fn should_cycle_on_inc_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xf6, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0xff).unwrap();
 
-    let state = cpu.run(4).unwrap();
+    let state = cpu.run(6).unwrap();
     assert_eq!(0x6002, state.pc.read());
-    assert_eq!(
-        (state.ps.carry, state.ps.negative, state.ps.zero),
-        (true, false, true)
-    );
+    assert_eq!(0x00, state.address_map.read(0x0a));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
 }
 
 #[test]
-fn should_cycle_on_inc_implied_operation() {
-    let cpu = generate_test_cpu_with_instructions(vec![0xee, 0xff, 0x01]);
+fn should_cycle_on_inc_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xfe, 0x00, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0xff).unwrap();
 
-    let state = cpu.run(6).unwrap();
+    let state = cpu.run(7).unwrap();
     assert_eq!(0x6003, state.pc.read());
-    assert_eq!(1, state.address_map.read(0x01ff));
-    assert_eq!((false, false), (state.ps.negative, state.ps.zero));
+    assert_eq!(0x00, state.address_map.read(0x0105));
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
 }
 
 #[test]
@@ -542,6 +1745,50 @@ fn should_cycle_on_jmp_indirect_operation() {
     assert_eq!(0xeaea, state.pc.read());
 }
 
+#[test]
+fn jmp_indirect_operation_should_wrap_within_the_page() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6c, 0xff, 0x00]);
+    cpu.address_map.write(0xff, 0x34).unwrap();
+    cpu.address_map.write(0x100, 0x12).unwrap();
+    cpu.address_map.write(0x00, 0x56).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x5634, state.pc.read());
+}
+
+#[test]
+fn should_cycle_on_jsr_absolute_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x10, 0x60]);
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(0xfd, state.sp.read());
+    assert_eq!(
+        (0x60, 0x02),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+}
+
+#[test]
+fn should_cycle_on_jsr_absolute_operation_with_stack_pointer_wrap() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x10, 0x60])
+        .with_sp_register(register::StackPointer::with_value(0x00));
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(0xfe, state.sp.read());
+    assert_eq!(
+        (0x60, 0x02),
+        (
+            state.address_map.read(0x0100),
+            state.address_map.read(0x01ff)
+        )
+    );
+}
+
 #[test]
 fn should_cycle_on_lda_immediate_operation() {
     let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0xa9, 0x0f]);
@@ -771,6 +2018,78 @@ fn should_cycle_on_ldy_zeropage_indexed_with_x_operation() {
     assert_eq!((state.ps.negative, state.ps.zero), (true, false));
 }
 
+#[test]
+fn should_cycle_on_lsr_accumulator_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x4a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x81));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(0x40, state.acc.read());
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_lsr_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x46, 0x0a]);
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x40, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_lsr_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x56, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x40, state.address_map.read(0x0a));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_lsr_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x4e, 0x05, 0x01]);
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x40, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn should_cycle_on_lsr_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x5e, 0x00, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x81).unwrap();
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x40, state.address_map.read(0x0105));
+    assert_eq!(
+        (state.ps.carry, state.ps.negative, state.ps.zero),
+        (true, false, false)
+    );
+}
+
 #[test]
 fn should_cycle_on_nop_implied_operation() {
     let cpu = generate_test_cpu_with_instructions(vec![]);
@@ -782,6 +2101,121 @@ fn should_cycle_on_nop_implied_operation() {
     assert_eq!(0x6002, next_state.pc.read());
 }
 
+#[test]
+fn should_cycle_on_ora_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x09, 0x0f])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x05, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80));
+    cpu.address_map.write(0x0a, 0x0f).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_zeropage_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x15, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x0f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x0d, 0x05, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_absolute_indexed_with_x_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x1d, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_absolute_indexed_with_y_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x19, 0x00, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_x_indexed_indirect_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x01, 0x05])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x05).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_indirect_y_indexed_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x11, 0x0a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x80))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+    cpu.address_map.write(0x0a, 0x00).unwrap();
+    cpu.address_map.write(0x0b, 0x01).unwrap();
+    cpu.address_map.write(0x0105, 0x0f).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x8f, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (true, false));
+}
+
+#[test]
+fn should_cycle_on_ora_immediate_operation_with_zero_result() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x09, 0x00])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x00));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x00, state.acc.read());
+    assert_eq!((state.ps.negative, state.ps.zero), (false, true));
+}
+
 #[test]
 fn should_cycle_on_pha_implied_operation() {
     let cpu = generate_test_cpu_with_instructions(vec![0x48])
@@ -966,6 +2400,68 @@ fn should_cycle_on_sta_zeropage_with_x_index_operation() {
     assert_eq!(0xff, state.address_map.read(0x05));
 }
 
+#[test]
+fn should_cycle_on_stx_zeropage_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x86, 0x0a])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0xab));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xab, state.address_map.read(0x0a));
+}
+
+#[test]
+fn should_cycle_on_stx_zeropage_indexed_with_y_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x96, 0x05])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0xab))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x05));
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xab, state.address_map.read(0x0a));
+}
+
+#[test]
+fn should_cycle_on_stx_absolute_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x8e, 0x05, 0x01])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0xab));
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xab, state.address_map.read(0x0105));
+}
+
+#[test]
+fn should_cycle_on_sty_zeropage_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x84, 0x0a])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0xab));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xab, state.address_map.read(0x0a));
+}
+
+#[test]
+fn should_cycle_on_sty_zeropage_indexed_with_x_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x94, 0x05])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0xab))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x05));
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xab, state.address_map.read(0x0a));
+}
+
+#[test]
+fn should_cycle_on_sty_absolute_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x8c, 0x05, 0x01])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0xab));
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xab, state.address_map.read(0x0105));
+}
+
 #[test]
 fn should_cycle_on_tax_implied_operation() {
     let cpu = generate_test_cpu_with_instructions(vec![0xaa])
@@ -1042,3 +2538,71 @@ fn should_cycle_on_tya_implied_operation() {
     assert_eq!(0xff, state.y.read());
     assert_eq!((state.ps.negative, state.ps.zero), (true, false));
 }
+
+#[test]
+fn should_write_through_a_mirror_of_a_masked_address_space() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x8d, 0x05, 0x28])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x42))
+        .register_masked_address_space(
+            0x2000..=0x3fff,
+            0x07ff,
+            Memory::<ReadWrite>::new(0x2000, 0x27ff),
+        )
+        .unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x42, state.address_map.read(0x2005));
+    assert_eq!(0x42, state.address_map.read(0x3805));
+}
+
+#[test]
+fn should_hot_swap_an_address_space_on_a_running_cpu() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![]).run(2).unwrap();
+    assert_eq!(0x6001, cpu.pc.read());
+
+    let mut program = [0xea; 0x7000 - 0x6000].to_vec();
+    program[1] = 0xa9;
+    program[2] = 0x42;
+    cpu.replace_address_space(
+        0x6000..=0x7000,
+        Memory::<ReadOnly>::new(0x6000, 0x7000).load(program),
+    )
+    .unwrap();
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x42, state.acc.read());
+}
+
+#[test]
+fn should_bank_an_overlay_in_and_out_of_a_running_cpu() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xad, 0x00, 0xa0, 0xad, 0x00, 0xa0])
+        .register_address_space(0xa000..=0xbfff, Memory::<ReadWrite>::new(0xa000, 0xbfff))
+        .unwrap();
+    cpu.address_map.write(0xa000, 0x01).unwrap();
+    cpu.overlay_address_space(
+        0xa000..=0xbfff,
+        1,
+        crate::address_map::Routing::READS,
+        Memory::<ReadOnly>::new(0xa000, 0xbfff).load(vec![0x02; 0x2000]),
+    )
+    .unwrap();
+
+    let mut cpu = cpu.run(4).unwrap();
+    assert_eq!(0x02, cpu.acc.read());
+
+    cpu.set_overlay_routing(0xa000..=0xbfff, crate::address_map::Routing::NONE)
+        .unwrap();
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x01, state.acc.read());
+}
+
+#[test]
+fn should_write_offsets_to_a_relatively_addressed_address_space() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x8d, 0x05, 0x90])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x42))
+        .register_relative_address_space(0x9000..=0x90ff, Memory::<ReadWrite>::new(0x00, 0xff))
+        .unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x42, state.address_map.read(0x9005));
+}
@@ -0,0 +1,62 @@
+use super::generate_test_cpu_with_instructions;
+use crate::cpu::{mos6502::microcode::Microcode, register::Register, CPU};
+
+#[test]
+fn should_halt_while_rdy_is_low() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x01]).with_rdy_line(false);
+
+    let state = cpu.run(10).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+    assert_eq!(0x00, state.acc.read());
+
+    let state = state.with_rdy_line(true).run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x01, state.acc.read());
+}
+
+#[test]
+fn should_count_cycles_halted_by_rdy_against_the_budget() {
+    let cpu = generate_test_cpu_with_instructions(vec![]).with_rdy_line(false);
+
+    let state = cpu.run(10).unwrap();
+    assert_eq!(10, state.cycles());
+    assert!(!state.ready());
+}
+
+#[test]
+fn should_set_overflow_on_falling_edge_of_so() {
+    // clv
+    let cpu = generate_test_cpu_with_instructions(vec![0xb8]).with_so_line(true);
+    assert!(cpu.ps.overflow);
+
+    // holding the line asserted does not set the flag again.
+    let cpu = cpu.run(2).unwrap().with_so_line(true);
+    assert!(!cpu.ps.overflow);
+
+    let cpu = cpu.with_so_line(false).with_so_line(true);
+    assert!(cpu.ps.overflow);
+}
+
+#[test]
+fn should_drive_sync_only_on_the_opcode_fetch() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xad, 0x00, 0x02]);
+
+    let steps: Vec<Vec<Microcode>> = cpu.clone().into_iter().next().map(Into::into).unwrap();
+    let sync: Vec<bool> = steps
+        .iter()
+        .map(|step| step.iter().any(|mc| matches!(mc, Microcode::SetSync(_))))
+        .collect();
+    assert_eq!(vec![true, false, false, false], sync);
+
+    assert!(cpu.clone().run(1).unwrap().sync());
+    assert!(!cpu.run(4).unwrap().sync());
+}
+
+#[test]
+fn should_not_drive_sync_while_halted_by_rdy() {
+    let cpu = generate_test_cpu_with_instructions(vec![]).with_rdy_line(false);
+
+    let state = cpu.run(1).unwrap();
+    assert!(!state.sync());
+    assert_eq!(0x6000, state.pc.read());
+}
@@ -0,0 +1,138 @@
+use super::generate_test_cpu_with_instructions;
+use crate::address_map::{Addressable, WriteError};
+use crate::cpu::{
+    mos6502::{
+        microcode::Microcode,
+        register::{GPRegister, GeneralPurpose},
+        Execute, Variant, MOS6502,
+    },
+    register::Register,
+    CPU,
+};
+
+/// A status register that clears once it has been read by the cpu, such as
+/// the interrupt flags of a 6522 or the status of a 6551.
+#[derive(Clone, Default)]
+struct StatusRegister {
+    status: u8,
+}
+
+impl Addressable<u16> for StatusRegister {
+    fn read(&self, _: u16) -> u8 {
+        self.status
+    }
+
+    fn write(&mut self, _: u16, data: u8) -> Result<u8, WriteError<u16>> {
+        self.status = data;
+        Ok(data)
+    }
+
+    fn read_mut(&mut self, _: u16) -> u8 {
+        std::mem::take(&mut self.status)
+    }
+}
+
+fn generate_test_cpu_with_status_register(opcodes: Vec<u8>) -> MOS6502 {
+    let mut cpu = generate_test_cpu_with_instructions(opcodes)
+        .register_address_space(0x0200..=0x0200, StatusRegister::default())
+        .unwrap();
+    cpu.address_map.write(0x0200, 0x80).unwrap();
+    cpu
+}
+
+#[test]
+fn should_clear_status_register_on_load() {
+    let cpu = generate_test_cpu_with_status_register(vec![0xad, 0x00, 0x02]);
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!(0x00, state.address_map.read(0x0200));
+}
+
+#[test]
+fn should_clear_status_register_on_bit_test() {
+    let cpu = generate_test_cpu_with_status_register(vec![0x2c, 0x00, 0x02]);
+
+    let state = cpu.run(4).unwrap();
+    assert!(state.ps.negative);
+    assert_eq!(0x00, state.address_map.read(0x0200));
+}
+
+#[test]
+fn should_not_clear_status_register_on_unrelated_load() {
+    let cpu = generate_test_cpu_with_status_register(vec![0xad, 0x00, 0x60]);
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x80, state.address_map.read(0x0200));
+}
+
+#[test]
+fn should_schedule_fetch_and_operand_reads() {
+    let cpu = generate_test_cpu_with_status_register(vec![0xad, 0x00, 0x02]);
+    let steps: Vec<Vec<Microcode>> = cpu.into_iter().next().unwrap().into();
+
+    let reads: Vec<Vec<Microcode>> = steps
+        .into_iter()
+        .map(|step| {
+            step.into_iter()
+                .filter(|mc| matches!(mc, Microcode::ReadMemory(_)))
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            vec![gen_read_memory_microcode!(0x6000)],
+            vec![gen_read_memory_microcode!(0x6001)],
+            vec![gen_read_memory_microcode!(0x6002)],
+            vec![gen_read_memory_microcode!(0x0200)],
+        ],
+        reads
+    );
+}
+
+#[test]
+fn should_honor_read_side_effects_on_replay() {
+    let cpu = generate_test_cpu_with_status_register(vec![0xad, 0x00, 0x02]);
+    let microcode: Vec<Microcode> = cpu
+        .clone()
+        .into_iter()
+        .take(1)
+        .flat_map(Into::<Vec<Vec<Microcode>>>::into)
+        .flatten()
+        .collect();
+
+    let state = microcode.into_iter().fold(cpu, |c, mc| mc.execute(c));
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!(0x00, state.address_map.read(0x0200));
+}
+
+#[test]
+fn should_sample_operand_after_dummy_read_of_a_page_crossing_index() {
+    // the carry cycle reads 0x0210 before the operand is read from 0x0310,
+    // both decoding to the status register.
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xbd, 0xf0, 0x02])
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x20))
+        .register_address_space(0x0200..=0x03ff, StatusRegister::default())
+        .unwrap();
+    cpu.address_map.write(0x0200, 0x80).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x00, state.acc.read());
+    assert!(state.ps.zero);
+}
+
+#[test]
+fn should_sample_operand_without_dummy_read_of_a_page_crossing_index_on_65c02() {
+    // the 65C02 rereads the last byte of the instruction on the carry cycle.
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xbd, 0xf0, 0x02])
+        .with_variant(Variant::W65C02)
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x20))
+        .register_address_space(0x0200..=0x03ff, StatusRegister::default())
+        .unwrap();
+    cpu.address_map.write(0x0200, 0x80).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!(0x00, state.address_map.read(0x0200));
+}
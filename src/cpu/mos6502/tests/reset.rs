@@ -0,0 +1,175 @@
+use crate::address_map::{
+    memory::{Memory, ReadOnly, ReadWrite},
+    Addressable,
+};
+use crate::cpu::{
+    mos6502::{
+        operations::Vectors,
+        register::{self, GPRegister},
+        MOS6502,
+    },
+    register::Register,
+    w65c02::W65C02,
+    CPU,
+};
+
+fn generate_test_cpu_with_reset_vector(vector: u16, handler: u16) -> MOS6502 {
+    let mut cpu = MOS6502::default()
+        .register_address_space(0xff00..=0xffff, Memory::<ReadWrite>::new(0xff00, 0xffff))
+        .unwrap();
+    let [lsb, msb] = handler.to_le_bytes();
+    cpu.address_map.write(vector, lsb).unwrap();
+    cpu.address_map.write(vector + 1, msb).unwrap();
+    cpu
+}
+
+#[test]
+fn should_load_program_counter_from_reset_vector() {
+    let cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000);
+
+    let (remaining, state) = cpu.reset().into();
+    assert_eq!(0x6000, state.pc.read());
+    assert_eq!(6, remaining);
+}
+
+#[test]
+fn should_decrement_stack_pointer_and_disable_interrupts_on_reset() {
+    let mut cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000)
+        .with_sp_register(register::StackPointer::with_value(0x01));
+    cpu.ps.interrupt_disable = false;
+
+    let state = cpu.reset().unwrap();
+    assert_eq!(0xfe, state.sp.read());
+    assert!(state.ps.interrupt_disable);
+    // the pushes are suppressed, leaving the stack untouched.
+    assert_eq!(
+        (0x00, 0x00, 0x00),
+        (
+            state.address_map.read(0x0101),
+            state.address_map.read(0x0100),
+            state.address_map.read(0x01ff)
+        )
+    );
+}
+
+#[test]
+fn should_leave_remaining_registers_unchanged_on_reset() {
+    let mut cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000)
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x12))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x34))
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x56));
+    cpu.ps.carry = true;
+    cpu.ps.decimal = true;
+
+    let state = cpu.reset().unwrap();
+    assert_eq!(
+        (0x12, 0x34, 0x56),
+        (state.acc.read(), state.x.read(), state.y.read())
+    );
+    assert!(state.ps.carry);
+    assert!(state.ps.decimal);
+}
+
+#[test]
+fn should_clear_decimal_mode_on_w65c02_reset() {
+    let mut cpu = W65C02::from(generate_test_cpu_with_reset_vector(0xfffc, 0x6000));
+    cpu.ps.decimal = true;
+
+    let state = cpu.reset().unwrap();
+    assert!(!state.ps.decimal);
+}
+
+#[test]
+fn should_finish_reset_before_executing_the_first_instruction() {
+    let cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000)
+        .register_address_space(
+            0x6000..=0x6001,
+            Memory::<ReadOnly>::new(0x6000, 0x6001).load(vec![0xa9, 0x01]),
+        )
+        .unwrap();
+
+    let state = cpu.reset().run(8).unwrap();
+    assert_eq!(0x01, state.acc.read());
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn should_recover_a_halted_cpu_on_reset() {
+    let cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000)
+        .register_address_space(
+            0x6000..=0x6000,
+            Memory::<ReadOnly>::new(0x6000, 0x6000).load(vec![0x8b]),
+        )
+        .unwrap()
+        .with_pc_register(register::ProgramCounter::with_value(0x6000));
+
+    let state = cpu.run(2).unwrap();
+    assert!(state.halted().is_some());
+
+    let state = state.reset().unwrap();
+    assert!(state.halted().is_none());
+    assert_eq!(0x6000, state.pc.read());
+}
+
+#[test]
+fn should_load_program_counter_from_relocated_reset_vector() {
+    let cpu = generate_test_cpu_with_reset_vector(0xff7c, 0x6000).with_vectors(Vectors {
+        reset: 0xff7c,
+        ..Vectors::default()
+    });
+
+    let state = cpu.reset().unwrap();
+    assert_eq!(0x6000, state.pc.read());
+}
+
+#[test]
+fn should_service_irq_from_relocated_vector() {
+    let mut cpu = generate_test_cpu_with_reset_vector(0xff7e, 0x6200)
+        .with_vectors(Vectors {
+            irq: 0xff7e,
+            ..Vectors::default()
+        })
+        .with_pc_register(register::ProgramCounter::with_value(0x6000))
+        .with_irq_line(true);
+    cpu.ps.interrupt_disable = false;
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6200, state.pc.read());
+}
+
+#[test]
+fn should_count_reset_cycles() {
+    let cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000);
+
+    let state = cpu.reset().unwrap();
+    assert_eq!(1, state.cycles());
+
+    let state = state.reset().run(6).unwrap();
+    assert_eq!(8, state.cycles());
+    assert_eq!(0, state.in_flight());
+}
+
+#[test]
+fn should_clear_stall_on_reset() {
+    let cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000).with_stall(513);
+
+    let state = cpu.reset().unwrap();
+    assert_eq!(0, state.stalled());
+}
+
+#[test]
+fn should_discard_unserviced_nmi_on_reset() {
+    let mut cpu = generate_test_cpu_with_reset_vector(0xfffc, 0x6000)
+        .register_address_space(
+            0x6000..=0x6001,
+            Memory::<ReadOnly>::new(0x6000, 0x6001).load(vec![0xa9, 0x01]),
+        )
+        .unwrap()
+        .with_nmi_line(true);
+    cpu.address_map.write(0xfffa, 0x00).unwrap();
+    cpu.address_map.write(0xfffb, 0x62).unwrap();
+
+    let state = cpu.reset().run(8).unwrap();
+    assert_eq!(0x01, state.acc.read());
+    assert_eq!(0x6002, state.pc.read());
+}
@@ -0,0 +1,110 @@
+use super::generate_test_cpu_with_instructions;
+#[cfg(feature = "illegal-opcodes")]
+use crate::cpu::Halt;
+use crate::cpu::{mos6502::fault::CpuFault, register::Register, StepState, StopReason, CPU};
+
+#[test]
+fn should_execute_a_single_cycle_on_step_cycle() {
+    // lda $6000
+    let cpu = generate_test_cpu_with_instructions(vec![0xad, 0x00, 0x60]);
+
+    let (state, reason) = cpu.step_cycle();
+    assert_eq!(StopReason::Budget, reason);
+    assert_eq!(1, state.clone().unwrap().cycles());
+    assert_eq!(0x00, state.clone().unwrap().acc.read());
+
+    let state = (0..3).fold(state, |state, _| state.step_cycle().0).unwrap();
+    assert_eq!(4, state.cycles());
+    assert_eq!(0xad, state.acc.read());
+    assert_eq!(0x6003, state.pc.read());
+}
+
+#[test]
+fn should_complete_the_instruction_in_flight_on_step_instruction() {
+    // lda $6000
+    let cpu = generate_test_cpu_with_instructions(vec![0xad, 0x00, 0x60]);
+
+    let (state, _) = cpu.step_cycle();
+    let (state, reason) = state.step_instruction();
+    let state = state.unwrap();
+    assert_eq!(StopReason::Budget, reason);
+    assert_eq!(4, state.cycles());
+    assert_eq!(0x6003, state.pc.read());
+
+    let state = state.step_instruction().0.unwrap();
+    assert_eq!(6, state.cycles());
+    assert_eq!(0x6004, state.pc.read());
+}
+
+#[test]
+fn should_run_until_program_counter_is_reached() {
+    let cpu = generate_test_cpu_with_instructions(vec![]);
+
+    let (state, reason) = cpu.run_until(100, |cpu| cpu.pc.read() == 0x6004);
+    assert_eq!(StopReason::Predicate, reason);
+    assert_eq!(8, state.unwrap().cycles());
+}
+
+#[test]
+fn should_run_until_register_value_is_reached() {
+    // inx; jmp $6000
+    let cpu = generate_test_cpu_with_instructions(vec![0xe8, 0x4c, 0x00, 0x60]);
+
+    let (state, reason) = cpu.run_until(100, |cpu| cpu.x.read() == 0x05);
+    let state = state.unwrap();
+    assert_eq!(StopReason::Predicate, reason);
+    assert_eq!(0x05, state.x.read());
+    assert_eq!(22, state.cycles());
+}
+
+#[test]
+fn should_run_until_memory_is_written() {
+    // inx; stx $10; jmp $6000
+    let cpu = generate_test_cpu_with_instructions(vec![0xe8, 0x86, 0x10, 0x4c, 0x00, 0x60]);
+
+    let (state, reason) = cpu.run_until(100, |cpu| cpu.last_write() == Some((0x0010, 0x03)));
+    let state = state.unwrap();
+    assert_eq!(StopReason::Predicate, reason);
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x03, state.x.read());
+}
+
+#[test]
+fn should_stop_when_the_budget_is_exhausted() {
+    let cpu = generate_test_cpu_with_instructions(vec![]);
+
+    let (state, reason) = cpu.run_until(11, |_| false);
+    assert_eq!(StopReason::Budget, reason);
+
+    // the partial nop is carried into the next run.
+    let (remaining, state) = state.into();
+    assert_eq!(1, remaining);
+    assert_eq!(11, state.cycles());
+}
+
+#[test]
+fn should_stop_on_fault() {
+    // sta $6000, a write to the rom it is executing from.
+    let cpu = generate_test_cpu_with_instructions(vec![0x8d, 0x00, 0x60]);
+
+    let (state, reason) = StepState::from(cpu).run_until(100, |_| false);
+    assert!(matches!(
+        reason,
+        StopReason::Fault(fault) if matches!(fault.kind, CpuFault::BusWrite { address: 0x6000, .. })
+    ));
+    assert_eq!(4, state.unwrap().cycles());
+}
+
+#[cfg(feature = "illegal-opcodes")]
+#[test]
+fn should_stop_on_jam() {
+    // jam
+    let cpu = generate_test_cpu_with_instructions(vec![0x02]);
+
+    let (state, reason) = cpu.run_until(100, |_| false);
+    assert_eq!(StopReason::Halted(Halt::Jam), reason);
+    assert!(state.clone().unwrap().halted().is_some());
+
+    let (_, reason) = state.step_cycle();
+    assert_eq!(StopReason::Halted(Halt::Jam), reason);
+}
@@ -0,0 +1,206 @@
+use super::generate_test_cpu_with_instructions;
+use crate::address_map::Addressable;
+use crate::cpu::{
+    mos6502::{
+        fault::{CpuFault, Fault, FaultPolicy},
+        register::{GPRegister, GeneralPurpose},
+    },
+    register::Register,
+    CPU,
+};
+
+#[test]
+fn should_cycle_on_lax_absolute_indexed_with_y_operation_with_page_penalty() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xbf, 0xff, 0x60])
+        .with_gp_register(GPRegister::Y, GeneralPurpose::with_value(0x01));
+
+    // 0x6100 lies in the nop sled
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!((0xea, 0xea), (state.acc.read(), state.x.read()));
+    assert_eq!(5, state.cycles());
+}
+
+#[test]
+fn should_cycle_on_sax_zeropage_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x87, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x3c));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x30, state.address_map.read(0x10));
+}
+
+#[test]
+fn should_cycle_on_dcp_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xc7, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x00));
+    cpu.address_map.write(0x10, 0x01).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x00, state.address_map.read(0x10));
+    assert_eq!(
+        (true, true, false),
+        (state.ps.carry, state.ps.zero, state.ps.negative)
+    );
+}
+
+#[test]
+fn should_cycle_on_isc_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe7, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x10));
+    cpu.ps.carry = true;
+    cpu.address_map.write(0x10, 0xff).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x00, state.address_map.read(0x10));
+    assert_eq!(0x10, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn should_cycle_on_slo_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x07, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x01));
+    cpu.address_map.write(0x10, 0x81).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x02, state.address_map.read(0x10));
+    assert_eq!(0x03, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn should_cycle_on_rla_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x27, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xf0));
+    cpu.address_map.write(0x10, 0x40).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x80, state.address_map.read(0x10));
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((false, true), (state.ps.carry, state.ps.negative));
+}
+
+#[test]
+fn should_cycle_on_sre_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x47, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x01));
+    cpu.address_map.write(0x10, 0x03).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x01, state.address_map.read(0x10));
+    assert_eq!(0x00, state.acc.read());
+    assert_eq!((true, true), (state.ps.carry, state.ps.zero));
+}
+
+#[test]
+fn should_cycle_on_rra_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x67, 0x10])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x10));
+    cpu.address_map.write(0x10, 0x03).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x01, state.address_map.read(0x10));
+    assert_eq!(0x12, state.acc.read());
+    assert!(!state.ps.carry);
+}
+
+#[test]
+fn should_cycle_on_anc_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x2b, 0x80])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x80, state.acc.read());
+    assert_eq!((true, true), (state.ps.carry, state.ps.negative));
+}
+
+#[test]
+fn should_cycle_on_alr_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x4b, 0x03])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x01, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn should_cycle_on_arr_immediate_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6b, 0xc0])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0xff));
+    cpu.ps.carry = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0xe0, state.acc.read());
+    assert_eq!(
+        (true, true, false, false),
+        (
+            state.ps.carry,
+            state.ps.negative,
+            state.ps.overflow,
+            state.ps.zero
+        )
+    );
+}
+
+#[test]
+fn should_cycle_on_arr_immediate_operation_in_decimal_mode() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6b, 0xff])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x99));
+    cpu.ps.decimal = true;
+
+    // 0x99 rotates to 0x4c and is adjusted to 0xa2, carrying out.
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0xa2, state.acc.read());
+    assert_eq!(
+        (true, false, true),
+        (state.ps.carry, state.ps.negative, state.ps.overflow)
+    );
+}
+
+#[test]
+fn should_cycle_on_sbx_immediate_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xcb, 0x02])
+        .with_gp_register(GPRegister::ACC, GeneralPurpose::with_value(0x0f))
+        .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x03));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x01, state.x.read());
+    assert_eq!(0x0f, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn should_cycle_on_multi_byte_nop_operations() {
+    // nop #imm, nop zp, nop zp,x, nop abs, nop abs,x and a single byte nop.
+    let cpu = generate_test_cpu_with_instructions(vec![
+        0x80, 0xff, 0x04, 0xff, 0x14, 0xff, 0x0c, 0xff, 0x60, 0x1c, 0xff, 0x60, 0x1a,
+    ])
+    .with_gp_register(GPRegister::X, GeneralPurpose::with_value(0x01));
+
+    // the abs,x nop incurs a page boundary penalty.
+    let state = cpu.run(2 + 3 + 4 + 4 + 5 + 2).unwrap();
+    assert_eq!(0x600d, state.pc.read());
+    assert_eq!(20, state.cycles());
+}
+
+#[test]
+fn should_halt_on_jam_regardless_of_fault_policy() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xea, 0x02])
+        .with_fault_policy(FaultPolicy::Ignore);
+
+    assert_eq!(Ok(2), cpu.try_step());
+    assert_eq!(Err(Fault::new(CpuFault::Jam, 0x6001, 3)), cpu.try_step());
+
+    // the program counter is left on the jam.
+    assert_eq!(0x6001, cpu.pc.read());
+    let state = cpu.run(10).unwrap();
+    assert_eq!(0x6001, state.pc.read());
+    assert_eq!(4, state.cycles());
+    assert_eq!(Some(&Fault::new(CpuFault::Jam, 0x6001, 3)), state.halted());
+}
@@ -0,0 +1,28 @@
+use crate::cpu::mos6502::Variant;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of cycles that an OAM DMA transfer of a page stalls the cpu
+/// for, excluding the alignment cycle taken when started on an odd cycle.
+pub const OAM_DMA_CYCLES: usize = 513;
+
+gen_variant_cpu!(
+    /// Ricoh2A03 represents the cpu core of the NES. It executes the NMOS
+    /// instruction set of the MOS6502, which it dereferences to, but lacks
+    /// decimal mode. The decimal flag can still be set and cleared while
+    /// ADC and SBC always operate in binary.
+    Ricoh2A03,
+    Variant::Ricoh2A03
+);
+
+impl Ricoh2A03 {
+    /// Returns the number of cycles that an OAM DMA transfer, started on the
+    /// next cycle, stalls the cpu for. This is intended to be passed to
+    /// `with_stall` when a write to the DMA register is observed, with the
+    /// cpu's cycle count serving as the clock that the APU and DMA
+    /// synchronize against.
+    pub fn oam_dma_cycles(&self) -> usize {
+        OAM_DMA_CYCLES + self.cycles() % 2
+    }
+}
@@ -0,0 +1,97 @@
+use crate::address_map::memory::{Memory, ReadOnly};
+use crate::cpu::{
+    mos6502::register::{self, GPRegister},
+    register::Register,
+    ricoh2a03::Ricoh2A03,
+    CPU,
+};
+
+fn generate_test_cpu_with_instructions(opcodes: Vec<u8>) -> Ricoh2A03 {
+    let (start_addr, stop_addr) = (0x6000, 0x7000);
+    let mut nop_sled = [0xea; 0x7000 - 0x6000].to_vec();
+    for (index, val) in opcodes.into_iter().enumerate() {
+        nop_sled[index] = val;
+    }
+
+    Ricoh2A03::default()
+        .with_pc_register(register::ProgramCounter::with_value(start_addr))
+        .register_address_space(
+            start_addr..=stop_addr,
+            Memory::<ReadOnly>::new(0x6000, 0x7000).load(nop_sled),
+        )
+        .unwrap()
+}
+
+#[test]
+fn should_set_decimal_flag_without_enabling_decimal_mode() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xf8]);
+
+    let state = cpu.run(2).unwrap();
+    assert!(state.ps.decimal);
+    assert!(!state.decimal_mode());
+}
+
+#[test]
+fn adc_should_ignore_decimal_flag() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x69, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x09));
+    cpu.ps.decimal = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x0a, state.acc.read());
+}
+
+#[test]
+fn sbc_should_ignore_decimal_flag() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe9, 0x01])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x10));
+    cpu.ps.decimal = true;
+    cpu.ps.carry = true;
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x0f, state.acc.read());
+}
+
+#[test]
+fn should_count_cycles_across_runs() {
+    let cpu = generate_test_cpu_with_instructions(vec![]);
+
+    let state = cpu.run(4).unwrap().run(6).unwrap();
+    assert_eq!(10, state.cycles());
+    assert_eq!(0x6005, state.pc.read());
+}
+
+#[test]
+fn should_execute_nothing_while_stalled() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x01]).with_stall(513);
+
+    let state = cpu.run(513).unwrap();
+    assert_eq!(0, state.stalled());
+    assert_eq!(0x6000, state.pc.read());
+    assert_eq!(513, state.cycles());
+
+    let state = state.run(2).unwrap();
+    assert_eq!(0x01, state.acc.read());
+}
+
+#[test]
+fn should_defer_interrupts_while_stalled() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![])
+        .with_stall(4)
+        .with_irq_line(true);
+    cpu.ps.interrupt_disable = false;
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+}
+
+#[test]
+fn oam_dma_should_take_an_alignment_cycle_on_odd_cycles() {
+    let cpu = generate_test_cpu_with_instructions(vec![]);
+    assert_eq!(513, cpu.oam_dma_cycles());
+
+    // a single cycle stall leaves the cpu on an odd cycle.
+    let state = cpu.with_stall(1).run(1).unwrap();
+    assert_eq!(514, state.oam_dma_cycles());
+}
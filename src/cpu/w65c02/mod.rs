@@ -0,0 +1,13 @@
+use crate::cpu::mos6502::Variant;
+
+#[cfg(test)]
+mod tests;
+
+gen_variant_cpu!(
+    /// W65C02 represents the WDC 65C02, the CMOS revision of the 6502. It
+    /// shares its registers, microcode and execution model with the MOS6502,
+    /// which it dereferences to, differing only in the instruction set that
+    /// it decodes.
+    W65C02,
+    Variant::W65C02
+);
@@ -0,0 +1,491 @@
+use crate::address_map::{
+    memory::{Memory, ReadOnly, ReadWrite},
+    Addressable,
+};
+use crate::cpu::{
+    mos6502::{
+        fault::CpuFault,
+        operations::decode,
+        register::{self, GPRegister},
+        Variant,
+    },
+    register::Register,
+    w65c02::W65C02,
+    Cyclable, Halt, Offset, StopReason, CPU,
+};
+
+fn generate_test_cpu_with_instructions(opcodes: Vec<u8>) -> W65C02 {
+    let (start_addr, stop_addr) = (0x6000, 0x7000);
+    let mut nop_sled = [0xea; 0x7000 - 0x6000].to_vec();
+    for (index, val) in opcodes.into_iter().enumerate() {
+        nop_sled[index] = val;
+    }
+
+    W65C02::default()
+        .with_pc_register(register::ProgramCounter::with_value(start_addr))
+        .register_address_space(
+            start_addr..=stop_addr,
+            Memory::<ReadOnly>::new(0x6000, 0x7000).load(nop_sled),
+        )
+        .unwrap()
+}
+
+#[test]
+fn should_decode_every_opcode() {
+    for opcode in 0x00..=0xffu8 {
+        assert!(
+            decode(Variant::W65C02, &[opcode, 0x00, 0x00]).is_ok(),
+            "failed to decode {:#04x}",
+            opcode
+        );
+    }
+}
+
+#[test]
+fn should_decode_reserved_opcodes_as_nops_of_the_correct_size() {
+    let one_byte = (0x00..=0xffu8)
+        .filter(|op| op & 0x0f == 0x03 || (op & 0x0f == 0x0b && *op != 0xcb && *op != 0xdb));
+    let reserved = one_byte
+        .map(|op| (op, 1, 1))
+        .chain(
+            [0x02, 0x22, 0x42, 0x62, 0x82, 0xc2, 0xe2]
+                .iter()
+                .map(|&op| (op, 2, 2)),
+        )
+        .chain(vec![
+            (0x44, 2, 3),
+            (0x54, 2, 4),
+            (0xd4, 2, 4),
+            (0xf4, 2, 4),
+            (0xdc, 3, 4),
+            (0xfc, 3, 4),
+            (0x5c, 3, 8),
+        ]);
+
+    for (opcode, offset, cycles) in reserved {
+        let op = decode(Variant::W65C02, &[opcode, 0x00, 0x00]).unwrap();
+        assert_eq!(
+            (offset, cycles),
+            (op.offset(), op.cycles()),
+            "incorrect size for {:#04x}",
+            opcode
+        );
+    }
+}
+
+#[test]
+fn should_execute_reserved_opcode_as_nop() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x5c, 0x00, 0x02])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x05));
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x05, state.acc.read());
+    assert_eq!(8, state.cycles());
+}
+
+#[test]
+fn should_cycle_on_bra_relative_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x80, 0x08]);
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn bra_relative_operation_should_incur_penalty_at_page_boundary() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x80, 0xf8]);
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(4, state.cycles());
+}
+
+#[test]
+fn should_cycle_on_phx_and_plx_implied_operations() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xda, 0xa2, 0x00, 0xfa])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x85));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x85, state.address_map.read(0x01ff));
+    assert_eq!(0xfe, state.sp.read());
+
+    let state = state.run(6).unwrap();
+    assert_eq!(0x85, state.x.read());
+    assert_eq!(0xff, state.sp.read());
+    assert!(state.ps.negative);
+}
+
+#[test]
+fn should_cycle_on_phy_and_ply_implied_operations() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x5a, 0xa0, 0x01, 0x7a])
+        .with_gp_register(GPRegister::Y, register::GeneralPurpose::with_value(0x00));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x00, state.address_map.read(0x01ff));
+
+    let state = state.run(6).unwrap();
+    assert_eq!(0x00, state.y.read());
+    assert_eq!(0xff, state.sp.read());
+    assert!(state.ps.zero);
+}
+
+#[test]
+fn should_cycle_on_stz_operations() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![
+        0x64, 0x10, 0x74, 0x10, 0x9c, 0x30, 0x00, 0x9e, 0x30, 0x00,
+    ])
+    .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x01));
+    for addr in [0x10, 0x11, 0x30, 0x31].iter() {
+        cpu.address_map.write(*addr, 0xff).unwrap();
+    }
+
+    let state = cpu.run(3 + 4 + 4 + 5).unwrap();
+    assert_eq!(0x600a, state.pc.read());
+    for addr in [0x10, 0x11, 0x30, 0x31].iter() {
+        assert_eq!(0x00, state.address_map.read(*addr));
+    }
+}
+
+#[test]
+fn should_cycle_on_trb_zeropage_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x14, 0x10])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x0f));
+    cpu.address_map.write(0x10, 0x3c).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x30, state.address_map.read(0x10));
+    assert!(!state.ps.zero);
+}
+
+#[test]
+fn should_cycle_on_tsb_absolute_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x0c, 0x10, 0x00])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x0f));
+    cpu.address_map.write(0x10, 0x30).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x3f, state.address_map.read(0x10));
+    assert!(state.ps.zero);
+}
+
+#[test]
+fn should_cycle_on_zeropage_indirect_operations() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xb2, 0x10, 0x92, 0x20]);
+    // (0x10) -> 0x0030, (0x20) -> 0x0040
+    cpu.address_map.write(0x10, 0x30).unwrap();
+    cpu.address_map.write(0x20, 0x40).unwrap();
+    cpu.address_map.write(0x30, 0x85).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x85, state.acc.read());
+    assert!(state.ps.negative);
+
+    let state = state.run(5).unwrap();
+    assert_eq!(0x85, state.address_map.read(0x40));
+    assert_eq!(0x6004, state.pc.read());
+}
+
+#[test]
+fn zeropage_indirect_operation_should_wrap_within_the_zeropage() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x72, 0xff]);
+    // (0xff) -> 0x01ff
+    cpu.address_map.write(0xff, 0xff).unwrap();
+    cpu.address_map.write(0x00, 0x01).unwrap();
+    cpu.address_map.write(0x01ff, 0x05).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x05, state.acc.read());
+}
+
+#[test]
+fn should_cycle_on_jmp_absolute_indexed_indirect_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x7c, 0x10, 0x00])
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x02));
+    cpu.address_map.write(0x12, 0x34).unwrap();
+    cpu.address_map.write(0x13, 0x12).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x1234, state.pc.read());
+}
+
+#[test]
+fn jmp_indirect_operation_should_carry_into_the_next_page() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6c, 0xff, 0x00]);
+    cpu.address_map.write(0xff, 0x34).unwrap();
+    cpu.address_map.write(0x100, 0x12).unwrap();
+    cpu.address_map.write(0x00, 0x56).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x1234, state.pc.read());
+    assert_eq!(6, state.cycles());
+}
+
+#[test]
+fn should_cycle_on_inc_and_dec_accumulator_operations() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x1a, 0x3a, 0x3a])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0xff));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x00, state.acc.read());
+    assert!(state.ps.zero);
+
+    let state = state.run(4).unwrap();
+    assert_eq!(0xfe, state.acc.read());
+    assert!(state.ps.negative);
+}
+
+#[test]
+fn bit_immediate_operation_should_only_set_zero_flag() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x89, 0xc0])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x0f));
+    cpu.ps.negative = false;
+    cpu.ps.overflow = false;
+
+    let state = cpu.run(2).unwrap();
+    assert!(state.ps.zero);
+    assert!(!state.ps.negative);
+    assert!(!state.ps.overflow);
+}
+
+#[test]
+fn should_cycle_on_bit_zeropage_indexed_operation() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x34, 0x10])
+        .with_gp_register(GPRegister::ACC, register::GeneralPurpose::with_value(0x01))
+        .with_gp_register(GPRegister::X, register::GeneralPurpose::with_value(0x01));
+    cpu.address_map.write(0x11, 0xc1).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert!(!state.ps.zero);
+    assert!(state.ps.negative);
+    assert!(state.ps.overflow);
+}
+
+#[test]
+fn bbr_should_branch_when_bit_is_reset() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x2f, 0x10, 0x08]);
+    cpu.address_map.write(0x10, 0xfb).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn bbr_should_not_branch_when_bit_is_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x2f, 0x10, 0x08]);
+    cpu.address_map.write(0x10, 0x04).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+}
+
+#[test]
+fn bbs_should_branch_when_bit_is_set() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xff, 0x10, 0x08]);
+    cpu.address_map.write(0x10, 0x80).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6008, state.pc.read());
+}
+
+#[test]
+fn should_cycle_on_rmb_and_smb_operations() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x37, 0x10, 0xc7, 0x10]);
+    cpu.address_map.write(0x10, 0x0f).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x07, state.address_map.read(0x10));
+
+    let state = state.run(5).unwrap();
+    assert_eq!(0x17, state.address_map.read(0x10));
+}
+
+#[test]
+fn stp_should_halt_the_cpu() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xdb]);
+
+    let state = cpu.run(10).unwrap();
+    let fault = state.halted().unwrap();
+    assert_eq!(CpuFault::Stop, fault.kind);
+    assert_eq!(0x6000, fault.pc);
+    assert_eq!(3, state.cycles());
+}
+
+#[test]
+fn stp_should_stop_run_until_as_halted() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xdb]);
+
+    let (state, reason) = cpu.run_until(10, |_| false);
+    assert_eq!(StopReason::Halted(Halt::Stop), reason);
+    assert_eq!(3, state.unwrap().cycles());
+}
+
+#[test]
+fn wai_should_stop_run_until_as_halted() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xcb]);
+
+    let (state, reason) = cpu.run_until(10, |_| false);
+    let state = state.unwrap();
+    assert_eq!(StopReason::Halted(Halt::Wait), reason);
+    assert_eq!(3, state.cycles());
+    assert_eq!(0x6001, state.pc.read());
+}
+
+#[test]
+fn run_until_should_observe_the_variant_cpu() {
+    // stz $10
+    let cpu = generate_test_cpu_with_instructions(vec![0x64, 0x10]);
+
+    let (state, reason) = cpu.run_until(10, |cpu| cpu.last_write() == Some((0x0010, 0x00)));
+    assert_eq!(StopReason::Predicate, reason);
+    assert_eq!(Variant::W65C02, state.unwrap().variant());
+}
+
+/// A device counting the number of times it has been cloned.
+#[derive(Default)]
+struct CloneCounter {
+    clones: std::rc::Rc<std::cell::Cell<usize>>,
+}
+
+impl Clone for CloneCounter {
+    fn clone(&self) -> Self {
+        self.clones.set(self.clones.get() + 1);
+        Self {
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+impl Addressable<u16> for CloneCounter {
+    fn read(&self, _: u16) -> u8 {
+        0x00
+    }
+
+    fn write(&mut self, _: u16, data: u8) -> Result<u8, crate::address_map::WriteError<u16>> {
+        Ok(data)
+    }
+}
+
+#[test]
+fn run_until_should_not_clone_the_cpu_to_evaluate_the_predicate() {
+    let counter = CloneCounter::default();
+    let clones = counter.clones.clone();
+    let cpu = generate_test_cpu_with_instructions(vec![])
+        .register_address_space(0x0200..=0x0200, counter)
+        .unwrap();
+    let registered = clones.get();
+
+    let (_, reason) = cpu.run_until(10, |_| false);
+    assert_eq!(StopReason::Budget, reason);
+    assert_eq!(registered, clones.get());
+}
+
+fn generate_test_cpu_with_irq_vector(opcodes: Vec<u8>) -> W65C02 {
+    let mut cpu = generate_test_cpu_with_instructions(opcodes)
+        .register_address_space(0xff00..=0xffff, Memory::<ReadWrite>::new(0xff00, 0xffff))
+        .unwrap();
+    cpu.address_map.write(0xfffe, 0x00).unwrap();
+    cpu.address_map.write(0xffff, 0x62).unwrap();
+    cpu
+}
+
+#[test]
+fn wai_should_idle_until_an_interrupt_is_asserted() {
+    let mut cpu = generate_test_cpu_with_irq_vector(vec![0xcb]);
+    cpu.ps.interrupt_disable = false;
+
+    let state = cpu.run(10).unwrap();
+    assert!(state.waiting());
+    assert_eq!(0x6001, state.pc.read());
+
+    // the interrupt is serviced once the line is asserted.
+    let state = state.with_irq_line(true).run(7).unwrap();
+    assert!(!state.waiting());
+    assert_eq!(0x6200, state.pc.read());
+    assert_eq!(
+        (0x60, 0x01),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x01fe)
+        )
+    );
+}
+
+#[test]
+fn wai_should_resume_without_servicing_a_masked_interrupt() {
+    let mut cpu = generate_test_cpu_with_irq_vector(vec![0xcb]);
+    cpu.ps.interrupt_disable = true;
+
+    let state = cpu.run(10).unwrap();
+    assert!(state.waiting());
+
+    let state = state.with_irq_line(true).run(2).unwrap();
+    assert!(!state.waiting());
+    assert_eq!(0x6002, state.pc.read());
+}
+
+#[test]
+fn should_clear_decimal_mode_on_interrupt() {
+    let mut cpu = generate_test_cpu_with_irq_vector(vec![0x00]);
+    cpu.ps.decimal = true;
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6200, state.pc.read());
+    assert!(!state.ps.decimal);
+    // the pushed status should retain the decimal flag.
+    assert_eq!(0x08, state.address_map.read(0x01fd) & 0x08);
+}
+
+#[test]
+fn should_not_write_unmodified_value_on_read_modify_write() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xe6, 0x0a]);
+    cpu.address_map.write(0x0a, 0x7f).unwrap();
+
+    // stopping prior to the final cycle leaves memory untouched.
+    let state = cpu.clone().run(4).unwrap();
+    assert_eq!(0x7f, state.address_map.read(0x0a));
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x80, state.address_map.read(0x0a));
+}
+
+#[test]
+fn adc_should_set_flags_from_the_decimal_result() {
+    // SED; LDA #$99; CLC; ADC #$01
+    let cpu = generate_test_cpu_with_instructions(vec![0xf8, 0xa9, 0x99, 0x18, 0x69, 0x01]);
+
+    let state = cpu.run(9).unwrap();
+    assert_eq!(0x00, state.acc.read());
+    assert!(state.ps.zero);
+    assert!(state.ps.carry);
+    assert!(!state.ps.negative);
+}
+
+#[test]
+fn sbc_should_set_flags_from_the_decimal_result() {
+    // SED; SEC; LDA #$00; SBC #$01
+    let cpu = generate_test_cpu_with_instructions(vec![0xf8, 0x38, 0xa9, 0x00, 0xe9, 0x01]);
+
+    let state = cpu.run(9).unwrap();
+    assert_eq!(0x99, state.acc.read());
+    assert!(!state.ps.zero);
+    assert!(!state.ps.carry);
+    assert!(state.ps.negative);
+}
+
+#[test]
+fn should_take_an_extra_cycle_on_adc_and_sbc_in_decimal_mode() {
+    // NOP/SED; LDA #$99; CLC; ADC #$01
+    let binary = generate_test_cpu_with_instructions(vec![0xea, 0xa9, 0x99, 0x18, 0x69, 0x01]);
+    let decimal = generate_test_cpu_with_instructions(vec![0xf8, 0xa9, 0x99, 0x18, 0x69, 0x01]);
+
+    assert_eq!(0x9a, binary.run(8).unwrap().acc.read());
+    assert_eq!(0x99, decimal.clone().run(8).unwrap().acc.read());
+    assert_eq!(0x00, decimal.run(9).unwrap().acc.read());
+
+    // SED; SEC; LDA #$00; SBC #$01
+    let decimal = generate_test_cpu_with_instructions(vec![0xf8, 0x38, 0xa9, 0x00, 0xe9, 0x01]);
+
+    assert_eq!(0x00, decimal.clone().run(8).unwrap().acc.read());
+    assert_eq!(0x99, decimal.run(9).unwrap().acc.read());
+}
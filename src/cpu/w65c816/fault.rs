@@ -0,0 +1,98 @@
+use crate::address_map::WriteError;
+use crate::cpu::w65c816::W65C816;
+use std::fmt;
+
+/// CpuFault represents the conditions under which the cpu is unable to
+/// continue executing a program as written. Every opcode of the 65C816 is
+/// defined so, unlike the 6502, decoding can't fault.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CpuFault {
+    /// A device rejected a write, such as a write to read-only memory.
+    BusWrite {
+        address: u32,
+        value: u8,
+        reason: WriteError<u32>,
+    },
+    /// An access targeted an address that isn't backed by any device.
+    UnmappedAccess(u32),
+    /// The cpu executed a STP, stopping its clock until it is reset.
+    Stop,
+}
+
+impl std::error::Error for CpuFault {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BusWrite { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CpuFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusWrite {
+                address,
+                value,
+                reason,
+            } => write!(
+                f,
+                "failed to write {:#04x} to {:#08x}: {}",
+                value, address, reason
+            ),
+            Self::UnmappedAccess(address) => {
+                write!(f, "access to unmapped address {:#08x}", address)
+            }
+            Self::Stop => write!(f, "cpu stopped"),
+        }
+    }
+}
+
+/// Fault captures a CpuFault along with the 24-bit address of the
+/// instruction that raised it and the cycle it was raised on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fault {
+    pub kind: CpuFault,
+    pub pc: u32,
+    pub cycle: usize,
+}
+
+impl Fault {
+    pub fn new(kind: CpuFault, pc: u32, cycle: usize) -> Self {
+        Self { kind, pc, cycle }
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at pc {:#08x} on cycle {}",
+            self.kind, self.pc, self.cycle
+        )
+    }
+}
+
+impl std::error::Error for Fault {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// FaultPolicy defines how the cpu recovers from a fault.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum FaultPolicy {
+    /// Stops the cpu at the faulting instruction. A halted cpu will not
+    /// execute any further cycles until it is reset.
+    #[default]
+    Halt,
+    /// Continues execution as though the fault had not occurred. Failed
+    /// writes are dropped and an unmapped instruction is skipped as a single
+    /// byte, two cycle, no-op.
+    Ignore,
+    /// Hands the cpu and the fault to a handler, continuing execution from
+    /// the cpu it returns. The fetch of an unmapped instruction takes a
+    /// single cycle and leaves the program counter in place for the handler
+    /// to update.
+    Trap(fn(W65C816, Fault) -> W65C816),
+}
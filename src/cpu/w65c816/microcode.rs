@@ -0,0 +1,167 @@
+//! Stores single operations that perform state changes on the 65C816 these
+//! can include write operations to memory or registers and are the basic
+//! building blocks for an instruction implementation
+
+use crate::cpu::w65c816::register::{ByteRegisters, ProgramStatusFlags, WordRegisters};
+
+/// An Enumerable type to store each microcode operation possible on the
+/// 65C816 emulator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Microcode {
+    ReadMemory(ReadMemory),
+    WriteMemory(WriteMemory),
+    SetProgramStatusFlagState(SetProgramStatusFlagState),
+    Write8bitRegister(Write8bitRegister),
+    Write16bitRegister(Write16bitRegister),
+    Inc16bitRegister(Inc16bitRegister),
+    SetEmulationMode(SetEmulationMode),
+    SetInterruptLatch(SetInterruptLatch),
+    SetWaiting(SetWaiting),
+    SetStall(SetStall),
+    Stop,
+}
+
+/// Represents a read of the 24-bit memory location specified by the address
+/// field. The value read is discarded, as operands are resolved when an
+/// instruction is decoded, but the read allows devices to act on the access.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ReadMemory {
+    pub address: u32,
+}
+
+impl ReadMemory {
+    pub fn new(address: u32) -> Self {
+        Self { address }
+    }
+}
+
+/// Represents a write of the value to the 24-bit memory location specified
+/// by the address field.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WriteMemory {
+    pub address: u32,
+    pub value: u8,
+}
+
+impl WriteMemory {
+    pub fn new(address: u32, value: u8) -> Self {
+        Self { address, value }
+    }
+}
+
+/// Represents setting a single flag in the processor status register.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetProgramStatusFlagState {
+    pub flag: ProgramStatusFlags,
+    pub value: bool,
+}
+
+impl SetProgramStatusFlagState {
+    pub fn new(flag: ProgramStatusFlags, value: bool) -> Self {
+        Self { flag, value }
+    }
+}
+
+/// Represents a write of the specified 8-bit value to one of the 8-bit
+/// registers as defined by the ByteRegisters value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Write8bitRegister {
+    pub register: ByteRegisters,
+    pub value: u8,
+}
+
+impl Write8bitRegister {
+    pub fn new(register: ByteRegisters, value: u8) -> Self {
+        Self { register, value }
+    }
+}
+
+/// Represents a write of the specified 16-bit value to one of the 16-bit
+/// registers as defined by the WordRegisters value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Write16bitRegister {
+    pub register: WordRegisters,
+    pub value: u16,
+}
+
+impl Write16bitRegister {
+    pub fn new(register: WordRegisters, value: u16) -> Self {
+        Self { register, value }
+    }
+}
+
+/// Represents a wrapping increment of one of the 16-bit registers as defined
+/// by the WordRegisters value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inc16bitRegister {
+    pub register: WordRegisters,
+    pub value: u16,
+}
+
+impl Inc16bitRegister {
+    pub fn new(register: WordRegisters, value: u16) -> Self {
+        Self { register, value }
+    }
+}
+
+/// Represents switching between emulation and native mode. Entering
+/// emulation mode forces 8-bit registers and confines the stack to the
+/// first page.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetEmulationMode {
+    pub value: bool,
+}
+
+impl SetEmulationMode {
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+/// Represents each edge-triggered interrupt input of the 65C816.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterruptLine {
+    Nmi,
+    Abort,
+}
+
+/// Represents a store of the last sampled level of an edge-triggered
+/// interrupt line. This is used to detect the edge on which the interrupt
+/// triggers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetInterruptLatch {
+    pub line: InterruptLine,
+    pub value: bool,
+}
+
+impl SetInterruptLatch {
+    pub fn new(line: InterruptLine, value: bool) -> Self {
+        Self { line, value }
+    }
+}
+
+/// Represents the cpu entering or leaving the low power wait state of a
+/// `wai`, where it idles until an interrupt is asserted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetWaiting {
+    pub value: bool,
+}
+
+impl SetWaiting {
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+/// Represents setting the number of cycles remaining in a stall, such as one
+/// raised for the duration of a DMA transfer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetStall {
+    pub cycles: usize,
+}
+
+impl SetStall {
+    pub fn new(cycles: usize) -> Self {
+        Self { cycles }
+    }
+}
@@ -0,0 +1,949 @@
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+use crate::{
+    address_map::{
+        memory::{Memory, ReadWrite},
+        AddressMap, Addressable, RegistrationError, WriteError,
+    },
+    cpu::{
+        mos6502::{Execute, Generate},
+        register::Register,
+        Halt, Offset, StepState, StopReason, CPU,
+    },
+};
+
+pub mod fault;
+use fault::{CpuFault, Fault, FaultPolicy};
+
+pub mod microcode;
+use microcode::InterruptLine;
+
+#[cfg(test)]
+mod tests;
+
+pub mod register;
+use register::{
+    Bank, ByteRegisters, DirectPage, GeneralPurpose, ProcessorStatus, ProgramCounter,
+    ProgramStatusFlags, StackPointer, WordRegisters,
+};
+
+pub mod operations;
+use operations::{Interrupt, MOps, Operation};
+
+/// The cycles of an operation that remain to be executed, carried between
+/// runs when a cycle budget ends partway through the operation.
+#[derive(Debug, Clone, Default)]
+struct InFlight {
+    /// The 24-bit address of the instruction the operation was decoded
+    /// from, which any fault it raises is attributed to.
+    pc: u32,
+    steps: VecDeque<Vec<microcode::Microcode>>,
+    /// The number of steps remaining when the operand of the instruction is
+    /// read, at which point the instruction is generated again such that the
+    /// operand reflects the reads preceding it.
+    resample: Option<usize>,
+}
+
+impl InFlight {
+    fn new(pc: u32, mops: MOps) -> Self {
+        let instruction = mops.offset() > 0;
+        let sample = mops.sample_cycle().filter(|_| instruction);
+        let steps: VecDeque<_> = Vec::from(mops).into();
+
+        Self {
+            pc,
+            resample: sample.map(|cycle| steps.len() + 1 - cycle),
+            steps,
+        }
+    }
+}
+
+/// W65C816 represents the 16-bit successor to the 6502 with a 24-bit
+/// address bus. It resets into an emulation mode that behaves as a 65C02,
+/// with 8-bit registers and the stack confined to the first page, and is
+/// switched into native mode with `xce`.
+#[derive(Debug, Clone)]
+pub struct W65C816 {
+    pub(crate) address_map: AddressMap<u32>,
+    pub acc: GeneralPurpose,
+    pub x: GeneralPurpose,
+    pub y: GeneralPurpose,
+    pub sp: StackPointer,
+    pub dp: DirectPage,
+    pub pc: ProgramCounter,
+    pub dbr: Bank,
+    pub pbr: Bank,
+    pub ps: ProcessorStatus,
+    emulation: bool,
+    irq_line: bool,
+    nmi_line: bool,
+    nmi_latch: bool,
+    abort_line: bool,
+    abort_latch: bool,
+    rdy_line: bool,
+    waiting: bool,
+    fault_policy: FaultPolicy,
+    bus_fault: Option<CpuFault>,
+    halted: Option<Fault>,
+    stall: usize,
+    in_flight: InFlight,
+    last_write: Option<(u32, u8)>,
+    cycles: usize,
+}
+
+impl W65C816 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// instantiates a new W65C816 with a provided address_map.
+    #[allow(clippy::field_reassign_with_default)]
+    pub fn with_addressmap(am: AddressMap<u32>) -> Self {
+        let mut cpu = Self::default();
+        cpu.address_map = am;
+        cpu
+    }
+
+    /// Functions as a wrapper around the `with_addressmap` and `register`
+    /// methods in a way that conforms to the builder pattern and facilitates
+    /// chainability of the registration. As such this method _can_ fail and
+    /// will forward the errors from the above methods in any case that it
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mainspring::address_map::memory::{Memory, ReadOnly};
+    /// use mainspring::cpu::w65c816::W65C816;
+    ///
+    /// assert!(W65C816::default()
+    ///     .register_address_space(
+    ///         0x018000..=0x01ffff,
+    ///         Memory::<ReadOnly, u32>::new(0x018000, 0x01ffff),
+    ///     )
+    ///     .is_ok())
+    /// ```
+    pub fn register_address_space(
+        mut self,
+        space: RangeInclusive<u32>,
+        addr_space: impl Addressable<u32> + 'static,
+    ) -> Result<Self, RegistrationError<u32>> {
+        let am = self.address_map;
+        self.address_map = am.register(space, Box::new(addr_space))?;
+
+        Ok(self)
+    }
+
+    /// emulates the reset process of the CPU. Over 7 cycles the cpu returns
+    /// to emulation mode with 8-bit registers, clears the direct page and
+    /// bank registers, disables interrupts and decimal mode and loads the
+    /// program counter from the reset vector in bank 0. Resetting recovers a
+    /// cpu that has halted or is waiting on an interrupt, abandons any
+    /// instruction in flight or stall, and counts the first cycle of the
+    /// sequence with the remaining cycles carried into the next run.
+    pub fn reset(self) -> StepState<Self> {
+        let mut cpu = self;
+        let vector = cpu.read_bank0_word(u32::from(operations::RESET_VECTOR));
+
+        cpu.pc = ProgramCounter::default().write(vector);
+        cpu.dp = DirectPage::default();
+        cpu.dbr = Bank::default();
+        cpu.pbr = Bank::default();
+        cpu.ps.interrupt_disable = true;
+        cpu.ps.decimal = false;
+        cpu.emulation = true;
+        cpu.waiting = false;
+        cpu.bus_fault = None;
+        cpu.halted = None;
+        cpu.stall = 0;
+        cpu.cycles += 1;
+        cpu.in_flight = InFlight {
+            pc: cpu.program_address(),
+            steps: vec![vec![]; 6].into(),
+            resample: None,
+        };
+        StepState::new(7, cpu.constrain())
+    }
+
+    /// Provides a wrapper to update the accumulator in a way that returns the
+    /// entire cpu after modification.
+    pub fn with_acc_register(mut self, reg: GeneralPurpose) -> Self {
+        self.acc = reg;
+        self
+    }
+
+    /// Provides a wrapper to update the x index register in a way that
+    /// returns the entire cpu after modification. The upper byte is cleared
+    /// if the index registers are 8 bits wide.
+    pub fn with_x_register(mut self, reg: GeneralPurpose) -> Self {
+        self.x = reg;
+        self.constrain()
+    }
+
+    /// Provides a wrapper to update the y index register in a way that
+    /// returns the entire cpu after modification. The upper byte is cleared
+    /// if the index registers are 8 bits wide.
+    pub fn with_y_register(mut self, reg: GeneralPurpose) -> Self {
+        self.y = reg;
+        self.constrain()
+    }
+
+    /// Provides a wrapper to update the stack-pointer register in a way that
+    /// returns the entire cpu after modification. The upper byte is fixed to
+    /// 0x01 in emulation mode.
+    pub fn with_sp_register(mut self, reg: StackPointer) -> Self {
+        self.sp = reg;
+        self.constrain()
+    }
+
+    /// Provides a wrapper to update the direct page register in a way that
+    /// returns the entire cpu after modification.
+    pub fn with_dp_register(mut self, reg: DirectPage) -> Self {
+        self.dp = reg;
+        self
+    }
+
+    /// Provides a wrapper to update the program-counter register in a way that
+    /// returns the entire cpu after modification.
+    pub fn with_pc_register(mut self, reg: ProgramCounter) -> Self {
+        self.pc = reg;
+        self
+    }
+
+    /// Provides a wrapper to update the data bank register in a way that
+    /// returns the entire cpu after modification.
+    pub fn with_dbr_register(mut self, reg: Bank) -> Self {
+        self.dbr = reg;
+        self
+    }
+
+    /// Provides a wrapper to update the program bank register in a way that
+    /// returns the entire cpu after modification.
+    pub fn with_pbr_register(mut self, reg: Bank) -> Self {
+        self.pbr = reg;
+        self
+    }
+
+    /// Provides a wrapper to update the processor-status register in a way that
+    /// returns the entire cpu after modification. The memory and index select
+    /// flags remain set in emulation mode.
+    pub fn with_ps_register(mut self, reg: ProcessorStatus) -> Self {
+        self.ps = reg;
+        self.constrain()
+    }
+
+    /// Switches between emulation and native mode, returning the entire cpu
+    /// after modification. Entering emulation mode selects 8-bit registers
+    /// and confines the stack to the first page.
+    pub fn with_emulation_mode(mut self, emulation: bool) -> Self {
+        self.emulation = emulation;
+        self.constrain()
+    }
+
+    /// Returns true if the cpu is in emulation mode.
+    pub fn emulation(&self) -> bool {
+        self.emulation
+    }
+
+    /// Returns true if the accumulator, and the memory operated on alongside
+    /// it, is 16 bits wide.
+    pub fn wide_accumulator(&self) -> bool {
+        !self.ps.memory_select
+    }
+
+    /// Returns true if the index registers are 16 bits wide.
+    pub fn wide_index(&self) -> bool {
+        !self.ps.index_select
+    }
+
+    /// Sets the level of the host driven maskable interrupt request line,
+    /// returning the entire cpu after modification. IRQ is level-triggered
+    /// and is serviced between instructions for as long as it is asserted
+    /// and interrupts are not disabled.
+    pub fn with_irq_line(mut self, asserted: bool) -> Self {
+        self.irq_line = asserted;
+        self
+    }
+
+    /// Sets the level of the host driven non-maskable interrupt line,
+    /// returning the entire cpu after modification. NMI is edge-triggered and
+    /// is serviced once each time the line transitions to asserted.
+    pub fn with_nmi_line(mut self, asserted: bool) -> Self {
+        self.nmi_line = asserted;
+        self
+    }
+
+    /// Sets the level of the host driven abort line, returning the entire
+    /// cpu after modification. Abort is edge-triggered and pre-empts the next
+    /// instruction, which is not executed and is returned to by the abort
+    /// handler, allowing a memory management unit to retry it.
+    pub fn with_abort_line(mut self, asserted: bool) -> Self {
+        self.abort_line = asserted;
+        self
+    }
+
+    /// Sets the level of the RDY input, returning the entire cpu after
+    /// modification. While RDY is low the cpu halts on its next read cycle,
+    /// with the halted cycles counted against any cycle budget. Writes are
+    /// not halted, but as every instruction and interrupt begins with a read
+    /// the cpu idles between instructions until RDY is raised.
+    pub fn with_rdy_line(mut self, ready: bool) -> Self {
+        self.rdy_line = ready;
+        self
+    }
+
+    /// Returns true if the RDY input is high and the cpu is free to execute.
+    pub fn ready(&self) -> bool {
+        self.rdy_line
+    }
+
+    /// Stalls the cpu for the provided number of cycles, such as for the
+    /// duration of a DMA transfer, returning the entire cpu after
+    /// modification. Stalled cycles are counted but execute nothing and any
+    /// interrupts are deferred until the stall ends.
+    pub fn with_stall(mut self, cycles: usize) -> Self {
+        self.stall += cycles;
+        self
+    }
+
+    /// Returns the number of cycles remaining in the current stall.
+    pub fn stalled(&self) -> usize {
+        self.stall
+    }
+
+    /// Returns true if either the host or any device in the address map is
+    /// asserting the maskable interrupt request line.
+    pub fn irq_asserted(&self) -> bool {
+        self.irq_line || self.address_map.irq()
+    }
+
+    /// Returns true if either the host or any device in the address map is
+    /// asserting the non-maskable interrupt line.
+    pub fn nmi_asserted(&self) -> bool {
+        self.nmi_line || self.address_map.nmi()
+    }
+
+    /// Returns true if the cpu is idling in the wait state entered by a
+    /// `wai`.
+    pub fn waiting(&self) -> bool {
+        self.waiting
+    }
+
+    /// Sets the policy used to recover from faults, returning the entire cpu
+    /// after modification.
+    pub fn with_fault_policy(mut self, policy: FaultPolicy) -> Self {
+        self.fault_policy = policy;
+        self
+    }
+
+    /// Returns true if the clock has been stopped by a `stp`, which only a
+    /// reset recovers from.
+    pub fn stopped(&self) -> bool {
+        matches!(&self.halted, Some(fault) if fault.kind == CpuFault::Stop)
+    }
+
+    /// Returns the fault the cpu halted on, if it has halted.
+    pub fn halted(&self) -> Option<&Fault> {
+        self.halted.as_ref()
+    }
+
+    /// Returns the number of cycles executed since the cpu was instantiated.
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Returns the number of cycles remaining in the operation in flight,
+    /// which is zero between operations.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.steps.len()
+    }
+
+    /// Returns the address and value of the write made on the last cycle
+    /// executed, if any.
+    pub fn last_write(&self) -> Option<(u32, u8)> {
+        self.last_write
+    }
+
+    /// Returns the 24-bit address of the program counter in the program bank.
+    pub(crate) fn program_address(&self) -> u32 {
+        (u32::from(self.pbr.read()) << 16) | u32::from(self.pc.read())
+    }
+
+    /// Reads a single byte from the 24-bit address space.
+    pub(crate) fn read(&self, address: u32) -> u8 {
+        self.address_map.read(address)
+    }
+
+    /// Reads a little-endian word from bank 0, wrapping within the bank.
+    pub(crate) fn read_bank0_word(&self, address: u32) -> u16 {
+        u16::from_le_bytes([self.read(address), self.read((address + 1) & 0xffff)])
+    }
+
+    /// Reads a little-endian 24-bit address from bank 0, wrapping within the
+    /// bank.
+    pub(crate) fn read_bank0_long(&self, address: u32) -> u32 {
+        let bank = self.read((address + 2) & 0xffff);
+        (u32::from(bank) << 16) | u32::from(self.read_bank0_word(address))
+    }
+
+    /// Returns the bank 0 address of an offset into the direct page plus an
+    /// index. In emulation mode a page aligned direct page wraps within the
+    /// page as the zero page does on the 6502.
+    pub(crate) fn direct_address(&self, offset: u8, index: u16) -> u32 {
+        let dp = self.dp.read();
+        let offset = u16::from(offset).wrapping_add(index);
+        if self.emulation && dp & 0xff == 0 {
+            u32::from(dp | (offset & 0xff))
+        } else {
+            u32::from(dp.wrapping_add(offset))
+        }
+    }
+
+    /// Returns the stack pointer offset by a number of bytes, wrapping
+    /// within the first page in emulation mode.
+    pub(crate) fn stack_offset(&self, sp: u16, offset: i16) -> u16 {
+        let sp = sp.wrapping_add(offset as u16);
+        if self.emulation {
+            0x0100 | (sp & 0xff)
+        } else {
+            sp
+        }
+    }
+
+    /// Enforces the register constraints of the current mode. Emulation mode
+    /// forces 8-bit registers and a stack in the first page while 8-bit index
+    /// registers have their upper byte cleared.
+    fn constrain(mut self) -> Self {
+        if self.emulation {
+            self.ps.memory_select = true;
+            self.ps.index_select = true;
+            self.sp = StackPointer::with_value(0x0100 | (self.sp.read() & 0xff));
+        }
+        if self.ps.index_select {
+            self.x = GeneralPurpose::with_value(self.x.read() & 0xff);
+            self.y = GeneralPurpose::with_value(self.y.read() & 0xff);
+        }
+        self
+    }
+
+    /// Executes up to `budget` cycles of the operation in flight or, if
+    /// there is none, the next operation. Faults are handled according to
+    /// the fault policy as they are raised. A read cycle while RDY is low is
+    /// counted without executing, returning after the halted cycle.
+    fn step(self, budget: usize) -> Self {
+        if self.halted.is_some() {
+            return self;
+        }
+
+        let start = self.cycles;
+        // every operation begins with a read, so the next operation isn't
+        // decoded until RDY is raised.
+        let mut cpu = if self.in_flight.steps.is_empty() && self.rdy_line {
+            self.begin_operation()
+        } else {
+            self
+        };
+
+        while cpu.cycles - start < budget && cpu.halted.is_none() {
+            let write = cpu.in_flight.steps.front().map(|step| {
+                step.iter()
+                    .any(|mc| matches!(mc, microcode::Microcode::WriteMemory(_)))
+            });
+
+            if !cpu.rdy_line && write != Some(true) {
+                cpu.cycles += 1;
+                break;
+            } else if write.is_none() {
+                break;
+            }
+
+            cpu = cpu.execute_in_flight_cycle();
+        }
+
+        cpu
+    }
+
+    /// Decodes the next operation, placing it in flight.
+    fn begin_operation(self) -> Self {
+        let pc = self.program_address();
+        let (mut cpu, mops) = self.next_operation();
+        cpu.in_flight = mops.map(|mops| InFlight::new(pc, mops)).unwrap_or_default();
+        cpu
+    }
+
+    /// Executes the next cycle of the operation in flight. The operation is
+    /// abandoned if the cpu halts.
+    fn execute_in_flight_cycle(mut self) -> Self {
+        if self.in_flight.resample == Some(self.in_flight.steps.len()) {
+            self = self.resample();
+        }
+        let step = self.in_flight.steps.pop_front().unwrap_or_default();
+        let pc = self.in_flight.pc;
+        let mut cpu = self.execute_cycle(step, pc);
+
+        if cpu.halted.is_some() {
+            cpu.in_flight = InFlight::default();
+        }
+        cpu
+    }
+
+    /// Decodes the next operation, or pending interrupt. A fault raised while
+    /// decoding is handled according to the fault policy, returning the
+    /// operation to execute in its place, which is `None` if the cpu has
+    /// halted.
+    fn next_operation(self) -> (Self, Option<MOps>) {
+        let (pc, cycle) = (self.program_address(), self.cycles);
+        match self.decode() {
+            Ok(mops) => (self, Some(mops)),
+            Err(kind) => {
+                let cpu = self.handle_fault(Fault::new(kind, pc, cycle));
+                let mops = match cpu.fault_policy {
+                    _ if cpu.halted.is_some() => None,
+                    FaultPolicy::Ignore => Some(MOps::new(1, 2, vec![])),
+                    _ => Some(MOps::new(0, 1, vec![])),
+                };
+
+                (cpu, mops)
+            }
+        }
+    }
+
+    /// Executes a single cycle of microcode, handling any fault raised by a
+    /// write according to the fault policy.
+    fn execute_cycle(mut self, step: Vec<microcode::Microcode>, pc: u32) -> Self {
+        self.last_write = None;
+        let mut cpu = step.into_iter().fold(self, |cpu, mc| mc.execute(cpu));
+        let cycle = cpu.cycles;
+        cpu.cycles += 1;
+
+        match cpu.bus_fault.take() {
+            Some(kind) => cpu.handle_fault(Fault::new(kind, pc, cycle)),
+            None => cpu,
+        }
+    }
+
+    /// Returns the reason the cpu has stopped executing, if it has halted or
+    /// is idling in the wait state between instructions.
+    fn stop_reason(&self) -> Option<StopReason<Fault>> {
+        match &self.halted {
+            Some(fault) if fault.kind == CpuFault::Stop => Some(StopReason::Halted(Halt::Stop)),
+            Some(fault) => Some(StopReason::Fault(fault.clone())),
+            None if self.waiting && self.in_flight.steps.is_empty() => {
+                Some(StopReason::Halted(Halt::Wait))
+            }
+            None => None,
+        }
+    }
+
+    /// Applies the fault policy to a fault.
+    fn handle_fault(mut self, fault: Fault) -> Self {
+        match self.fault_policy {
+            FaultPolicy::Halt => {
+                self.halted = Some(fault);
+                self
+            }
+            FaultPolicy::Ignore => self,
+            FaultPolicy::Trap(handler) => handler(self, fault),
+        }
+    }
+
+    /// Wraps the cpu in a StepState reporting the cycles left in flight.
+    fn into_step_state(self) -> StepState<Self> {
+        StepState::new(self.in_flight() + 1, self)
+    }
+
+    /// Generates the operations for the next instruction or, if one is
+    /// pending, an interrupt.
+    fn decode(&self) -> Result<MOps, CpuFault> {
+        if self.stall > 0 {
+            return Ok(MOps::new(
+                0,
+                1,
+                vec![microcode::Microcode::SetStall(microcode::SetStall::new(
+                    self.stall - 1,
+                ))],
+            ));
+        }
+
+        let nmi = self.nmi_asserted();
+        let irq = self.irq_asserted();
+        let abort = self.abort_line;
+        let nmi_edge = nmi && !self.nmi_latch;
+        let abort_edge = abort && !self.abort_latch;
+
+        // Poll interrupts between instructions, with an abort taking
+        // priority over an NMI edge and both over a level IRQ.
+        let mops = if abort_edge {
+            Interrupt::Abort.generate(self)
+        } else if nmi_edge {
+            Interrupt::Nmi.generate(self)
+        } else if irq && !self.ps.interrupt_disable {
+            Interrupt::Irq.generate(self)
+        } else if self.waiting && !irq {
+            // idle in the wait state until an interrupt is asserted.
+            MOps::new(0, 1, vec![])
+        } else {
+            self.decode_instruction()?
+        };
+
+        // an asserted interrupt wakes the cpu from the wait state, even if
+        // the interrupt itself is masked.
+        let mops = if self.waiting && (irq || nmi_edge || abort_edge) {
+            mops.with_microcode(vec![microcode::Microcode::SetWaiting(
+                microcode::SetWaiting::new(false),
+            )])
+        } else {
+            mops
+        };
+
+        // latch the sampled levels for detecting the next edges.
+        let mops = [
+            (InterruptLine::Nmi, nmi, self.nmi_latch),
+            (InterruptLine::Abort, abort, self.abort_latch),
+        ]
+        .iter()
+        .filter(|(_, level, latch)| level != latch)
+        .fold(mops, |mops, &(line, level, _)| {
+            mops.with_microcode(vec![microcode::Microcode::SetInterruptLatch(
+                microcode::SetInterruptLatch::new(line, level),
+            )])
+        });
+
+        Ok(mops)
+    }
+
+    /// Generates the operations for the instruction at the program counter,
+    /// fetched from the program bank.
+    fn decode_instruction(&self) -> Result<MOps, CpuFault> {
+        let (address, pc) = (self.program_address(), self.pc.read());
+        if !self.address_map.is_mapped(address) {
+            return Err(CpuFault::UnmappedAccess(address));
+        }
+
+        let bytes = [0u16, 1, 2, 3]
+            .map(|offset| self.read((address & 0xff0000) | u32::from(pc.wrapping_add(offset))));
+
+        Ok(Operation::decode(self, &bytes)
+            .generate(self)
+            .with_fetch(address))
+    }
+
+    /// Generates the instruction in flight again on the cycle its operand is
+    /// read. Operand values are sampled from memory when an instruction is
+    /// generated, so this allows them to observe the side effects of the
+    /// reads preceding the operand, such as a dummy read of a status
+    /// register. The remaining steps are replaced by those of the
+    /// regenerated instruction, retaining the interrupt bookkeeping
+    /// scheduled on the final step when the instruction was decoded.
+    fn resample(mut self) -> Self {
+        self.in_flight.resample = None;
+        let mut steps: Vec<Vec<microcode::Microcode>> = match self.decode_instruction() {
+            Ok(mops) => mops.into(),
+            Err(_) => return self,
+        };
+
+        let remaining = self.in_flight.steps.len();
+        let mut tail = steps.split_off(steps.len().saturating_sub(remaining));
+        let bookkeeping: Vec<_> = self
+            .in_flight
+            .steps
+            .back()
+            .into_iter()
+            .flatten()
+            .filter(|mc| {
+                matches!(
+                    mc,
+                    microcode::Microcode::SetInterruptLatch(_)
+                        | microcode::Microcode::SetWaiting(_)
+                )
+            })
+            .copied()
+            .collect();
+        if let Some(last) = tail.last_mut() {
+            last.extend(bookkeeping);
+        }
+        self.in_flight.steps = tail.into();
+        self
+    }
+}
+
+impl Default for W65C816 {
+    fn default() -> Self {
+        Self {
+            address_map: AddressMap::new()
+                .register(
+                    0x0000..=0x01FF,
+                    Box::new(Memory::<ReadWrite, u32>::new(0x0000, 0x01FF)),
+                )
+                .unwrap(),
+            acc: GeneralPurpose::default(),
+            x: GeneralPurpose::default(),
+            y: GeneralPurpose::default(),
+            sp: StackPointer::default(),
+            dp: DirectPage::default(),
+            pc: ProgramCounter::default(),
+            dbr: Bank::default(),
+            pbr: Bank::default(),
+            ps: ProcessorStatus::default(),
+            emulation: true,
+            irq_line: false,
+            nmi_line: false,
+            nmi_latch: false,
+            abort_line: false,
+            abort_latch: false,
+            rdy_line: true,
+            waiting: false,
+            fault_policy: FaultPolicy::default(),
+            bus_fault: None,
+            halted: None,
+            stall: 0,
+            in_flight: InFlight::default(),
+            last_write: None,
+            cycles: 0,
+        }
+    }
+}
+
+impl CPU<W65C816> for W65C816 {
+    type Fault = Fault;
+
+    /// Runs for exactly the cycle budget, carrying any instruction left in
+    /// flight into the next run. Faults are recovered from by the fault
+    /// policy, stopping early only if the cpu halts.
+    fn run(self, cycles: usize) -> StepState<W65C816> {
+        let target = self.cycles + cycles;
+        let mut cpu = self;
+
+        while cpu.cycles < target && cpu.halted.is_none() {
+            let budget = target - cpu.cycles;
+            cpu = cpu.step(budget);
+        }
+
+        cpu.into_step_state()
+    }
+
+    fn step_cycle(self) -> (StepState<W65C816>, StopReason<Self::Fault>) {
+        let cpu = self.step(1);
+        let reason = cpu.stop_reason().unwrap_or(StopReason::Budget);
+        (cpu.into_step_state(), reason)
+    }
+
+    /// Executes the remainder of the operation in flight or the next
+    /// operation, stopping early on a read cycle halted by RDY.
+    fn step_instruction(self) -> (StepState<W65C816>, StopReason<Self::Fault>) {
+        let cpu = self.step(usize::MAX);
+        let reason = cpu.stop_reason().unwrap_or(StopReason::Budget);
+        (cpu.into_step_state(), reason)
+    }
+
+    fn run_until<P>(
+        self,
+        cycles: usize,
+        mut predicate: P,
+    ) -> (StepState<W65C816>, StopReason<Self::Fault>)
+    where
+        P: FnMut(&W65C816) -> bool,
+    {
+        let target = self.cycles + cycles;
+        let mut cpu = self;
+
+        let reason = loop {
+            if cpu.cycles >= target {
+                break StopReason::Budget;
+            }
+
+            cpu = cpu.step(1);
+            if let Some(reason) = cpu.stop_reason() {
+                break reason;
+            } else if predicate(&cpu) {
+                break StopReason::Predicate;
+            }
+        };
+
+        (cpu.into_step_state(), reason)
+    }
+}
+
+/// The remaining cycles of a StepState are carried by the cpu as the
+/// operation in flight, which is resumed by the next run.
+impl CPU<W65C816> for StepState<W65C816> {
+    type Fault = Fault;
+
+    fn run(self, cycles: usize) -> StepState<W65C816> {
+        self.unwrap().run(cycles)
+    }
+
+    fn step_cycle(self) -> (StepState<W65C816>, StopReason<Self::Fault>) {
+        self.unwrap().step_cycle()
+    }
+
+    fn step_instruction(self) -> (StepState<W65C816>, StopReason<Self::Fault>) {
+        self.unwrap().step_instruction()
+    }
+
+    fn run_until<P>(
+        self,
+        cycles: usize,
+        predicate: P,
+    ) -> (StepState<W65C816>, StopReason<Self::Fault>)
+    where
+        P: FnMut(&W65C816) -> bool,
+    {
+        self.unwrap().run_until(cycles, predicate)
+    }
+}
+
+// microcode execution
+
+impl Execute<W65C816> for microcode::Microcode {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        match self {
+            Self::ReadMemory(mc) => mc.execute(cpu),
+            Self::WriteMemory(mc) => mc.execute(cpu),
+            Self::SetProgramStatusFlagState(mc) => mc.execute(cpu),
+            Self::Write8bitRegister(mc) => mc.execute(cpu),
+            Self::Write16bitRegister(mc) => mc.execute(cpu),
+            Self::Inc16bitRegister(mc) => mc.execute(cpu),
+            Self::SetEmulationMode(mc) => mc.execute(cpu),
+            Self::SetInterruptLatch(mc) => mc.execute(cpu),
+            Self::SetWaiting(mc) => mc.execute(cpu),
+            Self::SetStall(mc) => mc.execute(cpu),
+            Self::Stop => {
+                let mut cpu = cpu;
+                cpu.halted = Some(Fault::new(
+                    CpuFault::Stop,
+                    cpu.program_address(),
+                    cpu.cycles,
+                ));
+                cpu
+            }
+        }
+    }
+}
+
+impl Execute<W65C816> for microcode::ReadMemory {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        cpu.address_map.read_mut(self.address);
+        cpu
+    }
+}
+
+impl Execute<W65C816> for microcode::WriteMemory {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        cpu.last_write = Some((self.address, self.value));
+        if let Err(reason) = cpu.address_map.write(self.address, self.value) {
+            let fault = match reason {
+                WriteError::Unmapped { address, .. } => CpuFault::UnmappedAccess(address),
+                reason => CpuFault::BusWrite {
+                    address: self.address,
+                    value: self.value,
+                    reason,
+                },
+            };
+
+            // only the first fault raised in a cycle is recorded.
+            cpu.bus_fault.get_or_insert(fault);
+        }
+        cpu
+    }
+}
+
+impl Execute<W65C816> for microcode::SetProgramStatusFlagState {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut status = cpu.ps;
+
+        match self.flag {
+            ProgramStatusFlags::Negative => status.negative = self.value,
+            ProgramStatusFlags::Overflow => status.overflow = self.value,
+            ProgramStatusFlags::MemorySelect => status.memory_select = self.value,
+            ProgramStatusFlags::IndexSelect => status.index_select = self.value,
+            ProgramStatusFlags::Decimal => status.decimal = self.value,
+            ProgramStatusFlags::Interrupt => status.interrupt_disable = self.value,
+            ProgramStatusFlags::Zero => status.zero = self.value,
+            ProgramStatusFlags::Carry => status.carry = self.value,
+        };
+
+        cpu.with_ps_register(status)
+    }
+}
+
+impl Execute<W65C816> for microcode::Write8bitRegister {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        match self.register {
+            ByteRegisters::DBR => cpu.dbr = Bank::with_value(self.value),
+            ByteRegisters::PBR => cpu.pbr = Bank::with_value(self.value),
+            ByteRegisters::PS => cpu.ps = ProcessorStatus::with_value(self.value),
+        };
+        cpu.constrain()
+    }
+}
+
+impl Execute<W65C816> for microcode::Write16bitRegister {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        let value = self.value;
+        match self.register {
+            WordRegisters::ACC => cpu.acc = GeneralPurpose::with_value(value),
+            WordRegisters::X => cpu.x = GeneralPurpose::with_value(value),
+            WordRegisters::Y => cpu.y = GeneralPurpose::with_value(value),
+            WordRegisters::SP => cpu.sp = StackPointer::with_value(value),
+            WordRegisters::DP => cpu.dp = DirectPage::with_value(value),
+            WordRegisters::PC => cpu.pc = ProgramCounter::with_value(value),
+        };
+        cpu.constrain()
+    }
+}
+
+impl Execute<W65C816> for microcode::Inc16bitRegister {
+    /// Increments the register, wrapping within the bank as the program
+    /// counter does.
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let value = match self.register {
+            WordRegisters::ACC => cpu.acc.read(),
+            WordRegisters::X => cpu.x.read(),
+            WordRegisters::Y => cpu.y.read(),
+            WordRegisters::SP => cpu.sp.read(),
+            WordRegisters::DP => cpu.dp.read(),
+            WordRegisters::PC => cpu.pc.read(),
+        };
+        microcode::Write16bitRegister::new(self.register, value.wrapping_add(self.value))
+            .execute(cpu)
+    }
+}
+
+impl Execute<W65C816> for microcode::SetEmulationMode {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        cpu.with_emulation_mode(self.value)
+    }
+}
+
+impl Execute<W65C816> for microcode::SetInterruptLatch {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        match self.line {
+            InterruptLine::Nmi => cpu.nmi_latch = self.value,
+            InterruptLine::Abort => cpu.abort_latch = self.value,
+        };
+        cpu
+    }
+}
+
+impl Execute<W65C816> for microcode::SetWaiting {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        cpu.waiting = self.value;
+        cpu
+    }
+}
+
+impl Execute<W65C816> for microcode::SetStall {
+    fn execute(self, cpu: W65C816) -> W65C816 {
+        let mut cpu = cpu;
+        cpu.stall = self.cycles;
+        cpu
+    }
+}
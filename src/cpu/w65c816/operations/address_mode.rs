@@ -0,0 +1,238 @@
+use crate::cpu::{
+    register::Register,
+    w65c816::{register::ProcessorStatus, W65C816},
+};
+
+/// Represents each addressing mode of the 65C816. Direct page modes are
+/// relative to the direct page register in bank 0 while absolute modes are
+/// relative to the data bank register, with the long modes supplying a full
+/// 24-bit address.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AddressMode {
+    /// `op`
+    Implied,
+    /// `op A`
+    Accumulator,
+    /// `op #$nn`, always 8 bits wide.
+    Immediate,
+    /// `op #$nn` or `op #$nnnn` depending on the memory select flag.
+    ImmediateMemory,
+    /// `op #$nn` or `op #$nnnn` depending on the index select flag.
+    ImmediateIndex,
+    /// `op $nnnn`
+    Absolute,
+    /// `op $nnnn,X`
+    AbsoluteX,
+    /// `op $nnnn,Y`
+    AbsoluteY,
+    /// `op $nnnnnn`
+    AbsoluteLong,
+    /// `op $nnnnnn,X`
+    AbsoluteLongX,
+    /// `op ($nnnn)`
+    AbsoluteIndirect,
+    /// `op [$nnnn]`
+    AbsoluteIndirectLong,
+    /// `op ($nnnn,X)`
+    AbsoluteIndexedIndirect,
+    /// `op $nn`
+    Direct,
+    /// `op $nn,X`
+    DirectX,
+    /// `op $nn,Y`
+    DirectY,
+    /// `op ($nn)`
+    DirectIndirect,
+    /// `op [$nn]`
+    DirectIndirectLong,
+    /// `op ($nn,X)`
+    DirectIndexedIndirect,
+    /// `op ($nn),Y`
+    DirectIndirectIndexed,
+    /// `op [$nn],Y`
+    DirectIndirectLongIndexed,
+    /// `op $nn,S`
+    StackRelative,
+    /// `op ($nn,S),Y`
+    StackRelativeIndirectIndexed,
+    /// `op $nn`, a signed 8-bit branch offset.
+    Relative,
+    /// `op $nnnn`, a signed 16-bit branch offset.
+    RelativeLong,
+    /// `op $dd,$ss`, the destination and source banks of a block move.
+    BlockMove,
+}
+
+impl AddressMode {
+    /// Returns the number of operand bytes following the opcode, which for
+    /// immediate modes depends on the width selected in the processor status.
+    pub fn operand_bytes(self, ps: &ProcessorStatus) -> usize {
+        match self {
+            Self::Implied | Self::Accumulator => 0,
+            Self::ImmediateMemory => 2 - ps.memory_select as usize,
+            Self::ImmediateIndex => 2 - ps.index_select as usize,
+            Self::Immediate
+            | Self::Direct
+            | Self::DirectX
+            | Self::DirectY
+            | Self::DirectIndirect
+            | Self::DirectIndirectLong
+            | Self::DirectIndexedIndirect
+            | Self::DirectIndirectIndexed
+            | Self::DirectIndirectLongIndexed
+            | Self::StackRelative
+            | Self::StackRelativeIndirectIndexed
+            | Self::Relative => 1,
+            Self::Absolute
+            | Self::AbsoluteX
+            | Self::AbsoluteY
+            | Self::AbsoluteIndirect
+            | Self::AbsoluteIndirectLong
+            | Self::AbsoluteIndexedIndirect
+            | Self::RelativeLong
+            | Self::BlockMove => 2,
+            Self::AbsoluteLong | Self::AbsoluteLongX => 3,
+        }
+    }
+
+    /// Returns the 24-bit address of the data an instruction operates on, or
+    /// `None` for modes that don't reference data in memory.
+    pub fn effective_address(self, cpu: &W65C816, operand: u32) -> Option<u32> {
+        let dbr = u32::from(cpu.dbr.read()) << 16;
+        let (x, y) = (cpu.x.read(), cpu.y.read());
+        let dp_offset = operand as u8;
+
+        let address = match self {
+            Self::Absolute => dbr | operand,
+            Self::AbsoluteX => (dbr | operand) + u32::from(x),
+            Self::AbsoluteY => (dbr | operand) + u32::from(y),
+            Self::AbsoluteLong => operand,
+            Self::AbsoluteLongX => operand + u32::from(x),
+            Self::Direct => cpu.direct_address(dp_offset, 0),
+            Self::DirectX => cpu.direct_address(dp_offset, x),
+            Self::DirectY => cpu.direct_address(dp_offset, y),
+            Self::DirectIndirect => {
+                dbr | u32::from(cpu.read_bank0_word(cpu.direct_address(dp_offset, 0)))
+            }
+            Self::DirectIndirectLong => cpu.read_bank0_long(cpu.direct_address(dp_offset, 0)),
+            Self::DirectIndexedIndirect => {
+                dbr | u32::from(cpu.read_bank0_word(cpu.direct_address(dp_offset, x)))
+            }
+            Self::DirectIndirectIndexed => {
+                (dbr | u32::from(cpu.read_bank0_word(cpu.direct_address(dp_offset, 0))))
+                    + u32::from(y)
+            }
+            Self::DirectIndirectLongIndexed => {
+                cpu.read_bank0_long(cpu.direct_address(dp_offset, 0)) + u32::from(y)
+            }
+            Self::StackRelative => u32::from(cpu.sp.read().wrapping_add(u16::from(dp_offset))),
+            Self::StackRelativeIndirectIndexed => {
+                let pointer = u32::from(cpu.sp.read().wrapping_add(u16::from(dp_offset)));
+                (dbr | u32::from(cpu.read_bank0_word(pointer))) + u32::from(y)
+            }
+            _ => return None,
+        };
+
+        Some(address & 0xffffff)
+    }
+
+    /// Returns the bank 0 addresses of the pointer that an indirect mode
+    /// reads its effective address from, in the order they are read.
+    pub fn pointer_addresses(self, cpu: &W65C816, operand: u32) -> Vec<u32> {
+        let dp_offset = operand as u8;
+        let (pointer, size) = match self {
+            Self::DirectIndirect | Self::DirectIndirectIndexed => {
+                (cpu.direct_address(dp_offset, 0), 2)
+            }
+            Self::DirectIndexedIndirect => (cpu.direct_address(dp_offset, cpu.x.read()), 2),
+            Self::DirectIndirectLong | Self::DirectIndirectLongIndexed => {
+                (cpu.direct_address(dp_offset, 0), 3)
+            }
+            Self::StackRelativeIndirectIndexed => (
+                u32::from(cpu.sp.read().wrapping_add(u16::from(dp_offset))),
+                2,
+            ),
+            _ => return vec![],
+        };
+
+        (0..size).map(|idx| (pointer + idx) & 0xffff).collect()
+    }
+
+    /// Returns the address of the byte following an address in the mode,
+    /// used to access the upper byte of 16-bit data. Direct page and stack
+    /// relative data wraps within bank 0 while all other data may cross into
+    /// the next bank.
+    pub fn next_address(self, address: u32) -> u32 {
+        match self {
+            Self::Direct | Self::DirectX | Self::DirectY | Self::StackRelative => {
+                (address + 1) & 0xffff
+            }
+            _ => (address + 1) & 0xffffff,
+        }
+    }
+
+    /// Returns the cycles taken to read an 8-bit operand in the mode,
+    /// excluding any penalties.
+    pub fn read_cycles(self) -> usize {
+        match self {
+            Self::Implied | Self::Accumulator => 2,
+            Self::Immediate | Self::ImmediateMemory | Self::ImmediateIndex => 2,
+            Self::Direct => 3,
+            Self::DirectX | Self::DirectY => 4,
+            Self::Absolute | Self::AbsoluteX | Self::AbsoluteY => 4,
+            Self::StackRelative => 4,
+            Self::AbsoluteLong | Self::AbsoluteLongX => 5,
+            Self::DirectIndirect | Self::DirectIndirectIndexed => 5,
+            Self::DirectIndirectLong
+            | Self::DirectIndirectLongIndexed
+            | Self::DirectIndexedIndirect => 6,
+            Self::StackRelativeIndirectIndexed => 7,
+            _ => 2,
+        }
+    }
+
+    /// Returns the cycle penalty for addressing relative to a direct page
+    /// that isn't aligned to a page boundary.
+    pub fn direct_page_penalty(self, cpu: &W65C816) -> usize {
+        let unaligned = cpu.dp.read() & 0xff != 0;
+        match self {
+            Self::Direct
+            | Self::DirectX
+            | Self::DirectY
+            | Self::DirectIndirect
+            | Self::DirectIndirectLong
+            | Self::DirectIndexedIndirect
+            | Self::DirectIndirectIndexed
+            | Self::DirectIndirectLongIndexed => unaligned as usize,
+            _ => 0,
+        }
+    }
+
+    /// Returns the cycle penalty a read incurs for indexing across a page
+    /// boundary, which is always taken with 16-bit index registers.
+    pub fn index_penalty(self, cpu: &W65C816, operand: u32) -> usize {
+        let base = match self {
+            Self::AbsoluteX | Self::AbsoluteY => operand,
+            Self::DirectIndirectIndexed => {
+                u32::from(cpu.read_bank0_word(cpu.direct_address(operand as u8, 0)))
+            }
+            _ => return 0,
+        };
+        let index = match self {
+            Self::AbsoluteX => cpu.x.read(),
+            _ => cpu.y.read(),
+        };
+
+        let crossed = (base & 0xff00) != (base.wrapping_add(u32::from(index)) & 0xff00);
+        (cpu.wide_index() || crossed) as usize
+    }
+
+    /// Returns the cycle penalty a write incurs for indexing, which is taken
+    /// regardless of whether a page boundary is crossed.
+    pub fn store_penalty(self) -> usize {
+        matches!(
+            self,
+            Self::AbsoluteX | Self::AbsoluteY | Self::DirectIndirectIndexed
+        ) as usize
+    }
+}
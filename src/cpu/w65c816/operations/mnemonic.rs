@@ -0,0 +1,96 @@
+/// Represents each instruction mnemonic of the 65C816.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mnemonic {
+    ADC,
+    AND,
+    ASL,
+    BCC,
+    BCS,
+    BEQ,
+    BIT,
+    BMI,
+    BNE,
+    BPL,
+    BRA,
+    BRK,
+    BRL,
+    BVC,
+    BVS,
+    CLC,
+    CLD,
+    CLI,
+    CLV,
+    CMP,
+    COP,
+    CPX,
+    CPY,
+    DEC,
+    DEX,
+    DEY,
+    EOR,
+    INC,
+    INX,
+    INY,
+    JML,
+    JMP,
+    JSL,
+    JSR,
+    LDA,
+    LDX,
+    LDY,
+    LSR,
+    MVN,
+    MVP,
+    NOP,
+    ORA,
+    PEA,
+    PEI,
+    PER,
+    PHA,
+    PHB,
+    PHD,
+    PHK,
+    PHP,
+    PHX,
+    PHY,
+    PLA,
+    PLB,
+    PLD,
+    PLP,
+    PLX,
+    PLY,
+    REP,
+    ROL,
+    ROR,
+    RTI,
+    RTL,
+    RTS,
+    SBC,
+    SEC,
+    SED,
+    SEI,
+    SEP,
+    STA,
+    STP,
+    STX,
+    STY,
+    STZ,
+    TAX,
+    TAY,
+    TCD,
+    TCS,
+    TDC,
+    TRB,
+    TSB,
+    TSC,
+    TSX,
+    TXA,
+    TXS,
+    TXY,
+    TYA,
+    TYX,
+    WAI,
+    WDM,
+    XBA,
+    XCE,
+}
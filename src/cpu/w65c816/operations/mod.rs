@@ -0,0 +1,1357 @@
+use crate::cpu::{
+    mops::BusCycle,
+    mos6502::Generate,
+    register::Register,
+    w65c816::{
+        microcode::{
+            Inc16bitRegister, Microcode, ReadMemory, SetEmulationMode, SetProgramStatusFlagState,
+            SetWaiting, Write16bitRegister, Write8bitRegister, WriteMemory,
+        },
+        register::{ByteRegisters, ProcessorStatus, ProgramStatusFlags, WordRegisters},
+        W65C816,
+    },
+    Offset,
+};
+use parcel::{
+    parsers::byte::{any_byte, expect_byte},
+    BoxedParser, ParseResult, Parser,
+};
+
+pub mod address_mode;
+use address_mode::AddressMode;
+pub mod mnemonic;
+use mnemonic::Mnemonic;
+
+/// The address of the COP vector in native mode.
+pub const NATIVE_COP_VECTOR: u16 = 0xffe4;
+/// The address of the BRK vector in native mode.
+pub const NATIVE_BRK_VECTOR: u16 = 0xffe6;
+/// The address of the ABORT vector in native mode.
+pub const NATIVE_ABORT_VECTOR: u16 = 0xffe8;
+/// The address of the NMI vector in native mode.
+pub const NATIVE_NMI_VECTOR: u16 = 0xffea;
+/// The address of the IRQ vector in native mode.
+pub const NATIVE_IRQ_VECTOR: u16 = 0xffee;
+/// The address of the COP vector in emulation mode.
+pub const EMULATION_COP_VECTOR: u16 = 0xfff4;
+/// The address of the ABORT vector in emulation mode.
+pub const EMULATION_ABORT_VECTOR: u16 = 0xfff8;
+/// The address of the NMI vector in emulation mode.
+pub const EMULATION_NMI_VECTOR: u16 = 0xfffa;
+/// The address of the reset vector, which is always taken in emulation mode.
+pub const RESET_VECTOR: u16 = 0xfffc;
+/// The address of the shared IRQ and BRK vector in emulation mode.
+pub const EMULATION_IRQ_VECTOR: u16 = 0xfffe;
+
+/// Represents the software and hardware interrupts of the 65C816, each of
+/// which loads its handler from a vector in bank 0 that differs between
+/// native and emulation mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interrupt {
+    Cop,
+    Brk,
+    Abort,
+    Nmi,
+    Irq,
+}
+
+impl Interrupt {
+    /// Returns the address of the vector that the interrupt handler is loaded
+    /// from.
+    pub fn vector(self, emulation: bool) -> u16 {
+        match (self, emulation) {
+            (Self::Cop, false) => NATIVE_COP_VECTOR,
+            (Self::Brk, false) => NATIVE_BRK_VECTOR,
+            (Self::Abort, false) => NATIVE_ABORT_VECTOR,
+            (Self::Nmi, false) => NATIVE_NMI_VECTOR,
+            (Self::Irq, false) => NATIVE_IRQ_VECTOR,
+            (Self::Cop, true) => EMULATION_COP_VECTOR,
+            (Self::Abort, true) => EMULATION_ABORT_VECTOR,
+            (Self::Nmi, true) => EMULATION_NMI_VECTOR,
+            (Self::Brk, true) | (Self::Irq, true) => EMULATION_IRQ_VECTOR,
+        }
+    }
+
+    /// Returns the addresses of the vector that the interrupt handler is
+    /// loaded from, which are read on the final cycles of the interrupt.
+    pub fn reads(self, cpu: &W65C816) -> Vec<u32> {
+        let vector = u32::from(self.vector(cpu.emulation()));
+        vec![vector, vector + 1]
+    }
+
+    /// Generates the entry into the handler of a `brk` or `cop`, which return
+    /// past their signature byte.
+    fn generate_software(self, cpu: &W65C816) -> MOps {
+        interrupt_frame(cpu, self, 2, cpu.pc.read().wrapping_add(2))
+    }
+}
+
+impl Generate<W65C816, MOps> for Interrupt {
+    /// Generates the entry into a hardware interrupt handler between
+    /// instructions. An abort returns to the instruction it pre-empted.
+    fn generate(self, cpu: &W65C816) -> MOps {
+        interrupt_frame(cpu, self, 0, cpu.pc.read())
+    }
+}
+
+/// Generates the entry into an interrupt handler. In native mode the program
+/// bank, return address and processor status are pushed while emulation mode
+/// omits the program bank and, as on the 6502, sets the break bit in the
+/// pushed status only for a `brk`. The frame is pushed from the third cycle
+/// and the vector read on the final two, after which interrupts are disabled,
+/// decimal mode cleared and the handler entered in bank 0.
+fn interrupt_frame(cpu: &W65C816, interrupt: Interrupt, offset: usize, return_addr: u16) -> MOps {
+    let [lsb, msb] = return_addr.to_le_bytes();
+    let status = cpu.ps.read();
+    let frame = if cpu.emulation() {
+        let status = match interrupt {
+            Interrupt::Brk => status | 0x30,
+            _ => (status & 0xef) | 0x20,
+        };
+        push(cpu, &[msb, lsb, status])
+    } else {
+        push(cpu, &[cpu.pbr.read(), msb, lsb, status])
+    };
+    let handler = cpu.read_bank0_word(u32::from(interrupt.vector(cpu.emulation())));
+
+    let microcode = frame
+        .into_iter()
+        .chain(vec![
+            set_flag(ProgramStatusFlags::Interrupt, true),
+            set_flag(ProgramStatusFlags::Decimal, false),
+            write_byte_register(ByteRegisters::PBR, 0),
+            write_word_register(WordRegisters::PC, handler.wrapping_sub(offset as u16)),
+        ])
+        .collect();
+    with_writes_from_cycle(offset, 7 + !cpu.emulation() as usize, 3, microcode)
+        .with_reads(interrupt.reads(cpu))
+}
+
+/// MOps represents the microcode equivalent of a 65C816 instruction,
+/// scheduled cycle by cycle.
+pub type MOps = crate::cpu::mops::MOps<Microcode>;
+
+impl BusCycle for Microcode {
+    type Address = u32;
+
+    fn read(address: u32) -> Self {
+        Microcode::ReadMemory(ReadMemory::new(address))
+    }
+
+    /// Instructions are fetched from the program bank, wrapping within it.
+    fn fetch(address: u32, offset: usize) -> Self {
+        let pc = (address as u16).wrapping_add(offset as u16);
+        Self::read((address & 0xff0000) | u32::from(pc))
+    }
+
+    fn advance(offset: usize) -> Self {
+        Microcode::Inc16bitRegister(Inc16bitRegister::new(WordRegisters::PC, offset as u16))
+    }
+
+    fn accesses_memory(&self) -> bool {
+        matches!(self, Microcode::ReadMemory(_) | Microcode::WriteMemory(_))
+    }
+}
+
+/// Instantiates an operation with its writes to memory scheduled, in order
+/// and one per cycle, beginning on the provided cycle. The remaining
+/// microcode executes on the final cycle.
+fn with_writes_from_cycle(
+    offset: usize,
+    cycles: usize,
+    cycle: usize,
+    microcode: Vec<Microcode>,
+) -> MOps {
+    let (writes, microcode): (Vec<_>, Vec<_>) = microcode
+        .into_iter()
+        .partition(|mc| matches!(mc, Microcode::WriteMemory(_)));
+    MOps::new(offset, cycles, microcode).with_microcode_from_cycle(cycle, writes)
+}
+
+/// Instantiates an operation with its writes to memory scheduled, in order
+/// and one per cycle, on the final cycles of the operation.
+fn with_trailing_writes(offset: usize, cycles: usize, microcode: Vec<Microcode>) -> MOps {
+    let writes = microcode
+        .iter()
+        .filter(|mc| matches!(mc, Microcode::WriteMemory(_)))
+        .count();
+    with_writes_from_cycle(offset, cycles, cycles + 1 - writes, microcode)
+}
+
+/// Operation represents a single decoded 65C816 instruction, its operand and
+/// its size in bytes. Unlike the 6502, the size of an immediate operand
+/// depends on the register widths at the time it is decoded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Operation {
+    pub mnemonic: Mnemonic,
+    pub address_mode: AddressMode,
+    operand: u32,
+    offset: usize,
+}
+
+impl Operation {
+    pub fn new(mnemonic: Mnemonic, address_mode: AddressMode, operand: u32, offset: usize) -> Self {
+        Self {
+            mnemonic,
+            address_mode,
+            operand,
+            offset,
+        }
+    }
+
+    /// Decodes an operation from an opcode and the three bytes that follow
+    /// it, using the memory and index select flags of the cpu to size
+    /// immediate operands. Every opcode of the 65C816 is defined so decoding
+    /// can't fail.
+    pub fn decode(cpu: &W65C816, bytes: &[u8; 4]) -> Self {
+        match (OperationParser { ps: cpu.ps }).parse(bytes) {
+            Ok(parcel::MatchStatus::Match((_, op))) => op,
+            _ => unreachable!("no operation matches opcode {:#04x}", bytes[0]),
+        }
+    }
+
+    /// Returns the raw operand of the operation, with multi-byte operands
+    /// decoded from little-endian order.
+    pub fn operand(&self) -> u32 {
+        self.operand
+    }
+}
+
+impl Offset for Operation {
+    fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// Provides a wrapper type for parsing byte slices into Operations. The
+/// processor status sizes the operands of the immediate address modes.
+struct OperationParser {
+    ps: ProcessorStatus,
+}
+
+impl<'a> Parser<'a, &'a [u8], Operation> for OperationParser {
+    fn parse(&self, input: &'a [u8]) -> ParseResult<&'a [u8], Operation> {
+        let ps = self.ps;
+        let parsers: Vec<BoxedParser<'a, &'a [u8], Operation>> = OPCODES
+            .iter()
+            .map(|&(opcode, mnemonic, address_mode)| {
+                let operand_bytes = address_mode.operand_bytes(&ps);
+                expect_byte(opcode)
+                    .and_then(move |_| parcel::take_n(any_byte(), operand_bytes))
+                    .map(move |bytes| {
+                        let operand = bytes
+                            .iter()
+                            .rev()
+                            .fold(0u32, |acc, &byte| (acc << 8) | u32::from(byte));
+                        Operation::new(mnemonic, address_mode, operand, operand_bytes + 1)
+                    })
+            })
+            .collect();
+
+        parcel::one_of(parsers).parse(input)
+    }
+}
+
+/// The mnemonic and address mode of each of the 256 opcodes of the 65C816.
+const OPCODES: [(u8, Mnemonic, AddressMode); 256] = [
+    (0x00, Mnemonic::BRK, AddressMode::Immediate),
+    (0x01, Mnemonic::ORA, AddressMode::DirectIndexedIndirect),
+    (0x02, Mnemonic::COP, AddressMode::Immediate),
+    (0x03, Mnemonic::ORA, AddressMode::StackRelative),
+    (0x04, Mnemonic::TSB, AddressMode::Direct),
+    (0x05, Mnemonic::ORA, AddressMode::Direct),
+    (0x06, Mnemonic::ASL, AddressMode::Direct),
+    (0x07, Mnemonic::ORA, AddressMode::DirectIndirectLong),
+    (0x08, Mnemonic::PHP, AddressMode::Implied),
+    (0x09, Mnemonic::ORA, AddressMode::ImmediateMemory),
+    (0x0a, Mnemonic::ASL, AddressMode::Accumulator),
+    (0x0b, Mnemonic::PHD, AddressMode::Implied),
+    (0x0c, Mnemonic::TSB, AddressMode::Absolute),
+    (0x0d, Mnemonic::ORA, AddressMode::Absolute),
+    (0x0e, Mnemonic::ASL, AddressMode::Absolute),
+    (0x0f, Mnemonic::ORA, AddressMode::AbsoluteLong),
+    (0x10, Mnemonic::BPL, AddressMode::Relative),
+    (0x11, Mnemonic::ORA, AddressMode::DirectIndirectIndexed),
+    (0x12, Mnemonic::ORA, AddressMode::DirectIndirect),
+    (
+        0x13,
+        Mnemonic::ORA,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0x14, Mnemonic::TRB, AddressMode::Direct),
+    (0x15, Mnemonic::ORA, AddressMode::DirectX),
+    (0x16, Mnemonic::ASL, AddressMode::DirectX),
+    (0x17, Mnemonic::ORA, AddressMode::DirectIndirectLongIndexed),
+    (0x18, Mnemonic::CLC, AddressMode::Implied),
+    (0x19, Mnemonic::ORA, AddressMode::AbsoluteY),
+    (0x1a, Mnemonic::INC, AddressMode::Accumulator),
+    (0x1b, Mnemonic::TCS, AddressMode::Implied),
+    (0x1c, Mnemonic::TRB, AddressMode::Absolute),
+    (0x1d, Mnemonic::ORA, AddressMode::AbsoluteX),
+    (0x1e, Mnemonic::ASL, AddressMode::AbsoluteX),
+    (0x1f, Mnemonic::ORA, AddressMode::AbsoluteLongX),
+    (0x20, Mnemonic::JSR, AddressMode::Absolute),
+    (0x21, Mnemonic::AND, AddressMode::DirectIndexedIndirect),
+    (0x22, Mnemonic::JSL, AddressMode::AbsoluteLong),
+    (0x23, Mnemonic::AND, AddressMode::StackRelative),
+    (0x24, Mnemonic::BIT, AddressMode::Direct),
+    (0x25, Mnemonic::AND, AddressMode::Direct),
+    (0x26, Mnemonic::ROL, AddressMode::Direct),
+    (0x27, Mnemonic::AND, AddressMode::DirectIndirectLong),
+    (0x28, Mnemonic::PLP, AddressMode::Implied),
+    (0x29, Mnemonic::AND, AddressMode::ImmediateMemory),
+    (0x2a, Mnemonic::ROL, AddressMode::Accumulator),
+    (0x2b, Mnemonic::PLD, AddressMode::Implied),
+    (0x2c, Mnemonic::BIT, AddressMode::Absolute),
+    (0x2d, Mnemonic::AND, AddressMode::Absolute),
+    (0x2e, Mnemonic::ROL, AddressMode::Absolute),
+    (0x2f, Mnemonic::AND, AddressMode::AbsoluteLong),
+    (0x30, Mnemonic::BMI, AddressMode::Relative),
+    (0x31, Mnemonic::AND, AddressMode::DirectIndirectIndexed),
+    (0x32, Mnemonic::AND, AddressMode::DirectIndirect),
+    (
+        0x33,
+        Mnemonic::AND,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0x34, Mnemonic::BIT, AddressMode::DirectX),
+    (0x35, Mnemonic::AND, AddressMode::DirectX),
+    (0x36, Mnemonic::ROL, AddressMode::DirectX),
+    (0x37, Mnemonic::AND, AddressMode::DirectIndirectLongIndexed),
+    (0x38, Mnemonic::SEC, AddressMode::Implied),
+    (0x39, Mnemonic::AND, AddressMode::AbsoluteY),
+    (0x3a, Mnemonic::DEC, AddressMode::Accumulator),
+    (0x3b, Mnemonic::TSC, AddressMode::Implied),
+    (0x3c, Mnemonic::BIT, AddressMode::AbsoluteX),
+    (0x3d, Mnemonic::AND, AddressMode::AbsoluteX),
+    (0x3e, Mnemonic::ROL, AddressMode::AbsoluteX),
+    (0x3f, Mnemonic::AND, AddressMode::AbsoluteLongX),
+    (0x40, Mnemonic::RTI, AddressMode::Implied),
+    (0x41, Mnemonic::EOR, AddressMode::DirectIndexedIndirect),
+    (0x42, Mnemonic::WDM, AddressMode::Immediate),
+    (0x43, Mnemonic::EOR, AddressMode::StackRelative),
+    (0x44, Mnemonic::MVP, AddressMode::BlockMove),
+    (0x45, Mnemonic::EOR, AddressMode::Direct),
+    (0x46, Mnemonic::LSR, AddressMode::Direct),
+    (0x47, Mnemonic::EOR, AddressMode::DirectIndirectLong),
+    (0x48, Mnemonic::PHA, AddressMode::Implied),
+    (0x49, Mnemonic::EOR, AddressMode::ImmediateMemory),
+    (0x4a, Mnemonic::LSR, AddressMode::Accumulator),
+    (0x4b, Mnemonic::PHK, AddressMode::Implied),
+    (0x4c, Mnemonic::JMP, AddressMode::Absolute),
+    (0x4d, Mnemonic::EOR, AddressMode::Absolute),
+    (0x4e, Mnemonic::LSR, AddressMode::Absolute),
+    (0x4f, Mnemonic::EOR, AddressMode::AbsoluteLong),
+    (0x50, Mnemonic::BVC, AddressMode::Relative),
+    (0x51, Mnemonic::EOR, AddressMode::DirectIndirectIndexed),
+    (0x52, Mnemonic::EOR, AddressMode::DirectIndirect),
+    (
+        0x53,
+        Mnemonic::EOR,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0x54, Mnemonic::MVN, AddressMode::BlockMove),
+    (0x55, Mnemonic::EOR, AddressMode::DirectX),
+    (0x56, Mnemonic::LSR, AddressMode::DirectX),
+    (0x57, Mnemonic::EOR, AddressMode::DirectIndirectLongIndexed),
+    (0x58, Mnemonic::CLI, AddressMode::Implied),
+    (0x59, Mnemonic::EOR, AddressMode::AbsoluteY),
+    (0x5a, Mnemonic::PHY, AddressMode::Implied),
+    (0x5b, Mnemonic::TCD, AddressMode::Implied),
+    (0x5c, Mnemonic::JML, AddressMode::AbsoluteLong),
+    (0x5d, Mnemonic::EOR, AddressMode::AbsoluteX),
+    (0x5e, Mnemonic::LSR, AddressMode::AbsoluteX),
+    (0x5f, Mnemonic::EOR, AddressMode::AbsoluteLongX),
+    (0x60, Mnemonic::RTS, AddressMode::Implied),
+    (0x61, Mnemonic::ADC, AddressMode::DirectIndexedIndirect),
+    (0x62, Mnemonic::PER, AddressMode::RelativeLong),
+    (0x63, Mnemonic::ADC, AddressMode::StackRelative),
+    (0x64, Mnemonic::STZ, AddressMode::Direct),
+    (0x65, Mnemonic::ADC, AddressMode::Direct),
+    (0x66, Mnemonic::ROR, AddressMode::Direct),
+    (0x67, Mnemonic::ADC, AddressMode::DirectIndirectLong),
+    (0x68, Mnemonic::PLA, AddressMode::Implied),
+    (0x69, Mnemonic::ADC, AddressMode::ImmediateMemory),
+    (0x6a, Mnemonic::ROR, AddressMode::Accumulator),
+    (0x6b, Mnemonic::RTL, AddressMode::Implied),
+    (0x6c, Mnemonic::JMP, AddressMode::AbsoluteIndirect),
+    (0x6d, Mnemonic::ADC, AddressMode::Absolute),
+    (0x6e, Mnemonic::ROR, AddressMode::Absolute),
+    (0x6f, Mnemonic::ADC, AddressMode::AbsoluteLong),
+    (0x70, Mnemonic::BVS, AddressMode::Relative),
+    (0x71, Mnemonic::ADC, AddressMode::DirectIndirectIndexed),
+    (0x72, Mnemonic::ADC, AddressMode::DirectIndirect),
+    (
+        0x73,
+        Mnemonic::ADC,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0x74, Mnemonic::STZ, AddressMode::DirectX),
+    (0x75, Mnemonic::ADC, AddressMode::DirectX),
+    (0x76, Mnemonic::ROR, AddressMode::DirectX),
+    (0x77, Mnemonic::ADC, AddressMode::DirectIndirectLongIndexed),
+    (0x78, Mnemonic::SEI, AddressMode::Implied),
+    (0x79, Mnemonic::ADC, AddressMode::AbsoluteY),
+    (0x7a, Mnemonic::PLY, AddressMode::Implied),
+    (0x7b, Mnemonic::TDC, AddressMode::Implied),
+    (0x7c, Mnemonic::JMP, AddressMode::AbsoluteIndexedIndirect),
+    (0x7d, Mnemonic::ADC, AddressMode::AbsoluteX),
+    (0x7e, Mnemonic::ROR, AddressMode::AbsoluteX),
+    (0x7f, Mnemonic::ADC, AddressMode::AbsoluteLongX),
+    (0x80, Mnemonic::BRA, AddressMode::Relative),
+    (0x81, Mnemonic::STA, AddressMode::DirectIndexedIndirect),
+    (0x82, Mnemonic::BRL, AddressMode::RelativeLong),
+    (0x83, Mnemonic::STA, AddressMode::StackRelative),
+    (0x84, Mnemonic::STY, AddressMode::Direct),
+    (0x85, Mnemonic::STA, AddressMode::Direct),
+    (0x86, Mnemonic::STX, AddressMode::Direct),
+    (0x87, Mnemonic::STA, AddressMode::DirectIndirectLong),
+    (0x88, Mnemonic::DEY, AddressMode::Implied),
+    (0x89, Mnemonic::BIT, AddressMode::ImmediateMemory),
+    (0x8a, Mnemonic::TXA, AddressMode::Implied),
+    (0x8b, Mnemonic::PHB, AddressMode::Implied),
+    (0x8c, Mnemonic::STY, AddressMode::Absolute),
+    (0x8d, Mnemonic::STA, AddressMode::Absolute),
+    (0x8e, Mnemonic::STX, AddressMode::Absolute),
+    (0x8f, Mnemonic::STA, AddressMode::AbsoluteLong),
+    (0x90, Mnemonic::BCC, AddressMode::Relative),
+    (0x91, Mnemonic::STA, AddressMode::DirectIndirectIndexed),
+    (0x92, Mnemonic::STA, AddressMode::DirectIndirect),
+    (
+        0x93,
+        Mnemonic::STA,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0x94, Mnemonic::STY, AddressMode::DirectX),
+    (0x95, Mnemonic::STA, AddressMode::DirectX),
+    (0x96, Mnemonic::STX, AddressMode::DirectY),
+    (0x97, Mnemonic::STA, AddressMode::DirectIndirectLongIndexed),
+    (0x98, Mnemonic::TYA, AddressMode::Implied),
+    (0x99, Mnemonic::STA, AddressMode::AbsoluteY),
+    (0x9a, Mnemonic::TXS, AddressMode::Implied),
+    (0x9b, Mnemonic::TXY, AddressMode::Implied),
+    (0x9c, Mnemonic::STZ, AddressMode::Absolute),
+    (0x9d, Mnemonic::STA, AddressMode::AbsoluteX),
+    (0x9e, Mnemonic::STZ, AddressMode::AbsoluteX),
+    (0x9f, Mnemonic::STA, AddressMode::AbsoluteLongX),
+    (0xa0, Mnemonic::LDY, AddressMode::ImmediateIndex),
+    (0xa1, Mnemonic::LDA, AddressMode::DirectIndexedIndirect),
+    (0xa2, Mnemonic::LDX, AddressMode::ImmediateIndex),
+    (0xa3, Mnemonic::LDA, AddressMode::StackRelative),
+    (0xa4, Mnemonic::LDY, AddressMode::Direct),
+    (0xa5, Mnemonic::LDA, AddressMode::Direct),
+    (0xa6, Mnemonic::LDX, AddressMode::Direct),
+    (0xa7, Mnemonic::LDA, AddressMode::DirectIndirectLong),
+    (0xa8, Mnemonic::TAY, AddressMode::Implied),
+    (0xa9, Mnemonic::LDA, AddressMode::ImmediateMemory),
+    (0xaa, Mnemonic::TAX, AddressMode::Implied),
+    (0xab, Mnemonic::PLB, AddressMode::Implied),
+    (0xac, Mnemonic::LDY, AddressMode::Absolute),
+    (0xad, Mnemonic::LDA, AddressMode::Absolute),
+    (0xae, Mnemonic::LDX, AddressMode::Absolute),
+    (0xaf, Mnemonic::LDA, AddressMode::AbsoluteLong),
+    (0xb0, Mnemonic::BCS, AddressMode::Relative),
+    (0xb1, Mnemonic::LDA, AddressMode::DirectIndirectIndexed),
+    (0xb2, Mnemonic::LDA, AddressMode::DirectIndirect),
+    (
+        0xb3,
+        Mnemonic::LDA,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0xb4, Mnemonic::LDY, AddressMode::DirectX),
+    (0xb5, Mnemonic::LDA, AddressMode::DirectX),
+    (0xb6, Mnemonic::LDX, AddressMode::DirectY),
+    (0xb7, Mnemonic::LDA, AddressMode::DirectIndirectLongIndexed),
+    (0xb8, Mnemonic::CLV, AddressMode::Implied),
+    (0xb9, Mnemonic::LDA, AddressMode::AbsoluteY),
+    (0xba, Mnemonic::TSX, AddressMode::Implied),
+    (0xbb, Mnemonic::TYX, AddressMode::Implied),
+    (0xbc, Mnemonic::LDY, AddressMode::AbsoluteX),
+    (0xbd, Mnemonic::LDA, AddressMode::AbsoluteX),
+    (0xbe, Mnemonic::LDX, AddressMode::AbsoluteY),
+    (0xbf, Mnemonic::LDA, AddressMode::AbsoluteLongX),
+    (0xc0, Mnemonic::CPY, AddressMode::ImmediateIndex),
+    (0xc1, Mnemonic::CMP, AddressMode::DirectIndexedIndirect),
+    (0xc2, Mnemonic::REP, AddressMode::Immediate),
+    (0xc3, Mnemonic::CMP, AddressMode::StackRelative),
+    (0xc4, Mnemonic::CPY, AddressMode::Direct),
+    (0xc5, Mnemonic::CMP, AddressMode::Direct),
+    (0xc6, Mnemonic::DEC, AddressMode::Direct),
+    (0xc7, Mnemonic::CMP, AddressMode::DirectIndirectLong),
+    (0xc8, Mnemonic::INY, AddressMode::Implied),
+    (0xc9, Mnemonic::CMP, AddressMode::ImmediateMemory),
+    (0xca, Mnemonic::DEX, AddressMode::Implied),
+    (0xcb, Mnemonic::WAI, AddressMode::Implied),
+    (0xcc, Mnemonic::CPY, AddressMode::Absolute),
+    (0xcd, Mnemonic::CMP, AddressMode::Absolute),
+    (0xce, Mnemonic::DEC, AddressMode::Absolute),
+    (0xcf, Mnemonic::CMP, AddressMode::AbsoluteLong),
+    (0xd0, Mnemonic::BNE, AddressMode::Relative),
+    (0xd1, Mnemonic::CMP, AddressMode::DirectIndirectIndexed),
+    (0xd2, Mnemonic::CMP, AddressMode::DirectIndirect),
+    (
+        0xd3,
+        Mnemonic::CMP,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0xd4, Mnemonic::PEI, AddressMode::Direct),
+    (0xd5, Mnemonic::CMP, AddressMode::DirectX),
+    (0xd6, Mnemonic::DEC, AddressMode::DirectX),
+    (0xd7, Mnemonic::CMP, AddressMode::DirectIndirectLongIndexed),
+    (0xd8, Mnemonic::CLD, AddressMode::Implied),
+    (0xd9, Mnemonic::CMP, AddressMode::AbsoluteY),
+    (0xda, Mnemonic::PHX, AddressMode::Implied),
+    (0xdb, Mnemonic::STP, AddressMode::Implied),
+    (0xdc, Mnemonic::JML, AddressMode::AbsoluteIndirectLong),
+    (0xdd, Mnemonic::CMP, AddressMode::AbsoluteX),
+    (0xde, Mnemonic::DEC, AddressMode::AbsoluteX),
+    (0xdf, Mnemonic::CMP, AddressMode::AbsoluteLongX),
+    (0xe0, Mnemonic::CPX, AddressMode::ImmediateIndex),
+    (0xe1, Mnemonic::SBC, AddressMode::DirectIndexedIndirect),
+    (0xe2, Mnemonic::SEP, AddressMode::Immediate),
+    (0xe3, Mnemonic::SBC, AddressMode::StackRelative),
+    (0xe4, Mnemonic::CPX, AddressMode::Direct),
+    (0xe5, Mnemonic::SBC, AddressMode::Direct),
+    (0xe6, Mnemonic::INC, AddressMode::Direct),
+    (0xe7, Mnemonic::SBC, AddressMode::DirectIndirectLong),
+    (0xe8, Mnemonic::INX, AddressMode::Implied),
+    (0xe9, Mnemonic::SBC, AddressMode::ImmediateMemory),
+    (0xea, Mnemonic::NOP, AddressMode::Implied),
+    (0xeb, Mnemonic::XBA, AddressMode::Implied),
+    (0xec, Mnemonic::CPX, AddressMode::Absolute),
+    (0xed, Mnemonic::SBC, AddressMode::Absolute),
+    (0xee, Mnemonic::INC, AddressMode::Absolute),
+    (0xef, Mnemonic::SBC, AddressMode::AbsoluteLong),
+    (0xf0, Mnemonic::BEQ, AddressMode::Relative),
+    (0xf1, Mnemonic::SBC, AddressMode::DirectIndirectIndexed),
+    (0xf2, Mnemonic::SBC, AddressMode::DirectIndirect),
+    (
+        0xf3,
+        Mnemonic::SBC,
+        AddressMode::StackRelativeIndirectIndexed,
+    ),
+    (0xf4, Mnemonic::PEA, AddressMode::Absolute),
+    (0xf5, Mnemonic::SBC, AddressMode::DirectX),
+    (0xf6, Mnemonic::INC, AddressMode::DirectX),
+    (0xf7, Mnemonic::SBC, AddressMode::DirectIndirectLongIndexed),
+    (0xf8, Mnemonic::SED, AddressMode::Implied),
+    (0xf9, Mnemonic::SBC, AddressMode::AbsoluteY),
+    (0xfa, Mnemonic::PLX, AddressMode::Implied),
+    (0xfb, Mnemonic::XCE, AddressMode::Implied),
+    (0xfc, Mnemonic::JSR, AddressMode::AbsoluteIndexedIndirect),
+    (0xfd, Mnemonic::SBC, AddressMode::AbsoluteX),
+    (0xfe, Mnemonic::INC, AddressMode::AbsoluteX),
+    (0xff, Mnemonic::SBC, AddressMode::AbsoluteLongX),
+];
+
+impl Generate<W65C816, MOps> for Operation {
+    fn generate(self, cpu: &W65C816) -> MOps {
+        match self.mnemonic {
+            Mnemonic::ADC
+            | Mnemonic::AND
+            | Mnemonic::BIT
+            | Mnemonic::CMP
+            | Mnemonic::EOR
+            | Mnemonic::LDA
+            | Mnemonic::ORA
+            | Mnemonic::SBC => self.generate_accumulator_read(cpu),
+            Mnemonic::CPX | Mnemonic::CPY | Mnemonic::LDX | Mnemonic::LDY => {
+                self.generate_index_read(cpu)
+            }
+            Mnemonic::STA | Mnemonic::STX | Mnemonic::STY | Mnemonic::STZ => {
+                self.generate_store(cpu)
+            }
+            Mnemonic::ASL
+            | Mnemonic::DEC
+            | Mnemonic::INC
+            | Mnemonic::LSR
+            | Mnemonic::ROL
+            | Mnemonic::ROR
+            | Mnemonic::TRB
+            | Mnemonic::TSB => self.generate_read_modify_write(cpu),
+            Mnemonic::BCC
+            | Mnemonic::BCS
+            | Mnemonic::BEQ
+            | Mnemonic::BMI
+            | Mnemonic::BNE
+            | Mnemonic::BPL
+            | Mnemonic::BRA
+            | Mnemonic::BRL
+            | Mnemonic::BVC
+            | Mnemonic::BVS => self.generate_branch(cpu),
+            Mnemonic::JML
+            | Mnemonic::JMP
+            | Mnemonic::JSL
+            | Mnemonic::JSR
+            | Mnemonic::RTI
+            | Mnemonic::RTL
+            | Mnemonic::RTS => self.generate_jump(cpu),
+            Mnemonic::PEA
+            | Mnemonic::PEI
+            | Mnemonic::PER
+            | Mnemonic::PHA
+            | Mnemonic::PHB
+            | Mnemonic::PHD
+            | Mnemonic::PHK
+            | Mnemonic::PHP
+            | Mnemonic::PHX
+            | Mnemonic::PHY
+            | Mnemonic::PLA
+            | Mnemonic::PLB
+            | Mnemonic::PLD
+            | Mnemonic::PLP
+            | Mnemonic::PLX
+            | Mnemonic::PLY => self.generate_stack(cpu),
+            Mnemonic::MVN | Mnemonic::MVP => self.generate_block_move(cpu),
+            Mnemonic::BRK
+            | Mnemonic::CLC
+            | Mnemonic::CLD
+            | Mnemonic::CLI
+            | Mnemonic::CLV
+            | Mnemonic::COP
+            | Mnemonic::DEX
+            | Mnemonic::DEY
+            | Mnemonic::INX
+            | Mnemonic::INY
+            | Mnemonic::NOP
+            | Mnemonic::REP
+            | Mnemonic::SEC
+            | Mnemonic::SED
+            | Mnemonic::SEI
+            | Mnemonic::SEP
+            | Mnemonic::STP
+            | Mnemonic::TAX
+            | Mnemonic::TAY
+            | Mnemonic::TCD
+            | Mnemonic::TCS
+            | Mnemonic::TDC
+            | Mnemonic::TSC
+            | Mnemonic::TSX
+            | Mnemonic::TXA
+            | Mnemonic::TXS
+            | Mnemonic::TXY
+            | Mnemonic::TYA
+            | Mnemonic::TYX
+            | Mnemonic::WAI
+            | Mnemonic::WDM
+            | Mnemonic::XBA
+            | Mnemonic::XCE => self.generate_implied(cpu),
+        }
+    }
+}
+
+impl Operation {
+    /// Returns the addresses that the operand of the instruction is read
+    /// from at the provided width, following any pointer it is addressed
+    /// through.
+    fn operand_reads(&self, cpu: &W65C816, wide: bool) -> Vec<u32> {
+        let mode = self.address_mode;
+        let data = match mode.effective_address(cpu, self.operand) {
+            Some(address) if wide => vec![address, mode.next_address(address)],
+            Some(address) => vec![address],
+            None => vec![],
+        };
+
+        [mode.pointer_addresses(cpu, self.operand), data].concat()
+    }
+
+    /// Reads the operand of the instruction, either from the instruction
+    /// stream, the accumulator or memory, at the provided width.
+    fn read_operand(&self, cpu: &W65C816, wide: bool) -> u16 {
+        let mode = self.address_mode;
+        match mode.effective_address(cpu, self.operand) {
+            Some(address) if wide => {
+                u16::from_le_bytes([cpu.read(address), cpu.read(mode.next_address(address))])
+            }
+            Some(address) => u16::from(cpu.read(address)),
+            None if mode == AddressMode::Accumulator => cpu.acc.read() & mask(wide),
+            None => self.operand as u16,
+        }
+    }
+
+    /// Generates the microcode to write a result back to the operand of the
+    /// instruction, either the accumulator or memory, at the provided width.
+    fn write_operand(&self, cpu: &W65C816, value: u16, wide: bool) -> Vec<Microcode> {
+        let mode = self.address_mode;
+        match mode.effective_address(cpu, self.operand) {
+            Some(address) => {
+                let [lsb, msb] = value.to_le_bytes();
+                let mut microcode = vec![write_memory(address, lsb)];
+                if wide {
+                    microcode.push(write_memory(mode.next_address(address), msb));
+                }
+                microcode
+            }
+            None => vec![write_accumulator(cpu, value, wide)],
+        }
+    }
+
+    fn generate_accumulator_read(&self, cpu: &W65C816) -> MOps {
+        let mode = self.address_mode;
+        let wide = cpu.wide_accumulator();
+        let cycles = mode.read_cycles()
+            + wide as usize
+            + mode.direct_page_penalty(cpu)
+            + mode.index_penalty(cpu, self.operand);
+        let (acc, value) = (cpu.acc.read() & mask(wide), self.read_operand(cpu, wide));
+
+        let microcode = match self.mnemonic {
+            Mnemonic::LDA => load_accumulator(cpu, value, wide),
+            Mnemonic::AND => load_accumulator(cpu, acc & value, wide),
+            Mnemonic::EOR => load_accumulator(cpu, acc ^ value, wide),
+            Mnemonic::ORA => load_accumulator(cpu, acc | value, wide),
+            Mnemonic::ADC => add_with_carry(cpu, acc, value, wide),
+            Mnemonic::SBC => subtract_with_borrow(cpu, acc, value, wide),
+            Mnemonic::CMP => compare(acc, value, wide),
+            // bit immediate only affects the zero flag.
+            _ if mode == AddressMode::ImmediateMemory => {
+                vec![set_flag(ProgramStatusFlags::Zero, acc & value == 0)]
+            }
+            _ => vec![
+                set_flag(ProgramStatusFlags::Negative, value & sign(wide) != 0),
+                set_flag(ProgramStatusFlags::Overflow, value & (sign(wide) >> 1) != 0),
+                set_flag(ProgramStatusFlags::Zero, acc & value == 0),
+            ],
+        };
+
+        MOps::new(self.offset(), cycles, microcode).with_reads(self.operand_reads(cpu, wide))
+    }
+
+    fn generate_index_read(&self, cpu: &W65C816) -> MOps {
+        let mode = self.address_mode;
+        let wide = cpu.wide_index();
+        let cycles = mode.read_cycles()
+            + wide as usize
+            + mode.direct_page_penalty(cpu)
+            + mode.index_penalty(cpu, self.operand);
+        let value = self.read_operand(cpu, wide);
+
+        let microcode = match self.mnemonic {
+            Mnemonic::LDX => load_register(WordRegisters::X, value, wide),
+            Mnemonic::LDY => load_register(WordRegisters::Y, value, wide),
+            Mnemonic::CPX => compare(cpu.x.read(), value, wide),
+            _ => compare(cpu.y.read(), value, wide),
+        };
+
+        MOps::new(self.offset(), cycles, microcode).with_reads(self.operand_reads(cpu, wide))
+    }
+
+    fn generate_store(&self, cpu: &W65C816) -> MOps {
+        let mode = self.address_mode;
+        let (value, wide) = match self.mnemonic {
+            Mnemonic::STA => (cpu.acc.read(), cpu.wide_accumulator()),
+            Mnemonic::STX => (cpu.x.read(), cpu.wide_index()),
+            Mnemonic::STY => (cpu.y.read(), cpu.wide_index()),
+            _ => (0, cpu.wide_accumulator()),
+        };
+        let cycles = mode.read_cycles()
+            + wide as usize
+            + mode.direct_page_penalty(cpu)
+            + mode.store_penalty();
+
+        let pointer = mode.pointer_addresses(cpu, self.operand);
+        with_trailing_writes(self.offset(), cycles, self.write_operand(cpu, value, wide))
+            .with_reads(pointer)
+    }
+
+    fn generate_read_modify_write(&self, cpu: &W65C816) -> MOps {
+        let mode = self.address_mode;
+        let wide = cpu.wide_accumulator();
+        let cycles = match mode {
+            AddressMode::Accumulator => 2,
+            AddressMode::Direct => 5,
+            AddressMode::DirectX | AddressMode::Absolute => 6,
+            _ => 7,
+        } + mode.direct_page_penalty(cpu)
+            + if mode == AddressMode::Accumulator {
+                0
+            } else {
+                2 * wide as usize
+            };
+
+        let (value, carry) = (self.read_operand(cpu, wide), cpu.ps.carry);
+        let acc = cpu.acc.read() & mask(wide);
+        let (result, flags) = match self.mnemonic {
+            Mnemonic::ASL => {
+                let result = value << 1;
+                (result, shift_flags(result, value & sign(wide) != 0, wide))
+            }
+            Mnemonic::LSR => (value >> 1, shift_flags(value >> 1, value & 1 != 0, wide)),
+            Mnemonic::ROL => {
+                let result = (value << 1) | carry as u16;
+                (result, shift_flags(result, value & sign(wide) != 0, wide))
+            }
+            Mnemonic::ROR => {
+                let result = (value >> 1) | if carry { sign(wide) } else { 0 };
+                (result, shift_flags(result, value & 1 != 0, wide))
+            }
+            Mnemonic::INC => {
+                let result = value.wrapping_add(1);
+                (result, negative_and_zero(result, wide))
+            }
+            Mnemonic::DEC => {
+                let result = value.wrapping_sub(1);
+                (result, negative_and_zero(result, wide))
+            }
+            Mnemonic::TSB => (
+                value | acc,
+                vec![set_flag(ProgramStatusFlags::Zero, acc & value == 0)],
+            ),
+            _ => (
+                value & !acc,
+                vec![set_flag(ProgramStatusFlags::Zero, acc & value == 0)],
+            ),
+        };
+
+        let microcode = self
+            .write_operand(cpu, result & mask(wide), wide)
+            .into_iter()
+            .chain(flags)
+            .collect();
+        with_trailing_writes(self.offset(), cycles, microcode)
+            .with_reads(self.operand_reads(cpu, wide))
+    }
+
+    fn generate_branch(&self, cpu: &W65C816) -> MOps {
+        let (ps, offset) = (cpu.ps, self.offset());
+        let next = cpu.pc.read().wrapping_add(offset as u16);
+
+        let taken = match self.mnemonic {
+            Mnemonic::BCC => !ps.carry,
+            Mnemonic::BCS => ps.carry,
+            Mnemonic::BEQ => ps.zero,
+            Mnemonic::BNE => !ps.zero,
+            Mnemonic::BMI => ps.negative,
+            Mnemonic::BPL => !ps.negative,
+            Mnemonic::BVC => !ps.overflow,
+            Mnemonic::BVS => ps.overflow,
+            _ => true,
+        };
+
+        if self.mnemonic == Mnemonic::BRL {
+            let target = next.wrapping_add(self.operand as u16);
+            return MOps::new(offset, 4, jump(target, offset));
+        } else if !taken {
+            return MOps::new(offset, 2, vec![]);
+        }
+
+        // crossing a page on a taken branch only costs a cycle in emulation
+        // mode.
+        let target = next.wrapping_add(self.operand as u8 as i8 as u16);
+        let crossed = (next & 0xff00) != (target & 0xff00);
+        MOps::new(
+            offset,
+            3 + (crossed && cpu.emulation()) as usize,
+            jump(target, offset),
+        )
+    }
+
+    fn generate_jump(&self, cpu: &W65C816) -> MOps {
+        let (pc, pbr, operand) = (cpu.pc.read(), cpu.pbr.read(), self.operand);
+        let (mode, offset) = (self.address_mode, self.offset());
+        // the pointer of an indexed indirect jump is read from the program
+        // bank.
+        let indexed_pointer = {
+            let pointer = operand.wrapping_add(u32::from(cpu.x.read())) & 0xffff;
+            let bank = u32::from(pbr) << 16;
+            vec![bank | pointer, bank | ((pointer + 1) & 0xffff)]
+        };
+        let indexed_target =
+            || u16::from_le_bytes([cpu.read(indexed_pointer[0]), cpu.read(indexed_pointer[1])]);
+        let bank0_pointer = |size: u32| {
+            (0..size)
+                .map(|idx| (operand + idx) & 0xffff)
+                .collect::<Vec<_>>()
+        };
+
+        let (cycles, microcode, reads) = match (self.mnemonic, mode) {
+            (Mnemonic::JMP, AddressMode::Absolute) => (3, jump(operand as u16, offset), vec![]),
+            (Mnemonic::JMP, AddressMode::AbsoluteIndirect) => (
+                5,
+                jump(cpu.read_bank0_word(operand), offset),
+                bank0_pointer(2),
+            ),
+            (Mnemonic::JMP, _) => (6, jump(indexed_target(), offset), indexed_pointer.clone()),
+            (Mnemonic::JML, AddressMode::AbsoluteLong) => (4, jump_long(operand, offset), vec![]),
+            (Mnemonic::JML, _) => (
+                6,
+                jump_long(cpu.read_bank0_long(operand), offset),
+                bank0_pointer(3),
+            ),
+            (Mnemonic::JSR, AddressMode::Absolute) => {
+                let [lsb, msb] = pc.wrapping_add(2).to_le_bytes();
+                let microcode = [push(cpu, &[msb, lsb]), jump(operand as u16, offset)].concat();
+                (6, microcode, vec![])
+            }
+            // the return address is pushed before the pointer is read.
+            (Mnemonic::JSR, _) => {
+                let [lsb, msb] = pc.wrapping_add(2).to_le_bytes();
+                let microcode = [push(cpu, &[msb, lsb]), jump(indexed_target(), offset)].concat();
+                return with_writes_from_cycle(offset, 8, offset + 1, microcode)
+                    .with_reads(indexed_pointer);
+            }
+            (Mnemonic::JSL, _) => {
+                let [lsb, msb] = pc.wrapping_add(3).to_le_bytes();
+                let microcode = [push(cpu, &[pbr, msb, lsb]), jump_long(operand, offset)].concat();
+                (8, microcode, vec![])
+            }
+            (Mnemonic::RTS, _) => {
+                let (bytes, sp) = pull(cpu, 2);
+                let return_addr = u16::from_le_bytes([bytes[0], bytes[1]]).wrapping_add(1);
+                let microcode = [vec![sp], jump(return_addr, offset)].concat();
+                (6, microcode, stack_addresses(cpu, 2))
+            }
+            (Mnemonic::RTL, _) => {
+                let (bytes, sp) = pull(cpu, 3);
+                let return_addr = u16::from_le_bytes([bytes[0], bytes[1]]).wrapping_add(1);
+                let target = (u32::from(bytes[2]) << 16) | u32::from(return_addr);
+                let microcode = [vec![sp], jump_long(target, offset)].concat();
+                (6, microcode, stack_addresses(cpu, 3))
+            }
+            // rti additionally restores the program bank in native mode.
+            _ if cpu.emulation() => {
+                let (bytes, sp) = pull(cpu, 3);
+                let return_addr = u16::from_le_bytes([bytes[1], bytes[2]]);
+                let microcode = [
+                    vec![sp, write_byte_register(ByteRegisters::PS, bytes[0])],
+                    jump(return_addr, offset),
+                ]
+                .concat();
+                (6, microcode, stack_addresses(cpu, 3))
+            }
+            _ => {
+                let (bytes, sp) = pull(cpu, 4);
+                let return_addr = u16::from_le_bytes([bytes[1], bytes[2]]);
+                let target = (u32::from(bytes[3]) << 16) | u32::from(return_addr);
+                let microcode = [
+                    vec![sp, write_byte_register(ByteRegisters::PS, bytes[0])],
+                    jump_long(target, offset),
+                ]
+                .concat();
+                (7, microcode, stack_addresses(cpu, 4))
+            }
+        };
+
+        with_trailing_writes(offset, cycles, microcode).with_reads(reads)
+    }
+
+    fn generate_stack(&self, cpu: &W65C816) -> MOps {
+        let (wide_acc, wide_index) = (cpu.wide_accumulator(), cpu.wide_index());
+        let push_register = |value: u16, wide: bool| {
+            let [lsb, msb] = value.to_le_bytes();
+            if wide {
+                push(cpu, &[msb, lsb])
+            } else {
+                push(cpu, &[lsb])
+            }
+        };
+        let pull_register = |wide: bool| {
+            let (bytes, sp) = pull(cpu, 1 + wide as usize);
+            let value = u16::from_le_bytes([bytes[0], *bytes.get(1).unwrap_or(&0)]);
+            (value, sp, stack_addresses(cpu, bytes.len()))
+        };
+
+        let (cycles, microcode, reads) = match self.mnemonic {
+            Mnemonic::PHA => (
+                3 + wide_acc as usize,
+                push_register(cpu.acc.read(), wide_acc),
+                vec![],
+            ),
+            Mnemonic::PHX => (
+                3 + wide_index as usize,
+                push_register(cpu.x.read(), wide_index),
+                vec![],
+            ),
+            Mnemonic::PHY => (
+                3 + wide_index as usize,
+                push_register(cpu.y.read(), wide_index),
+                vec![],
+            ),
+            Mnemonic::PHP => (3, push(cpu, &[cpu.ps.read()]), vec![]),
+            Mnemonic::PHB => (3, push(cpu, &[cpu.dbr.read()]), vec![]),
+            Mnemonic::PHK => (3, push(cpu, &[cpu.pbr.read()]), vec![]),
+            Mnemonic::PHD => (4, push_register(cpu.dp.read(), true), vec![]),
+            Mnemonic::PEA => (5, push_register(self.operand as u16, true), vec![]),
+            Mnemonic::PEI => {
+                let address = cpu.direct_address(self.operand as u8, 0);
+                (
+                    6 + self.address_mode.direct_page_penalty(cpu),
+                    push_register(cpu.read_bank0_word(address), true),
+                    vec![address, (address + 1) & 0xffff],
+                )
+            }
+            Mnemonic::PER => {
+                let next = cpu.pc.read().wrapping_add(self.offset() as u16);
+                (
+                    6,
+                    push_register(next.wrapping_add(self.operand as u16), true),
+                    vec![],
+                )
+            }
+            Mnemonic::PLA => {
+                let (value, sp, reads) = pull_register(wide_acc);
+                let microcode = [vec![sp], load_accumulator(cpu, value, wide_acc)].concat();
+                (4 + wide_acc as usize, microcode, reads)
+            }
+            Mnemonic::PLX => {
+                let (value, sp, reads) = pull_register(wide_index);
+                let microcode =
+                    [vec![sp], load_register(WordRegisters::X, value, wide_index)].concat();
+                (4 + wide_index as usize, microcode, reads)
+            }
+            Mnemonic::PLY => {
+                let (value, sp, reads) = pull_register(wide_index);
+                let microcode =
+                    [vec![sp], load_register(WordRegisters::Y, value, wide_index)].concat();
+                (4 + wide_index as usize, microcode, reads)
+            }
+            Mnemonic::PLB => {
+                let (value, sp, reads) = pull_register(false);
+                let microcode = [
+                    vec![sp, write_byte_register(ByteRegisters::DBR, value as u8)],
+                    negative_and_zero(value, false),
+                ]
+                .concat();
+                (4, microcode, reads)
+            }
+            Mnemonic::PLD => {
+                let (value, sp, reads) = pull_register(true);
+                let microcode = [vec![sp], load_register(WordRegisters::DP, value, true)].concat();
+                (5, microcode, reads)
+            }
+            _ => {
+                let (value, sp, reads) = pull_register(false);
+                let microcode = vec![sp, write_byte_register(ByteRegisters::PS, value as u8)];
+                (4, microcode, reads)
+            }
+        };
+
+        with_trailing_writes(self.offset(), cycles, microcode).with_reads(reads)
+    }
+
+    /// Generates a single iteration of a block move, reading the byte at X
+    /// in the source bank on the fourth cycle and writing it to Y in the
+    /// destination bank on the fifth, before decrementing the accumulator.
+    /// The instruction is re-executed until the accumulator underflows,
+    /// allowing interrupts to be serviced between bytes.
+    fn generate_block_move(&self, cpu: &W65C816) -> MOps {
+        let (destination, source) = (self.operand as u8, (self.operand >> 8) as u8);
+        let (x, y, index_mask) = (cpu.x.read(), cpu.y.read(), mask(cpu.wide_index()));
+        let source_address = (u32::from(source) << 16) | u32::from(x);
+        let value = cpu.read(source_address);
+        let (next_x, next_y) = match self.mnemonic {
+            Mnemonic::MVN => (x.wrapping_add(1), y.wrapping_add(1)),
+            _ => (x.wrapping_sub(1), y.wrapping_sub(1)),
+        };
+        let count = cpu.acc.read().wrapping_sub(1);
+
+        let microcode = vec![
+            write_word_register(WordRegisters::X, next_x & index_mask),
+            write_word_register(WordRegisters::Y, next_y & index_mask),
+            write_word_register(WordRegisters::ACC, count),
+            write_byte_register(ByteRegisters::DBR, destination),
+        ];
+        // the program counter is returned to the instruction until the move
+        // completes.
+        let microcode = if count == 0xffff {
+            microcode
+        } else {
+            [microcode, jump(cpu.pc.read(), self.offset())].concat()
+        };
+
+        MOps::new(self.offset(), 7, microcode)
+            .with_microcode_on_cycle(4, vec![Microcode::read(source_address)])
+            .with_microcode_on_cycle(
+                5,
+                vec![write_memory(
+                    (u32::from(destination) << 16) | u32::from(y),
+                    value,
+                )],
+            )
+    }
+
+    fn generate_implied(&self, cpu: &W65C816) -> MOps {
+        let (acc, x, y) = (cpu.acc.read(), cpu.x.read(), cpu.y.read());
+        let (wide_acc, wide_index) = (cpu.wide_accumulator(), cpu.wide_index());
+        let transfer = |register: WordRegisters, value: u16, wide: bool| match register {
+            WordRegisters::ACC => load_accumulator(cpu, value & mask(wide), wide),
+            _ => load_register(register, value & mask(wide), wide),
+        };
+
+        let (cycles, microcode) = match self.mnemonic {
+            Mnemonic::CLC => (2, vec![set_flag(ProgramStatusFlags::Carry, false)]),
+            Mnemonic::CLD => (2, vec![set_flag(ProgramStatusFlags::Decimal, false)]),
+            Mnemonic::CLI => (2, vec![set_flag(ProgramStatusFlags::Interrupt, false)]),
+            Mnemonic::CLV => (2, vec![set_flag(ProgramStatusFlags::Overflow, false)]),
+            Mnemonic::SEC => (2, vec![set_flag(ProgramStatusFlags::Carry, true)]),
+            Mnemonic::SED => (2, vec![set_flag(ProgramStatusFlags::Decimal, true)]),
+            Mnemonic::SEI => (2, vec![set_flag(ProgramStatusFlags::Interrupt, true)]),
+            Mnemonic::REP => {
+                let status = cpu.ps.read() & !(self.operand as u8);
+                (3, vec![write_byte_register(ByteRegisters::PS, status)])
+            }
+            Mnemonic::SEP => {
+                let status = cpu.ps.read() | self.operand as u8;
+                (3, vec![write_byte_register(ByteRegisters::PS, status)])
+            }
+            Mnemonic::XCE => (
+                2,
+                vec![
+                    set_flag(ProgramStatusFlags::Carry, cpu.emulation()),
+                    Microcode::SetEmulationMode(SetEmulationMode::new(cpu.ps.carry)),
+                ],
+            ),
+            Mnemonic::DEX => (2, transfer(WordRegisters::X, x.wrapping_sub(1), wide_index)),
+            Mnemonic::DEY => (2, transfer(WordRegisters::Y, y.wrapping_sub(1), wide_index)),
+            Mnemonic::INX => (2, transfer(WordRegisters::X, x.wrapping_add(1), wide_index)),
+            Mnemonic::INY => (2, transfer(WordRegisters::Y, y.wrapping_add(1), wide_index)),
+            Mnemonic::TAX => (2, transfer(WordRegisters::X, acc, wide_index)),
+            Mnemonic::TAY => (2, transfer(WordRegisters::Y, acc, wide_index)),
+            Mnemonic::TXA => (2, transfer(WordRegisters::ACC, x, wide_acc)),
+            Mnemonic::TYA => (2, transfer(WordRegisters::ACC, y, wide_acc)),
+            Mnemonic::TXY => (2, transfer(WordRegisters::Y, x, wide_index)),
+            Mnemonic::TYX => (2, transfer(WordRegisters::X, y, wide_index)),
+            Mnemonic::TSX => (2, transfer(WordRegisters::X, cpu.sp.read(), wide_index)),
+            Mnemonic::TSC => (2, transfer(WordRegisters::ACC, cpu.sp.read(), true)),
+            Mnemonic::TDC => (2, transfer(WordRegisters::ACC, cpu.dp.read(), true)),
+            Mnemonic::TCD => (2, transfer(WordRegisters::DP, acc, true)),
+            Mnemonic::TCS => (2, vec![write_word_register(WordRegisters::SP, acc)]),
+            Mnemonic::TXS => (2, vec![write_word_register(WordRegisters::SP, x)]),
+            Mnemonic::XBA => {
+                let swapped = acc.rotate_left(8);
+                let microcode = [
+                    vec![write_word_register(WordRegisters::ACC, swapped)],
+                    negative_and_zero(swapped, false),
+                ]
+                .concat();
+                (3, microcode)
+            }
+            Mnemonic::BRK => return Interrupt::Brk.generate_software(cpu),
+            Mnemonic::COP => return Interrupt::Cop.generate_software(cpu),
+            Mnemonic::STP => (3, vec![Microcode::Stop]),
+            Mnemonic::WAI => (3, vec![Microcode::SetWaiting(SetWaiting::new(true))]),
+            // nop and the reserved wdm.
+            _ => (2, vec![]),
+        };
+
+        MOps::new(self.offset(), cycles, microcode)
+    }
+}
+
+// microcode helpers
+
+fn write_memory(address: u32, value: u8) -> Microcode {
+    Microcode::WriteMemory(WriteMemory::new(address, value))
+}
+
+fn set_flag(flag: ProgramStatusFlags, value: bool) -> Microcode {
+    Microcode::SetProgramStatusFlagState(SetProgramStatusFlagState::new(flag, value))
+}
+
+fn write_byte_register(register: ByteRegisters, value: u8) -> Microcode {
+    Microcode::Write8bitRegister(Write8bitRegister::new(register, value))
+}
+
+fn write_word_register(register: WordRegisters, value: u16) -> Microcode {
+    Microcode::Write16bitRegister(Write16bitRegister::new(register, value))
+}
+
+/// Generates the microcode to jump to a target within the program bank. The
+/// program counter is advanced past the instruction once it has executed, so
+/// the target is offset by the size of the instruction.
+fn jump(target: u16, offset: usize) -> Vec<Microcode> {
+    vec![write_word_register(
+        WordRegisters::PC,
+        target.wrapping_sub(offset as u16),
+    )]
+}
+
+/// Generates the microcode to jump to a 24-bit target, offset by the size of
+/// the instruction as with `jump`.
+fn jump_long(target: u32, offset: usize) -> Vec<Microcode> {
+    [
+        vec![write_byte_register(
+            ByteRegisters::PBR,
+            (target >> 16) as u8,
+        )],
+        jump(target as u16, offset),
+    ]
+    .concat()
+}
+
+/// Returns the mask of a value at the provided width.
+fn mask(wide: bool) -> u16 {
+    if wide {
+        0xffff
+    } else {
+        0x00ff
+    }
+}
+
+/// Returns the sign bit of a value at the provided width.
+fn sign(wide: bool) -> u16 {
+    if wide {
+        0x8000
+    } else {
+        0x0080
+    }
+}
+
+fn negative_and_zero(value: u16, wide: bool) -> Vec<Microcode> {
+    vec![
+        set_flag(ProgramStatusFlags::Negative, value & sign(wide) != 0),
+        set_flag(ProgramStatusFlags::Zero, value & mask(wide) == 0),
+    ]
+}
+
+fn shift_flags(result: u16, carry: bool, wide: bool) -> Vec<Microcode> {
+    [
+        negative_and_zero(result, wide),
+        vec![set_flag(ProgramStatusFlags::Carry, carry)],
+    ]
+    .concat()
+}
+
+/// Generates the microcode to write the accumulator, retaining the upper
+/// byte when it is 8 bits wide.
+fn write_accumulator(cpu: &W65C816, value: u16, wide: bool) -> Microcode {
+    let value = if wide {
+        value
+    } else {
+        (cpu.acc.read() & 0xff00) | (value & 0xff)
+    };
+    write_word_register(WordRegisters::ACC, value)
+}
+
+fn load_accumulator(cpu: &W65C816, value: u16, wide: bool) -> Vec<Microcode> {
+    [
+        vec![write_accumulator(cpu, value, wide)],
+        negative_and_zero(value, wide),
+    ]
+    .concat()
+}
+
+fn load_register(register: WordRegisters, value: u16, wide: bool) -> Vec<Microcode> {
+    [
+        vec![write_word_register(register, value & mask(wide))],
+        negative_and_zero(value, wide),
+    ]
+    .concat()
+}
+
+fn compare(lhs: u16, rhs: u16, wide: bool) -> Vec<Microcode> {
+    let (lhs, rhs) = (lhs & mask(wide), rhs & mask(wide));
+    [
+        negative_and_zero(lhs.wrapping_sub(rhs), wide),
+        vec![set_flag(ProgramStatusFlags::Carry, lhs >= rhs)],
+    ]
+    .concat()
+}
+
+fn add_with_carry(cpu: &W65C816, lhs: u16, rhs: u16, wide: bool) -> Vec<Microcode> {
+    let carry = cpu.ps.carry;
+    let (result, carry) = if cpu.ps.decimal {
+        decimal_add(lhs, rhs, carry, wide)
+    } else {
+        let sum = u32::from(lhs) + u32::from(rhs) + carry as u32;
+        (sum as u16 & mask(wide), sum > u32::from(mask(wide)))
+    };
+    let overflow = (!(lhs ^ rhs) & (lhs ^ result) & sign(wide)) != 0;
+
+    [
+        load_accumulator(cpu, result, wide),
+        vec![
+            set_flag(ProgramStatusFlags::Carry, carry),
+            set_flag(ProgramStatusFlags::Overflow, overflow),
+        ],
+    ]
+    .concat()
+}
+
+fn subtract_with_borrow(cpu: &W65C816, lhs: u16, rhs: u16, wide: bool) -> Vec<Microcode> {
+    if !cpu.ps.decimal {
+        return add_with_carry(cpu, lhs, !rhs & mask(wide), wide);
+    }
+
+    let (result, carry) = decimal_subtract(lhs, rhs, cpu.ps.carry, wide);
+    let overflow = ((lhs ^ rhs) & (lhs ^ result) & sign(wide)) != 0;
+    [
+        load_accumulator(cpu, result, wide),
+        vec![
+            set_flag(ProgramStatusFlags::Carry, carry),
+            set_flag(ProgramStatusFlags::Overflow, overflow),
+        ],
+    ]
+    .concat()
+}
+
+/// Adds two packed BCD values of either 2 or 4 digits, returning the result
+/// and the carry out of the most significant digit.
+fn decimal_add(lhs: u16, rhs: u16, carry: bool, wide: bool) -> (u16, bool) {
+    let digits = if wide { 4 } else { 2 };
+    (0..digits).fold((0, carry), |(result, carry), digit| {
+        let shift = digit * 4;
+        let mut sum = ((lhs >> shift) & 0xf) + ((rhs >> shift) & 0xf) + carry as u16;
+        if sum > 9 {
+            sum += 6;
+        }
+        (result | ((sum & 0xf) << shift), sum > 0xf)
+    })
+}
+
+/// Subtracts two packed BCD values of either 2 or 4 digits, returning the
+/// result and the inverted borrow out of the most significant digit.
+fn decimal_subtract(lhs: u16, rhs: u16, carry: bool, wide: bool) -> (u16, bool) {
+    let digits = if wide { 4 } else { 2 };
+    (0..digits).fold((0, carry), |(result, carry), digit| {
+        let shift = digit * 4;
+        let difference =
+            ((lhs >> shift) & 0xf) as i16 - ((rhs >> shift) & 0xf) as i16 - !carry as i16;
+        let (difference, carry) = if difference < 0 {
+            (difference + 10, false)
+        } else {
+            (difference, true)
+        };
+        (result | (((difference as u16) & 0xf) << shift), carry)
+    })
+}
+
+/// Generates the microcode to push bytes, in order, to the stack and
+/// decrement the stack pointer past them.
+fn push(cpu: &W65C816, bytes: &[u8]) -> Vec<Microcode> {
+    let sp = cpu.sp.read();
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(index, &byte)| write_memory(u32::from(cpu.stack_offset(sp, -(index as i16))), byte))
+        .chain(std::iter::once(write_word_register(
+            WordRegisters::SP,
+            cpu.stack_offset(sp, -(bytes.len() as i16)),
+        )))
+        .collect()
+}
+
+/// Returns the addresses of a number of bytes, in the order they are pulled,
+/// from the stack.
+fn stack_addresses(cpu: &W65C816, count: usize) -> Vec<u32> {
+    let sp = cpu.sp.read();
+    (1..=count as i16)
+        .map(|index| u32::from(cpu.stack_offset(sp, index)))
+        .collect()
+}
+
+/// Reads a number of bytes, in the order they are pulled, from the stack
+/// returning them alongside the microcode to increment the stack pointer
+/// past them.
+fn pull(cpu: &W65C816, count: usize) -> (Vec<u8>, Microcode) {
+    let sp = cpu.sp.read();
+    let bytes = stack_addresses(cpu, count)
+        .into_iter()
+        .map(|address| cpu.read(address))
+        .collect();
+
+    (
+        bytes,
+        write_word_register(WordRegisters::SP, cpu.stack_offset(sp, count as i16)),
+    )
+}
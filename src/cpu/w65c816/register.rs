@@ -0,0 +1,227 @@
+use crate::cpu::register::Register;
+
+/// Represets each type of word-sized register available in the 65C816.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WordRegisters {
+    ACC,
+    X,
+    Y,
+    SP,
+    DP,
+    PC,
+}
+
+/// Represets each type of byte-sized register available in the 65C816.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ByteRegisters {
+    DBR,
+    PBR,
+    PS,
+}
+
+/// Represets each flag represented in the ProgramStatus Register.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProgramStatusFlags {
+    Negative,
+    Overflow,
+    MemorySelect,
+    IndexSelect,
+    Decimal,
+    Interrupt,
+    Zero,
+    Carry,
+}
+
+/// Represents the 16-bit accumulator and index registers. When the
+/// accumulator is 8 bits wide its upper byte, B, is retained while index
+/// registers narrowed to 8 bits have their upper byte cleared.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct GeneralPurpose {
+    inner: u16,
+}
+
+impl Register<u16, u16> for GeneralPurpose {
+    fn read(&self) -> u16 {
+        self.inner
+    }
+
+    fn write(self, value: u16) -> Self {
+        Self::with_value(value)
+    }
+
+    fn with_value(value: u16) -> Self {
+        Self { inner: value }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ProgramCounter {
+    inner: u16,
+}
+
+impl Register<u16, u16> for ProgramCounter {
+    fn read(&self) -> u16 {
+        self.inner
+    }
+
+    fn write(self, value: u16) -> Self {
+        Self::with_value(value)
+    }
+
+    fn with_value(value: u16) -> Self {
+        Self { inner: value }
+    }
+}
+
+/// Represents the 16-bit stack pointer. In emulation mode the upper byte is
+/// fixed to 0x01, confining the stack to the first page as on the 6502.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct StackPointer {
+    inner: u16,
+}
+
+impl Register<u16, u16> for StackPointer {
+    fn read(&self) -> u16 {
+        self.inner
+    }
+
+    fn write(self, value: u16) -> Self {
+        Self::with_value(value)
+    }
+
+    fn with_value(value: u16) -> Self {
+        Self { inner: value }
+    }
+}
+
+impl Default for StackPointer {
+    fn default() -> Self {
+        Self { inner: 0x01ff }
+    }
+}
+
+/// Represents the direct page register, which relocates the zero page of the
+/// 6502 anywhere in the first bank.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct DirectPage {
+    inner: u16,
+}
+
+impl Register<u16, u16> for DirectPage {
+    fn read(&self) -> u16 {
+        self.inner
+    }
+
+    fn write(self, value: u16) -> Self {
+        Self::with_value(value)
+    }
+
+    fn with_value(value: u16) -> Self {
+        Self { inner: value }
+    }
+}
+
+/// Represents the data and program bank registers, which supply the upper
+/// 8 bits of a 24-bit address.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Bank {
+    inner: u8,
+}
+
+impl Register<u8, u8> for Bank {
+    fn read(&self) -> u8 {
+        self.inner
+    }
+
+    fn write(self, value: u8) -> Self {
+        Self::with_value(value)
+    }
+
+    fn with_value(value: u8) -> Self {
+        Self { inner: value }
+    }
+}
+
+macro_rules! bit_is_set {
+    ($value:expr, $place:expr) => {
+        (($value >> $place) & 1) == 1
+    };
+}
+
+/// Represents the processor status register. The memory and index select
+/// flags occupy the bits that hold the break and unused flags on the 6502
+/// and, when set, select an 8-bit accumulator and 8-bit index registers
+/// respectively. Both are forced set in emulation mode, where they read back
+/// as the break and unused bits.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ProcessorStatus {
+    pub carry: bool,
+    pub zero: bool,
+    pub interrupt_disable: bool,
+    pub decimal: bool,
+    pub index_select: bool,
+    pub memory_select: bool,
+    pub overflow: bool,
+    pub negative: bool,
+}
+
+impl ProcessorStatus {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for ProcessorStatus {
+    fn default() -> Self {
+        Self {
+            carry: false,
+            zero: false,
+            interrupt_disable: true,
+            decimal: false,
+            index_select: true,
+            memory_select: true,
+            overflow: false,
+            negative: false,
+        }
+    }
+}
+
+impl Register<u8, u8> for ProcessorStatus {
+    fn read(&self) -> u8 {
+        (*self).into()
+    }
+
+    fn write(self, value: u8) -> Self {
+        Self::with_value(value)
+    }
+
+    fn with_value(value: u8) -> Self {
+        // check if each bit is set
+        Self {
+            negative: bit_is_set!(value, 7),
+            overflow: bit_is_set!(value, 6),
+            memory_select: bit_is_set!(value, 5),
+            index_select: bit_is_set!(value, 4),
+            decimal: bit_is_set!(value, 3),
+            interrupt_disable: bit_is_set!(value, 2),
+            zero: bit_is_set!(value, 1),
+            carry: bit_is_set!(value, 0),
+        }
+    }
+}
+
+impl From<ProcessorStatus> for u8 {
+    fn from(src: ProcessorStatus) -> u8 {
+        let mut ps: u8 = 0;
+        ps |= (src.negative as u8) << 7;
+        ps |= (src.overflow as u8) << 6;
+        ps |= (src.memory_select as u8) << 5;
+        ps |= (src.index_select as u8) << 4;
+        ps |= (src.decimal as u8) << 3;
+        ps |= (src.interrupt_disable as u8) << 2;
+        ps |= (src.zero as u8) << 1;
+        ps |= src.carry as u8;
+        ps
+    }
+}
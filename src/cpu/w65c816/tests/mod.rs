@@ -0,0 +1,675 @@
+use crate::address_map::{
+    memory::{Memory, ReadOnly, ReadWrite},
+    AddressMap, Addressable, WriteError,
+};
+use crate::cpu::{
+    register::Register,
+    w65c816::{
+        fault::{CpuFault, Fault, FaultPolicy},
+        operations::{mnemonic::Mnemonic, Operation},
+        register::{Bank, DirectPage, GeneralPurpose, ProgramCounter, StackPointer},
+        W65C816,
+    },
+    Halt, StopReason, CPU,
+};
+
+/// The handler addresses loaded into each vector of the test rom.
+const NATIVE_COP_HANDLER: u16 = 0x9000;
+const NATIVE_BRK_HANDLER: u16 = 0x9100;
+const NATIVE_ABORT_HANDLER: u16 = 0x9200;
+const NATIVE_NMI_HANDLER: u16 = 0x9300;
+const NATIVE_IRQ_HANDLER: u16 = 0x9400;
+const EMULATION_COP_HANDLER: u16 = 0x9500;
+const EMULATION_NMI_HANDLER: u16 = 0x9700;
+const EMULATION_IRQ_HANDLER: u16 = 0x9800;
+
+/// Generates a cpu in emulation mode executing from a rom at 0x8000 in bank
+/// 0, with ram below it and a second bank of ram.
+fn generate_test_cpu_with_instructions(opcodes: Vec<u8>) -> W65C816 {
+    let mut rom = [0xea; 0x8000].to_vec();
+    for (index, val) in opcodes.into_iter().enumerate() {
+        rom[index] = val;
+    }
+
+    let vectors = [
+        (0xffe4, NATIVE_COP_HANDLER),
+        (0xffe6, NATIVE_BRK_HANDLER),
+        (0xffe8, NATIVE_ABORT_HANDLER),
+        (0xffea, NATIVE_NMI_HANDLER),
+        (0xffee, NATIVE_IRQ_HANDLER),
+        (0xfff4, EMULATION_COP_HANDLER),
+        (0xfffa, EMULATION_NMI_HANDLER),
+        (0xfffc, 0x8000),
+        (0xfffe, EMULATION_IRQ_HANDLER),
+    ];
+    for (vector, handler) in vectors.iter() {
+        let [lsb, msb] = handler.to_le_bytes();
+        rom[*vector as usize - 0x8000] = lsb;
+        rom[*vector as usize - 0x8000 + 1] = msb;
+    }
+
+    let am = AddressMap::new()
+        .register(
+            0x000000..=0x007fff,
+            Box::new(Memory::<ReadWrite, u32>::new(0x000000, 0x007fff)),
+        )
+        .unwrap()
+        .register(
+            0x008000..=0x00ffff,
+            Box::new(Memory::<ReadOnly, u32>::new(0x008000, 0x00ffff).load(rom)),
+        )
+        .unwrap()
+        .register(
+            0x010000..=0x01ffff,
+            Box::new(Memory::<ReadWrite, u32>::new(0x010000, 0x01ffff)),
+        )
+        .unwrap();
+
+    W65C816::with_addressmap(am).with_pc_register(ProgramCounter::with_value(0x8000))
+}
+
+/// Generates a cpu in native mode with 16-bit registers.
+fn generate_native_test_cpu_with_instructions(opcodes: Vec<u8>) -> W65C816 {
+    let mut cpu = generate_test_cpu_with_instructions(opcodes).with_emulation_mode(false);
+    cpu.ps.memory_select = false;
+    cpu.ps.index_select = false;
+    cpu
+}
+
+#[test]
+fn should_decode_operand_width_from_the_status_register() {
+    let emulated = generate_test_cpu_with_instructions(vec![]);
+    let native = generate_native_test_cpu_with_instructions(vec![]);
+    let bytes = [0xa9, 0x34, 0x12, 0x00];
+
+    let op = Operation::decode(&emulated, &bytes);
+    assert_eq!(Mnemonic::LDA, op.mnemonic);
+    assert_eq!(0x34, op.operand());
+
+    let op = Operation::decode(&native, &bytes);
+    assert_eq!(0x1234, op.operand());
+}
+
+#[test]
+fn should_decode_each_opcode_to_a_distinct_operation() {
+    let cpu = generate_test_cpu_with_instructions(vec![]);
+
+    let operations = (0..=0xffu8).fold(Vec::new(), |mut operations, opcode| {
+        let op = Operation::decode(&cpu, &[opcode, 0x00, 0x00, 0x00]);
+        let decoded = (op.mnemonic, op.address_mode);
+        assert!(
+            !operations.contains(&decoded),
+            "{:#04x} decoded twice",
+            opcode
+        );
+        operations.push(decoded);
+        operations
+    });
+    assert_eq!(256, operations.len());
+}
+
+#[test]
+fn should_reset_into_emulation_mode() {
+    let cpu = generate_native_test_cpu_with_instructions(vec![])
+        .with_x_register(GeneralPurpose::with_value(0x1234))
+        .with_dp_register(DirectPage::with_value(0x2000))
+        .with_dbr_register(Bank::with_value(0x01))
+        .with_pbr_register(Bank::with_value(0x01));
+
+    let (remaining, cpu) = cpu.reset().into();
+    assert_eq!(6, remaining);
+    assert!(cpu.emulation());
+    assert!(cpu.ps.memory_select && cpu.ps.index_select && cpu.ps.interrupt_disable);
+    assert_eq!(0x8000, cpu.pc.read());
+    assert_eq!(0x0034, cpu.x.read());
+    assert_eq!(0x0000, cpu.dp.read());
+    assert_eq!((0x00, 0x00), (cpu.dbr.read(), cpu.pbr.read()));
+    assert_eq!(0x01, cpu.sp.read() >> 8);
+}
+
+#[test]
+fn xce_should_exchange_carry_and_emulation_flags() {
+    // clc; xce; sec; xce
+    let cpu = generate_test_cpu_with_instructions(vec![0x18, 0xfb, 0x38, 0xfb]);
+
+    let state = cpu.run(4).unwrap();
+    assert!(!state.emulation());
+    assert!(state.ps.carry);
+
+    let state = state.run(4).unwrap();
+    assert!(state.emulation());
+    assert!(!state.ps.carry);
+}
+
+#[test]
+fn entering_emulation_mode_should_narrow_registers() {
+    // sec; xce
+    let cpu = generate_native_test_cpu_with_instructions(vec![0x38, 0xfb])
+        .with_x_register(GeneralPurpose::with_value(0x1234))
+        .with_sp_register(StackPointer::with_value(0x1ff0));
+
+    let state = cpu.run(4).unwrap();
+    assert!(state.ps.memory_select && state.ps.index_select);
+    assert_eq!(0x0034, state.x.read());
+    assert_eq!(0x01f0, state.sp.read());
+}
+
+#[test]
+fn rep_should_select_16_bit_registers_in_native_mode() {
+    // rep #$30; lda #$1234; ldx #$5678
+    let cpu =
+        generate_test_cpu_with_instructions(vec![0xc2, 0x30, 0xa9, 0x34, 0x12, 0xa2, 0x78, 0x56])
+            .with_emulation_mode(false);
+
+    let state = cpu.run(9).unwrap();
+    assert_eq!(9, state.cycles());
+    assert_eq!(0x1234, state.acc.read());
+    assert_eq!(0x5678, state.x.read());
+    assert_eq!(0x8008, state.pc.read());
+}
+
+#[test]
+fn rep_should_not_clear_register_widths_in_emulation_mode() {
+    // rep #$30
+    let cpu = generate_test_cpu_with_instructions(vec![0xc2, 0x30]);
+
+    let state = cpu.run(3).unwrap();
+    assert!(state.ps.memory_select && state.ps.index_select);
+}
+
+#[test]
+fn sep_should_clear_the_upper_byte_of_the_index_registers() {
+    // sep #$10
+    let cpu = generate_native_test_cpu_with_instructions(vec![0xe2, 0x10])
+        .with_x_register(GeneralPurpose::with_value(0x1234))
+        .with_y_register(GeneralPurpose::with_value(0x5678));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!((0x0034, 0x0078), (state.x.read(), state.y.read()));
+}
+
+#[test]
+fn should_retain_the_b_accumulator_with_an_8_bit_accumulator() {
+    // lda #$ff; xba
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0xeb])
+        .with_acc_register(GeneralPurpose::with_value(0x1234));
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x12ff, state.acc.read());
+
+    let state = state.run(3).unwrap();
+    assert_eq!(0xff12, state.acc.read());
+    assert!(!state.ps.negative);
+}
+
+#[test]
+fn should_set_flags_on_the_full_width_of_16_bit_operations() {
+    // lda #$8000; adc #$8000
+    let cpu = generate_native_test_cpu_with_instructions(vec![0xa9, 0x00, 0x80, 0x69, 0x00, 0x80]);
+
+    let state = cpu.run(3).unwrap();
+    assert!(state.ps.negative);
+
+    let state = state.run(3).unwrap();
+    assert_eq!(0x0000, state.acc.read());
+    assert!(state.ps.zero && state.ps.carry && state.ps.overflow);
+}
+
+#[test]
+fn adc_should_add_four_digits_in_decimal_mode() {
+    // sed; adc #$0001
+    let cpu = generate_native_test_cpu_with_instructions(vec![0xf8, 0x69, 0x01, 0x00])
+        .with_acc_register(GeneralPurpose::with_value(0x0999));
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x1000, state.acc.read());
+    assert!(!state.ps.carry);
+}
+
+#[test]
+fn sbc_should_subtract_four_digits_in_decimal_mode() {
+    // sed; sec; sbc #$0001
+    let cpu = generate_native_test_cpu_with_instructions(vec![0xf8, 0x38, 0xe9, 0x01, 0x00])
+        .with_acc_register(GeneralPurpose::with_value(0x1000));
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x0999, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn should_address_data_relative_to_the_data_bank() {
+    // lda $0010
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xad, 0x10, 0x00])
+        .with_dbr_register(Bank::with_value(0x01));
+    cpu.address_map.write(0x010010, 0x42).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x42, state.acc.read());
+}
+
+#[test]
+fn should_load_and_store_with_long_addressing() {
+    // lda $010010; sta $010020,x
+    let mut cpu =
+        generate_test_cpu_with_instructions(vec![0xaf, 0x10, 0x00, 0x01, 0x9f, 0x20, 0x00, 0x01])
+            .with_x_register(GeneralPurpose::with_value(0x02));
+    cpu.address_map.write(0x010010, 0x42).unwrap();
+
+    let state = cpu.run(10).unwrap();
+    assert_eq!(10, state.cycles());
+    assert_eq!(0x42, state.address_map.read(0x010022));
+}
+
+#[test]
+fn should_load_through_a_long_pointer_in_the_direct_page() {
+    // lda [$10],y
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xb7, 0x10])
+        .with_dp_register(DirectPage::with_value(0x0200))
+        .with_y_register(GeneralPurpose::with_value(0x01));
+    for (addr, value) in [
+        (0x0210, 0x00),
+        (0x0211, 0x80),
+        (0x0212, 0x01),
+        (0x018001, 0x42),
+    ] {
+        cpu.address_map.write(addr, value).unwrap();
+    }
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x42, state.acc.read());
+}
+
+#[test]
+fn should_take_an_extra_cycle_for_an_unaligned_direct_page() {
+    // lda $05
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xa5, 0x05])
+        .with_dp_register(DirectPage::with_value(0x0010));
+    cpu.address_map.write(0x0015, 0x42).unwrap();
+
+    let (state, _) = cpu.step_instruction();
+    let state = state.unwrap();
+    assert_eq!(4, state.cycles());
+    assert_eq!(0x42, state.acc.read());
+}
+
+#[test]
+fn should_load_relative_to_the_stack_pointer() {
+    // pea $1234; lda $01,s
+    let cpu = generate_native_test_cpu_with_instructions(vec![0xf4, 0x34, 0x12, 0xa3, 0x01]);
+
+    let state = cpu.run(10).unwrap();
+    assert_eq!(0x1234, state.acc.read());
+    assert_eq!(0x01fd, state.sp.read());
+}
+
+#[test]
+fn stack_should_wrap_within_the_first_page_in_emulation_mode() {
+    // pha
+    let cpu = generate_test_cpu_with_instructions(vec![0x48])
+        .with_sp_register(StackPointer::with_value(0x0100))
+        .with_acc_register(GeneralPurpose::with_value(0x42));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x01ff, state.sp.read());
+    assert_eq!(0x42, state.address_map.read(0x0100));
+}
+
+#[test]
+fn mvn_should_move_a_block_one_byte_per_execution() {
+    // mvn $01,$00
+    let mut cpu = generate_native_test_cpu_with_instructions(vec![0x54, 0x01, 0x00])
+        .with_acc_register(GeneralPurpose::with_value(0x0002))
+        .with_x_register(GeneralPurpose::with_value(0x1000))
+        .with_y_register(GeneralPurpose::with_value(0x2000));
+    for (offset, value) in [0x11, 0x22, 0x33].iter().enumerate() {
+        cpu.address_map
+            .write(0x1000 + offset as u32, *value)
+            .unwrap();
+    }
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x8000, state.pc.read());
+    assert_eq!(0x0001, state.acc.read());
+
+    let state = state.run(14).unwrap();
+    assert_eq!(21, state.cycles());
+    assert_eq!(0x8003, state.pc.read());
+    assert_eq!(0xffff, state.acc.read());
+    assert_eq!((0x1003, 0x2003), (state.x.read(), state.y.read()));
+    assert_eq!(0x01, state.dbr.read());
+    for (offset, value) in [0x11, 0x22, 0x33].iter().enumerate() {
+        assert_eq!(*value, state.address_map.read(0x012000 + offset as u32));
+    }
+}
+
+#[test]
+fn mvp_should_move_a_block_in_descending_order() {
+    // mvp $01,$00
+    let mut cpu = generate_native_test_cpu_with_instructions(vec![0x44, 0x01, 0x00])
+        .with_acc_register(GeneralPurpose::with_value(0x0001))
+        .with_x_register(GeneralPurpose::with_value(0x1001))
+        .with_y_register(GeneralPurpose::with_value(0x2001));
+    cpu.address_map.write(0x1000, 0x11).unwrap();
+    cpu.address_map.write(0x1001, 0x22).unwrap();
+
+    let state = cpu.run(14).unwrap();
+    assert_eq!(0x8003, state.pc.read());
+    assert_eq!((0x0fff, 0x1fff), (state.x.read(), state.y.read()));
+    assert_eq!(0x11, state.address_map.read(0x012000));
+    assert_eq!(0x22, state.address_map.read(0x012001));
+}
+
+#[test]
+fn jsl_and_rtl_should_call_across_banks() {
+    // jsl $018000
+    let mut cpu = generate_native_test_cpu_with_instructions(vec![0x22, 0x00, 0x80, 0x01]);
+    // rtl
+    cpu.address_map.write(0x018000, 0x6b).unwrap();
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!((0x01, 0x8000), (state.pbr.read(), state.pc.read()));
+    assert_eq!(0x01fc, state.sp.read());
+
+    let state = state.run(6).unwrap();
+    assert_eq!((0x00, 0x8004), (state.pbr.read(), state.pc.read()));
+    assert_eq!(0x01ff, state.sp.read());
+}
+
+#[test]
+fn cop_should_push_the_program_bank_in_native_mode() {
+    // cop $00
+    let cpu = generate_native_test_cpu_with_instructions(vec![0x02, 0x00]);
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!(NATIVE_COP_HANDLER, state.pc.read());
+    assert_eq!(0x01fb, state.sp.read());
+    assert_eq!(0x00, state.address_map.read(0x01ff));
+    assert_eq!(0x80, state.address_map.read(0x01fe));
+    assert_eq!(0x02, state.address_map.read(0x01fd));
+    assert!(state.ps.interrupt_disable);
+}
+
+#[test]
+fn brk_should_use_the_shared_irq_vector_in_emulation_mode() {
+    // brk $00
+    let cpu = generate_test_cpu_with_instructions(vec![0x00, 0x00]);
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(EMULATION_IRQ_HANDLER, state.pc.read());
+    assert_eq!(0x01fc, state.sp.read());
+    assert_eq!(0x30, state.address_map.read(0x01fd) & 0x30);
+}
+
+#[test]
+fn cop_should_use_its_own_vector_in_emulation_mode() {
+    // cop $00
+    let cpu = generate_test_cpu_with_instructions(vec![0x02, 0x00]);
+
+    let state = cpu.run(7).unwrap();
+    assert_eq!(EMULATION_COP_HANDLER, state.pc.read());
+}
+
+#[test]
+fn nmi_should_use_the_native_vector_and_rti_should_restore_the_program_bank() {
+    // rti at the nmi handler.
+    let mut program = vec![0xea; (NATIVE_NMI_HANDLER - 0x8000) as usize + 1];
+    program[(NATIVE_NMI_HANDLER - 0x8000) as usize] = 0x40;
+    let cpu = generate_native_test_cpu_with_instructions(program)
+        .with_pbr_register(Bank::with_value(0x01))
+        .with_nmi_line(true);
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!(
+        (0x00, NATIVE_NMI_HANDLER),
+        (state.pbr.read(), state.pc.read())
+    );
+
+    let state = state.run(7).unwrap();
+    assert_eq!((0x01, 0x8000), (state.pbr.read(), state.pc.read()));
+    assert_eq!(0x01ff, state.sp.read());
+}
+
+#[test]
+fn irq_should_use_the_vector_of_the_current_mode() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![]).with_irq_line(true);
+    cpu.ps.interrupt_disable = false;
+    let state = cpu.clone().run(7).unwrap();
+    assert_eq!(EMULATION_IRQ_HANDLER, state.pc.read());
+    // hardware interrupts push a clear break bit.
+    assert_eq!(0x00, state.address_map.read(0x01fd) & 0x10);
+
+    let state = cpu.with_emulation_mode(false).run(8).unwrap();
+    assert_eq!(NATIVE_IRQ_HANDLER, state.pc.read());
+}
+
+#[test]
+fn abort_should_return_to_the_aborted_instruction() {
+    let cpu = generate_native_test_cpu_with_instructions(vec![]).with_abort_line(true);
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!(NATIVE_ABORT_HANDLER, state.pc.read());
+    assert_eq!(0x80, state.address_map.read(0x01fe));
+    assert_eq!(0x00, state.address_map.read(0x01fd));
+
+    // abort is edge-triggered and isn't retaken while held.
+    let state = state.run(2).unwrap();
+    assert_eq!(NATIVE_ABORT_HANDLER + 1, state.pc.read());
+}
+
+#[test]
+fn wai_should_idle_until_an_interrupt_is_asserted() {
+    // wai
+    let cpu = generate_test_cpu_with_instructions(vec![0xcb]);
+
+    let state = cpu.run(10).unwrap();
+    assert!(state.waiting());
+    assert_eq!(0x8001, state.pc.read());
+
+    // a masked irq wakes the cpu without being serviced.
+    let state = state.with_irq_line(true).run(2).unwrap();
+    assert!(!state.waiting());
+    assert_eq!(0x8002, state.pc.read());
+}
+
+#[test]
+fn wai_should_stop_run_until_as_halted() {
+    // wai
+    let cpu = generate_test_cpu_with_instructions(vec![0xcb]);
+
+    let (state, reason) = cpu.run_until(10, |_| false);
+    assert_eq!(StopReason::Halted(Halt::Wait), reason);
+    assert_eq!(0x8001, state.unwrap().pc.read());
+}
+
+#[test]
+fn stp_should_stop_run_until_as_halted() {
+    // stp
+    let cpu = generate_test_cpu_with_instructions(vec![0xdb]);
+
+    let (state, reason) = cpu.run_until(10, |_| false);
+    assert_eq!(StopReason::Halted(Halt::Stop), reason);
+    assert_eq!(3, state.unwrap().cycles());
+}
+
+#[test]
+fn should_carry_the_instruction_in_flight_between_cycle_steps() {
+    // lda $010000, a 5 cycle instruction.
+    let cpu = generate_test_cpu_with_instructions(vec![0xaf, 0x00, 0x00, 0x01]);
+
+    let (state, reason) = cpu.step_cycle();
+    let state = state.unwrap();
+    assert_eq!(StopReason::Budget, reason);
+    assert_eq!(4, state.in_flight());
+    assert_eq!(0x8000, state.pc.read());
+
+    let (state, _) = state.step_instruction();
+    let state = state.unwrap();
+    assert_eq!(5, state.cycles());
+    assert_eq!(0x8004, state.pc.read());
+}
+
+#[test]
+fn should_run_until_memory_is_written() {
+    // lda #$42; sta $10
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x42, 0x85, 0x10]);
+
+    let (state, reason) = cpu.run_until(100, |cpu| cpu.last_write().is_some());
+    let state = state.unwrap();
+    assert_eq!(StopReason::Predicate, reason);
+    assert_eq!(Some((0x0010, 0x42)), state.last_write());
+    assert_eq!(5, state.cycles());
+}
+
+#[test]
+fn stp_should_stop_the_clock_until_reset() {
+    // stp
+    let cpu = generate_test_cpu_with_instructions(vec![0xdb]);
+
+    let state = cpu.run(10).unwrap();
+    assert!(state.stopped());
+    assert_eq!(3, state.cycles());
+
+    let (_, state) = state.reset().into();
+    assert!(!state.stopped());
+}
+
+#[test]
+fn should_carry_overrun_cycles_between_runs() {
+    // lda $010000, a 5 cycle instruction.
+    let cpu = generate_test_cpu_with_instructions(vec![0xaf, 0x00, 0x00, 0x01]);
+
+    let state = cpu.run(2);
+    let state = state.run(3).unwrap();
+    assert_eq!(5, state.cycles());
+    assert_eq!(0x8004, state.pc.read());
+}
+
+#[test]
+fn index_penalty_should_always_apply_with_16_bit_index_registers() {
+    // lda $1000,x
+    let cpu = generate_native_test_cpu_with_instructions(vec![0xbd, 0x00, 0x10]);
+
+    let (state, _) = cpu.step_instruction();
+    assert_eq!(6, state.unwrap().cycles());
+}
+
+#[test]
+fn run_should_stop_on_the_exact_cycle_budget() {
+    // lda $010000, a 5 cycle instruction.
+    let cpu = generate_test_cpu_with_instructions(vec![0xaf, 0x00, 0x00, 0x01]);
+
+    let (remaining, state) = cpu.run(2).into();
+    assert_eq!(2, state.cycles());
+    assert_eq!(3, remaining);
+    assert_eq!(3, state.in_flight());
+    assert_eq!(0x8000, state.pc.read());
+}
+
+#[test]
+fn should_write_each_byte_of_a_16_bit_store_on_its_own_cycle() {
+    // sta $10
+    let cpu = generate_native_test_cpu_with_instructions(vec![0x85, 0x10])
+        .with_acc_register(GeneralPurpose::with_value(0x1234));
+
+    let writes: Vec<_> = (0..4)
+        .scan(cpu, |cpu, _| {
+            let (state, _) = cpu.clone().step_cycle();
+            *cpu = state.unwrap();
+            Some(cpu.last_write())
+        })
+        .collect();
+    assert_eq!(
+        vec![None, None, Some((0x10, 0x34)), Some((0x11, 0x12))],
+        writes
+    );
+}
+
+#[test]
+fn rdy_should_halt_the_cpu_on_its_next_read_cycle() {
+    // nop
+    let cpu = generate_test_cpu_with_instructions(vec![0xea]).with_rdy_line(false);
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(3, state.cycles());
+    assert_eq!(0x8000, state.pc.read());
+
+    let state = state.with_rdy_line(true).run(2).unwrap();
+    assert_eq!(0x8001, state.pc.read());
+}
+
+#[test]
+fn should_count_stalled_cycles_before_executing() {
+    // nop
+    let cpu = generate_test_cpu_with_instructions(vec![0xea]).with_stall(2);
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0, state.stalled());
+    assert_eq!(0x8000, state.pc.read());
+
+    let state = state.run(2).unwrap();
+    assert_eq!(4, state.cycles());
+    assert_eq!(0x8001, state.pc.read());
+}
+
+#[test]
+fn should_stop_with_a_fault_on_write_to_read_only_memory() {
+    // lda #$ff; sta $8000
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0x8d, 0x00, 0x80, 0xea]);
+
+    let fault = Fault::new(
+        CpuFault::BusWrite {
+            address: 0x008000,
+            value: 0xff,
+            reason: WriteError::ReadOnly {
+                address: 0x008000,
+                value: 0xff,
+            },
+        },
+        0x008002,
+        5,
+    );
+    let (state, reason) = cpu.run_until(10, |_| false);
+    let state = state.unwrap();
+    assert_eq!(StopReason::Fault(fault.clone()), reason);
+    assert_eq!(Some(&fault), state.halted());
+    assert_eq!(6, state.cycles());
+
+    // a halted cpu should not advance.
+    let state = state.run(10).unwrap();
+    assert_eq!(6, state.cycles());
+    assert_eq!(0x8005, state.pc.read());
+}
+
+#[test]
+fn should_drop_failed_write_when_ignoring_faults() {
+    // sta $020000, an unmapped bank.
+    let cpu = generate_test_cpu_with_instructions(vec![0x8f, 0x00, 0x00, 0x02, 0xea])
+        .with_fault_policy(FaultPolicy::Ignore);
+
+    let (state, reason) = cpu.run_until(7, |_| false);
+    let state = state.unwrap();
+    assert_eq!(StopReason::Budget, reason);
+    assert_eq!(0x8005, state.pc.read());
+    assert!(state.halted().is_none());
+}
+
+#[test]
+fn should_invoke_trap_handler_on_unmapped_program_address() {
+    fn handler(cpu: W65C816, fault: Fault) -> W65C816 {
+        assert_eq!(CpuFault::UnmappedAccess(0x030000), fault.kind);
+        cpu.with_pbr_register(Bank::default())
+            .with_pc_register(ProgramCounter::with_value(0x8004))
+    }
+
+    // jmp $030000, an unmapped bank.
+    let cpu = generate_test_cpu_with_instructions(vec![0x5c, 0x00, 0x00, 0x03, 0xea])
+        .with_fault_policy(FaultPolicy::Trap(handler));
+
+    // the fetch of the unmapped instruction takes a single cycle.
+    let (state, reason) = cpu.run_until(7, |_| false);
+    let state = state.unwrap();
+    assert_eq!(StopReason::Budget, reason);
+    assert_eq!(0x8005, state.pc.read());
+    assert!(state.halted().is_none());
+}
@@ -2,4 +2,4 @@
 pub use crate::address_map::{Addressable, AddressableClone};
 
 #[allow(unused_imports)]
-pub use crate::cpu::{register::Register, Cyclable, Offset, CPU, StepState};
+pub use crate::cpu::{register::Register, Cyclable, Halt, Offset, StopReason, CPU, StepState};